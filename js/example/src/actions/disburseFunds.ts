@@ -13,6 +13,7 @@ export const disburseFunds = async (amount: number | bigint): Promise<string> =>
         amount,
         OWNER_PUBKEY,
         destinationTokenPubkey,
+        AUTHORITY_PUBKEY,
         AUTHORITY_PUBKEY
     );
     return await sendTransaction([disburseFunds], [OWNER_KEYPAIR]);
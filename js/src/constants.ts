@@ -3,3 +3,5 @@ import { PublicKey } from '@solana/web3.js';
 export const RNDR_PROGRAM_ID = new PublicKey('7g4aX3DYhk6xHiGuoAbEnVTp9HMgLqyENoK53AVm267E');
 
 export const RNDR_TOKEN_MINT = new PublicKey('EZPrZ4VS4XonzrcrsJaYVbXHtnAKw89wq5VN7beK4EJF');
+
+export const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey('BPFLoaderUpgradeab1e11111111111111111111111');
@@ -1,8 +1,22 @@
 import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
-import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import {
+    PublicKey,
+    SystemProgram,
+    SYSVAR_CLOCK_PUBKEY,
+    SYSVAR_RENT_PUBKEY,
+    TransactionInstruction,
+} from '@solana/web3.js';
 import { struct, u8 } from 'buffer-layout';
 import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
-import { findEscrowAddress, findEscrowAssociatedTokenAddress, findJobAddress, u64 } from '../util';
+import {
+    findNodeEarningsAddress,
+    findEscrowAddress,
+    findEscrowAssociatedTokenAddress,
+    findIdempotencyMarkerAddress,
+    findJobAddress,
+    findReceiptAddress,
+    u64,
+} from '../util';
 import { RNDRInstruction } from './instruction';
 
 interface Data {
@@ -12,16 +26,50 @@ interface Data {
 
 const DataLayout = struct<Data>([u8('instruction'), u64('amount')]);
 
+/** Receipt to write for a payout, `payoutIndex` being the payout count of the job */
+export interface ReceiptRequest {
+    payoutIndex: bigint;
+    reference: Uint8Array;
+}
+
 export const createDisburseFundsInstruction = async (
     amount: number | bigint,
     owner: PublicKey,
     destinationToken: PublicKey,
-    authority: PublicKey
+    destinationOwner: PublicKey,
+    authority: PublicKey,
+    namespace?: PublicKey,
+    receipt?: ReceiptRequest,
+    idempotencyKey?: Uint8Array,
+    jobGeneration?: bigint,
+    rentPayer: PublicKey = owner
 ): Promise<TransactionInstruction> => {
-    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    if ((receipt || idempotencyKey) && jobGeneration === undefined) {
+        throw new Error('Job generation is required to write a receipt or use an idempotency key');
+    }
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT, namespace);
     const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
     const [job] = await findJobAddress(escrow, authority);
-    return disburseFunds(amount, RNDR_TOKEN_MINT, escrow, owner, escrowAssociatedToken, job, destinationToken);
+    const [nodeEarnings] = await findNodeEarningsAddress(escrow, destinationOwner);
+    const receiptAddress =
+        receipt && (await findReceiptAddress(job, jobGeneration ?? BigInt(0), receipt.payoutIndex))[0];
+    const idempotencyMarker =
+        idempotencyKey && (await findIdempotencyMarkerAddress(job, jobGeneration ?? BigInt(0), idempotencyKey))[0];
+    return disburseFunds(
+        amount,
+        RNDR_TOKEN_MINT,
+        escrow,
+        owner,
+        escrowAssociatedToken,
+        job,
+        destinationToken,
+        nodeEarnings,
+        rentPayer,
+        receiptAddress,
+        receipt?.reference,
+        idempotencyMarker,
+        idempotencyKey
+    );
 };
 
 export const disburseFunds = (
@@ -31,9 +79,15 @@ export const disburseFunds = (
     owner: PublicKey,
     escrowAssociatedToken: PublicKey,
     job: PublicKey,
-    destinationToken: PublicKey
+    destinationToken: PublicKey,
+    nodeEarnings: PublicKey,
+    rentPayer: PublicKey,
+    receipt?: PublicKey,
+    reference?: Uint8Array,
+    idempotencyMarker?: PublicKey,
+    idempotencyKey?: Uint8Array
 ): TransactionInstruction => {
-    const data = Buffer.alloc(DataLayout.span);
+    let data = Buffer.alloc(DataLayout.span);
     DataLayout.encode(
         {
             instruction: RNDRInstruction.DisburseFunds,
@@ -41,6 +95,14 @@ export const disburseFunds = (
         },
         data
     );
+    if ((receipt && reference) || (idempotencyMarker && idempotencyKey)) {
+        const referenceData = receipt && reference ? [Buffer.from([1]), Buffer.from(reference)] : [Buffer.from([0])];
+        const idempotencyKeyData =
+            idempotencyMarker && idempotencyKey
+                ? [Buffer.from([idempotencyKey.length]), Buffer.from(idempotencyKey)]
+                : [Buffer.from([0])];
+        data = Buffer.concat([data, ...referenceData, ...idempotencyKeyData]);
+    }
 
     const keys = [
         { pubkey: tokenMint, isSigner: false, isWritable: false },
@@ -50,7 +112,16 @@ export const disburseFunds = (
         { pubkey: job, isSigner: false, isWritable: true },
         { pubkey: destinationToken, isSigner: false, isWritable: true },
         { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: nodeEarnings, isSigner: false, isWritable: true },
+        { pubkey: rentPayer, isSigner: true, isWritable: true },
+        { pubkey: SYSVAR_CLOCK_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ];
+    if (receipt && reference) keys.push({ pubkey: receipt, isSigner: false, isWritable: true });
+    if (idempotencyMarker && idempotencyKey) {
+        keys.push({ pubkey: idempotencyMarker, isSigner: false, isWritable: true });
+    }
 
     return new TransactionInstruction({
         keys,
@@ -8,17 +8,20 @@ import { RNDRInstruction } from './instruction';
 interface Data {
     instruction: number;
     amount: bigint;
+    tier: number;
 }
 
-const DataLayout = struct<Data>([u8('instruction'), u64('amount')]);
+const DataLayout = struct<Data>([u8('instruction'), u64('amount'), u8('tier')]);
 
 export const createFundJobInstruction = async (
     amount: number | bigint,
     funder: PublicKey,
     sourceToken: PublicKey,
-    authority: PublicKey
+    authority: PublicKey,
+    namespace?: PublicKey,
+    tier = 0
 ): Promise<TransactionInstruction> => {
-    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT, namespace);
     const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
     const [job] = await findJobAddress(escrow, authority);
     return fundJobInstruction(
@@ -29,7 +32,8 @@ export const createFundJobInstruction = async (
         authority,
         escrow,
         escrowAssociatedToken,
-        job
+        job,
+        tier
     );
 };
 
@@ -41,13 +45,15 @@ export const fundJobInstruction = (
     authority: PublicKey,
     escrow: PublicKey,
     escrowAssociatedToken: PublicKey,
-    job: PublicKey
+    job: PublicKey,
+    tier = 0
 ): TransactionInstruction => {
     const data = Buffer.alloc(DataLayout.span);
     DataLayout.encode(
         {
             instruction: RNDRInstruction.FundJob,
             amount: BigInt(amount),
+            tier,
         },
         data
     );
@@ -2,7 +2,13 @@ import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token
 import { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY, TransactionInstruction } from '@solana/web3.js';
 import { struct, u8 } from 'buffer-layout';
 import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
-import { findEscrowAddress, findEscrowAssociatedTokenAddress, publicKey } from '../util';
+import {
+    findEscrowAddress,
+    findEscrowAssociatedTokenAddress,
+    findProgramConfigAddress,
+    findProgramDataAddress,
+    publicKey,
+} from '../util';
 import { RNDRInstruction } from './instruction';
 
 interface Data {
@@ -14,21 +20,39 @@ const DataLayout = struct<Data>([u8('instruction'), publicKey('owner')]);
 
 export const createInitEscrowInstruction = async (
     owner: PublicKey,
-    funder: PublicKey
+    funder: PublicKey,
+    authority: PublicKey,
+    namespace?: PublicKey
 ): Promise<TransactionInstruction> => {
-    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [programData] = await findProgramDataAddress();
+    const [programConfig] = await findProgramConfigAddress();
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT, namespace);
     const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
-    return initEscrowInstruction(owner, RNDR_TOKEN_MINT, funder, escrow, escrowAssociatedToken);
+    return initEscrowInstruction(
+        owner,
+        RNDR_TOKEN_MINT,
+        funder,
+        authority,
+        programData,
+        programConfig,
+        escrow,
+        escrowAssociatedToken,
+        namespace
+    );
 };
 
 export const initEscrowInstruction = (
     owner: PublicKey,
     tokenMint: PublicKey,
     funder: PublicKey,
+    authority: PublicKey,
+    programData: PublicKey,
+    programConfig: PublicKey,
     escrow: PublicKey,
-    escrowAssociatedToken: PublicKey
+    escrowAssociatedToken: PublicKey,
+    namespace?: PublicKey
 ): TransactionInstruction => {
-    const data = Buffer.alloc(DataLayout.span);
+    let data = Buffer.alloc(DataLayout.span);
     DataLayout.encode(
         {
             instruction: RNDRInstruction.InitEscrow,
@@ -36,10 +60,15 @@ export const initEscrowInstruction = (
         },
         data
     );
+    // The legacy escrow of the mint is initialized without a namespace
+    if (namespace) data = Buffer.concat([data, namespace.toBuffer()]);
 
     const keys = [
         { pubkey: tokenMint, isSigner: false, isWritable: false },
         { pubkey: funder, isSigner: true, isWritable: true },
+        { pubkey: authority, isSigner: true, isWritable: false },
+        { pubkey: programData, isSigner: false, isWritable: false },
+        { pubkey: programConfig, isSigner: false, isWritable: false },
         { pubkey: escrow, isSigner: false, isWritable: true },
         { pubkey: escrowAssociatedToken, isSigner: false, isWritable: true },
         { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
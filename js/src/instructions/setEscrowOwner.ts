@@ -13,9 +13,10 @@ const DataLayout = struct<Data>([u8('instruction'), publicKey('newOwner')]);
 
 export const createSetEscrowOwnerInstruction = async (
     newOwner: PublicKey,
-    currentOwner: PublicKey
+    currentOwner: PublicKey,
+    namespace?: PublicKey
 ): Promise<TransactionInstruction> => {
-    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT, namespace);
     return setEscrowOwnerInstruction(newOwner, escrow, currentOwner);
 };
 
@@ -2,4 +2,19 @@ export enum AccountType {
     Uninitialized = 0,
     EscrowV1 = 1,
     JobV1 = 2,
+    JobMetadataV1 = 3,
+    MilestoneV1 = 4,
+    PayoutRootV1 = 5,
+    RedeemedVoucherV1 = 6,
+    ProgramConfigV1 = 7,
+    FundingScheduleV1 = 8,
+    PendingPayoutV1 = 9,
+    RenderSubmissionV1 = 10,
+    RateCardV1 = 11,
+    AssignmentV1 = 12,
+    NodeV1 = 13,
+    StakeV1 = 14,
+    NodeEarningsV1 = 15,
+    ReceiptV1 = 16,
+    IdempotencyMarkerV1 = 17,
 }
@@ -1,5 +1,5 @@
 import { AccountInfo, PublicKey } from '@solana/web3.js';
-import { struct, u8 } from 'buffer-layout';
+import { seq, struct, u32, u8 } from 'buffer-layout';
 import { Parser, publicKey, u64 } from '../util';
 import { AccountType } from './accountType';
 
@@ -7,10 +7,30 @@ export interface Escrow {
     accountType: AccountType;
     amount: bigint;
     owner: PublicKey;
+    bumpSeed: number;
+    namespace: PublicKey;
+    jobCount: bigint;
+    successor: PublicKey;
+    tierMultipliers: number[];
+    nodeRegistryRequired: number;
+    minStake: bigint;
+    jobsCreated: bigint;
 }
 
 /** @internal */
-export const EscrowLayout = struct<Escrow>([u8('accountType'), u64('amount'), publicKey('owner')]);
+export const EscrowLayout = struct<Escrow>([
+    u8('accountType'),
+    u64('amount'),
+    publicKey('owner'),
+    u8('bumpSeed'),
+    publicKey('namespace'),
+    u64('jobCount'),
+    publicKey('successor'),
+    seq(u32(), 3, 'tierMultipliers'),
+    u8('nodeRegistryRequired'),
+    u64('minStake'),
+    u64('jobsCreated'),
+]);
 
 export const ESCROW_SIZE = EscrowLayout.span;
 
@@ -1,3 +1,5 @@
 export * from './accountType';
 export * from './escrow';
 export * from './job';
+export * from './nodeEarnings';
+export * from './receipt';
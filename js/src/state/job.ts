@@ -1,5 +1,5 @@
 import { AccountInfo, PublicKey } from '@solana/web3.js';
-import { struct, u8 } from 'buffer-layout';
+import { struct, u32, u8 } from 'buffer-layout';
 import { Parser, publicKey, u64 } from '../util';
 import { AccountType } from './accountType';
 
@@ -7,10 +7,38 @@ export interface Job {
     accountType: AccountType;
     amount: bigint;
     authority: PublicKey;
+    disbursed: bigint;
+    milestoneCount: number;
+    approvedAmount: bigint;
+    reserved: bigint;
+    submissionsRequired: number;
+    pricedPerFrame: number;
+    tier: number;
+    tierMultiplier: number;
+    payoutCount: bigint;
+    generation: bigint;
+    bumpSeed: number;
+    rentPayer: PublicKey;
 }
 
 /** @internal */
-export const JobLayout = struct<Job>([u8('accountType'), u64('amount'), publicKey('authority')]);
+export const JobLayout = struct<Job>([
+    u8('accountType'),
+    u64('amount'),
+    publicKey('authority'),
+    u64('disbursed'),
+    u8('milestoneCount'),
+    u64('approvedAmount'),
+    u64('reserved'),
+    u8('submissionsRequired'),
+    u8('pricedPerFrame'),
+    u8('tier'),
+    u32('tierMultiplier'),
+    u64('payoutCount'),
+    u64('generation'),
+    u8('bumpSeed'),
+    publicKey('rentPayer'),
+]);
 
 export const JOB_SIZE = JobLayout.span;
 
@@ -0,0 +1,45 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export interface NodeEarnings {
+    accountType: AccountType;
+    escrow: PublicKey;
+    owner: PublicKey;
+    earned: bigint;
+    payoutCount: bigint;
+    firstPayoutSlot: bigint;
+    lastPayoutSlot: bigint;
+    bumpSeed: number;
+    rentPayer: PublicKey;
+}
+
+/** @internal */
+export const NodeEarningsLayout = struct<NodeEarnings>([
+    u8('accountType'),
+    publicKey('escrow'),
+    publicKey('owner'),
+    u64('earned'),
+    u64('payoutCount'),
+    u64('firstPayoutSlot'),
+    u64('lastPayoutSlot'),
+    u8('bumpSeed'),
+    publicKey('rentPayer'),
+]);
+
+export const NODE_EARNINGS_SIZE = NodeEarningsLayout.span;
+
+export const isNodeEarnings = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === NODE_EARNINGS_SIZE && info.data.readUIntLE(0, 1) === AccountType.NodeEarningsV1;
+};
+
+export const parseNodeEarnings: Parser<NodeEarnings> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isNodeEarnings(info)) return;
+    const data = NodeEarningsLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
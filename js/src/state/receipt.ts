@@ -0,0 +1,74 @@
+import { AccountInfo, Connection, PublicKey } from '@solana/web3.js';
+import { blob, struct, u8 } from 'buffer-layout';
+import { findReceiptAddress, Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+import { parseJob } from './job';
+
+export interface Receipt {
+    accountType: AccountType;
+    job: PublicKey;
+    payoutIndex: bigint;
+    destination: PublicKey;
+    amount: bigint;
+    slot: bigint;
+    disburser: PublicKey;
+    reference: Uint8Array;
+    bumpSeed: number;
+    rentPayer: PublicKey;
+}
+
+/** @internal */
+export const ReceiptLayout = struct<Receipt>([
+    u8('accountType'),
+    publicKey('job'),
+    u64('payoutIndex'),
+    publicKey('destination'),
+    u64('amount'),
+    u64('slot'),
+    publicKey('disburser'),
+    blob(32, 'reference'),
+    u8('bumpSeed'),
+    publicKey('rentPayer'),
+]);
+
+export const RECEIPT_SIZE = ReceiptLayout.span;
+
+export const isReceipt = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === RECEIPT_SIZE && info.data.readUIntLE(0, 1) === AccountType.ReceiptV1;
+};
+
+export const parseReceipt: Parser<Receipt> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isReceipt(info)) return;
+    const data = ReceiptLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
+
+/** Lists the receipts written for the payouts of the current generation of a job, in payout order */
+export const getJobReceipts = async (connection: Connection, job: PublicKey): Promise<Receipt[]> => {
+    const jobInfo = await connection.getAccountInfo(job);
+    const parsedJob = jobInfo && parseJob(job, jobInfo);
+    if (!parsedJob) throw new Error('Job not found');
+
+    const addresses = await Promise.all(
+        Array.from({ length: Number(parsedJob.data.payoutCount) }, async (_, payoutIndex) => {
+            const [receipt] = await findReceiptAddress(job, parsedJob.data.generation, BigInt(payoutIndex));
+            return receipt;
+        })
+    );
+
+    // Receipts are optional, so payouts without one are skipped
+    const receipts: Receipt[] = [];
+    for (let i = 0; i < addresses.length; i += 100) {
+        const chunk = addresses.slice(i, i + 100);
+        const infos = await connection.getMultipleAccountsInfo(chunk);
+        infos.forEach((info, index) => {
+            const receipt = info && parseReceipt(chunk[index], info);
+            if (receipt) receipts.push(receipt.data);
+        });
+    }
+    return receipts;
+};
@@ -1,12 +1,15 @@
 import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token';
 import { PublicKey } from '@solana/web3.js';
-import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { toBufferLE } from 'bigint-buffer';
+import { BPF_LOADER_UPGRADEABLE_PROGRAM_ID, RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
 
-export const findEscrowAddress = async (tokenMint: PublicKey = RNDR_TOKEN_MINT): Promise<[PublicKey, number]> => {
-    return await PublicKey.findProgramAddress(
-        [Buffer.from('escrow', 'utf8'), tokenMint.toBuffer(), TOKEN_PROGRAM_ID.toBuffer()],
-        RNDR_PROGRAM_ID
-    );
+export const findEscrowAddress = async (
+    tokenMint: PublicKey = RNDR_TOKEN_MINT,
+    namespace?: PublicKey
+): Promise<[PublicKey, number]> => {
+    const seeds = [Buffer.from('escrow', 'utf8'), tokenMint.toBuffer(), TOKEN_PROGRAM_ID.toBuffer()];
+    if (namespace) seeds.push(namespace.toBuffer());
+    return await PublicKey.findProgramAddress(seeds, RNDR_PROGRAM_ID);
 };
 
 export const findEscrowAssociatedTokenAddress = async (
@@ -19,9 +22,46 @@ export const findEscrowAssociatedTokenAddress = async (
     );
 };
 
+export const findIdempotencyMarkerAddress = async (
+    job: PublicKey,
+    generation: bigint,
+    idempotencyKey: Uint8Array
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('idempotency', 'utf8'), job.toBuffer(), toBufferLE(generation, 8), Buffer.from(idempotencyKey)],
+        RNDR_PROGRAM_ID
+    );
+};
+
 export const findJobAddress = async (escrow: PublicKey, authority: PublicKey): Promise<[PublicKey, number]> => {
     return await PublicKey.findProgramAddress(
         [Buffer.from('job', 'utf8'), escrow.toBuffer(), authority.toBuffer()],
         RNDR_PROGRAM_ID
     );
 };
+
+export const findNodeEarningsAddress = async (escrow: PublicKey, owner: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('node_earnings', 'utf8'), escrow.toBuffer(), owner.toBuffer()],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findReceiptAddress = async (
+    job: PublicKey,
+    generation: bigint,
+    payoutIndex: bigint
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('receipt', 'utf8'), job.toBuffer(), toBufferLE(generation, 8), toBufferLE(payoutIndex, 8)],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findProgramConfigAddress = async (): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress([Buffer.from('config', 'utf8')], RNDR_PROGRAM_ID);
+};
+
+export const findProgramDataAddress = async (): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress([RNDR_PROGRAM_ID.toBuffer()], BPF_LOADER_UPGRADEABLE_PROGRAM_ID);
+};
@@ -24,6 +24,9 @@ pub enum RNDRError {
     /// MathError
     #[error("MathError")]
     MathError,
+    /// DuplicateDisbursal
+    #[error("DuplicateDisbursal")]
+    DuplicateDisbursal,
 }
 
 impl From<RNDRError> for ProgramError {
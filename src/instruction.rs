@@ -1,38 +1,85 @@
 //! Instruction types
 
 use {
-    crate::error::RNDRError,
+    crate::{
+        error::RNDRError,
+        merkle::MAX_PROOF_LEN,
+        state::{
+            is_valid_idempotency_key, MAX_ALLOWED_MINTS, MAX_ALLOWED_TOKEN_PROGRAMS,
+            MAX_CONTENT_ID_LEN, TIER_COUNT,
+        },
+        voucher::Voucher,
+    },
     solana_program::{
+        bpf_loader_upgradeable,
+        clock::UnixTimestamp,
         instruction::{AccountMeta, Instruction},
         msg,
         program_error::ProgramError,
+        program_pack::Pack,
         pubkey::{Pubkey, PUBKEY_BYTES},
         system_program,
-        sysvar::rent,
+        sysvar::{clock, instructions, rent},
     },
     spl_associated_token_account::get_associated_token_address,
     std::{convert::TryInto, mem::size_of},
 };
 
+/// Maximum number of disbursements in a 'BatchDisburse' instruction. With every job paying two
+/// destinations, this is the largest batch that fits in a single transaction packet.
+pub const MAX_BATCH_DISBURSEMENTS: usize = 16;
+
+/// Single transfer of a batch disbursal
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Disbursement {
+    /// Index of the Job account to debit
+    pub job_index: u8,
+    /// Index of the destination RNDR token account
+    pub destination_index: u8,
+    /// Amount of RNDR tokens to disburse, before the price multiplier of the Job tier
+    pub amount: u64,
+}
+
+/// Maximum number of fundings in a 'BatchFundJob' instruction
+pub const MAX_BATCH_FUNDINGS: usize = 16;
+
+/// Single Job top up of a batch funding
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JobFunding {
+    /// Job authority
+    pub authority: Pubkey,
+    /// Amount of RNDR tokens to fund
+    pub amount: u64,
+}
+
 /// Instructions supported by the RNDR program.
 #[derive(Clone, Debug, PartialEq)]
 pub enum RNDRInstruction {
     // 0
     /// Initialize an Escrow.
+    /// Only the upgrade authority of the RNDR program, or the admin of the program config once it
+    /// is initialized, can initialize an Escrow. The program config then restricts the token mints
+    /// and token programs Escrows can be created for.
     ///
     /// Accounts expected by this instruction:
     ///
     ///   0. `[]` RNDR SPL Token mint
     ///   1. `[writable,signer]` Funder SOL account
-    ///   2. `[writable]` Escrow PDA account
-    ///   3. `[writable]` Escrow ATA account
-    ///   4. `[]` Rent sysvar
-    ///   5. `[]` System program id
-    ///   6. `[]` Token program id
-    ///   7. `[]` Associated Token Account program id
+    ///   2. `[signer]` RNDR program upgrade authority or program config admin
+    ///   3. `[]` RNDR program data account
+    ///   4. `[]` Program config PDA account, possibly uninitialized
+    ///   5. `[writable]` Escrow PDA account
+    ///   6. `[writable]` Escrow ATA account
+    ///   7. `[]` Rent sysvar
+    ///   8. `[]` System program id
+    ///   9. `[]` Token program id
+    ///   10. `[]` Associated Token Account program id
     InitEscrow {
-        /// Owner authority that can disburse funds
+        /// Owner authority that can disburse funds, the default owner of the program config if
+        /// it's the default pubkey
         owner: Pubkey,
+        /// Optional namespace of the Escrow, none for the legacy Escrow of the mint
+        namespace: Option<Pubkey>,
     },
 
     // 1
@@ -48,7 +95,8 @@ pub enum RNDRInstruction {
     },
 
     // 2
-    /// Transfer funds into an Escrow and credit a Job.
+    /// Transfer funds into an Escrow and credit a Job. A Job that doesn't exist yet is created
+    /// with the priority tier provided, the tier of an existing Job is changed with `SetJobTier`.
     ///
     /// Accounts expected by this instruction:
     ///
@@ -66,10 +114,21 @@ pub enum RNDRInstruction {
     FundJob {
         /// Amount of RNDR tokens to escrow
         amount: u64,
+        /// Priority tier of the Job if it is created, 0 if untiered. An existing Job must be of
+        /// this tier unless it's 0
+        tier: u8,
     },
 
     // 3
-    /// Transfer funds from an Escrow and debit a Job
+    /// Transfer funds from an Escrow and debit a Job. The payout is recorded in the node earnings
+    /// of the destination owner, which are created if they don't exist yet. Only payouts made with
+    /// this instruction are recorded there, unlike the counters of a registered render node.
+    /// If a reference is provided, a Receipt of the payout is also written, seeded by the Job, its
+    /// generation and its payout count. If an idempotency key is provided, an idempotency marker
+    /// seeded by the Job, its generation and the key is created, and the disbursal fails with
+    /// `DuplicateDisbursal` if it exists. Keys are only unique while their marker exists: the rent
+    /// payer can close the marker once `IDEMPOTENCY_MARKER_LIFETIME` has passed, after which the
+    /// key can be used again.
     ///
     /// Accounts expected by this instruction:
     ///
@@ -80,195 +139,3193 @@ pub enum RNDRInstruction {
     ///   4. `[writable]` Job PDA account
     ///   5. `[writable]` Destination RNDR token account
     ///   6. `[]` Token program id
+    ///   7. `[writable]` Node earnings PDA account of the destination owner
+    ///   8. `[writable,signer]` Rent payer SOL account, for the node earnings, receipt and marker
+    ///   9. `[]` Clock sysvar
+    ///   10. `[]` Rent sysvar
+    ///   11. `[]` System program id
+    ///   12. `[writable]` Receipt PDA account of the payout, only if a reference is provided
+    ///   13. `[writable]` Idempotency marker PDA account, only if an idempotency key is provided
+    ///   14. `[writable]` (Optional) Render node PDA account of the destination
     DisburseFunds {
-        /// Amount of RNDR tokens to disburse
+        /// Amount of RNDR tokens to disburse, before the price multiplier of the Job tier
         amount: u64,
+        /// Optional external reference of the payout, such as an invoice hash, to record in a
+        /// Receipt. A zeroed reference writes a Receipt without one
+        reference: Option<[u8; 32]>,
+        /// Optional idempotency key of the disbursal, 16 or 32 bytes long
+        idempotency_key: Option<Vec<u8>>,
     },
-}
 
-impl RNDRInstruction {
-    /// Unpacks a byte buffer into a [RNDRInstruction](enum.RNDRInstruction.html).
-    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
-        let (&tag, rest) = input
-            .split_first()
-            .ok_or(RNDRError::InstructionUnpackError)?;
-        Ok(match tag {
-            0 => {
-                let (owner, _rest) = Self::unpack_pubkey(rest)?;
-                Self::InitEscrow { owner }
-            }
-            1 => {
-                let (new_owner, _rest) = Self::unpack_pubkey(rest)?;
-                Self::SetEscrowOwner { new_owner }
-            }
-            2 => {
-                let (amount, _rest) = Self::unpack_u64(rest)?;
-                Self::FundJob { amount }
-            }
-            3 => {
-                let (amount, _rest) = Self::unpack_u64(rest)?;
-                Self::DisburseFunds { amount }
-            }
-            _ => {
-                msg!("Instruction cannot be unpacked");
-                return Err(RNDRError::InstructionUnpackError.into());
-            }
-        })
-    }
+    // 4
+    /// Set the metadata of a Job.
+    /// Metadata can't be changed once funds have been disbursed from the Job or reserved for its
+    /// payouts.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Funder SOL account
+    ///   1. `[signer]` Job authority
+    ///   2. `[]` Job PDA account
+    ///   3. `[writable]` Job metadata PDA account
+    ///   4. `[]` Rent sysvar
+    ///   5. `[]` System program id
+    SetJobMetadata {
+        /// Hash of the scene or package being rendered
+        scene_hash: [u8; 32],
+        /// Optional content identifier of the scene, such as an IPFS CID
+        content_id: Vec<u8>,
+        /// First frame to render
+        frame_start: u32,
+        /// Last frame to render, inclusive
+        frame_end: u32,
+    },
 
-    fn unpack_u64(input: &[u8]) -> Result<(u64, &[u8]), ProgramError> {
-        if input.len() < 8 {
-            msg!("u64 cannot be unpacked");
-            return Err(RNDRError::InstructionUnpackError.into());
-        }
-        let (bytes, rest) = input.split_at(8);
-        let value = bytes
-            .get(..8)
-            .and_then(|slice| slice.try_into().ok())
-            .map(u64::from_le_bytes)
-            .ok_or(RNDRError::InstructionUnpackError)?;
-        Ok((value, rest))
-    }
+    // 5
+    /// Add a Milestone to a Job.
+    /// Once a Job has milestones, funds can only be disbursed up to the total of approved
+    /// milestones.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Funder SOL account
+    ///   1. `[signer]` Job authority
+    ///   2. `[writable]` Job PDA account
+    ///   3. `[writable]` Milestone PDA account for the next milestone index
+    ///   4. `[]` Rent sysvar
+    ///   5. `[]` System program id
+    AddMilestone {
+        /// Maximum amount of RNDR tokens that can be disbursed for the milestone
+        amount: u64,
+        /// Hash of the milestone description
+        description_hash: [u8; 32],
+    },
 
-    fn unpack_pubkey(input: &[u8]) -> Result<(Pubkey, &[u8]), ProgramError> {
-        if input.len() < PUBKEY_BYTES {
-            msg!("Pubkey cannot be unpacked");
-            return Err(RNDRError::InstructionUnpackError.into());
-        }
-        let (key, rest) = input.split_at(PUBKEY_BYTES);
-        let pk = Pubkey::new(key);
-        Ok((pk, rest))
-    }
+    // 6
+    /// Approve a Milestone of a Job, allowing its amount to be disbursed.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Job authority
+    ///   1. `[writable]` Job PDA account
+    ///   2. `[writable]` Milestone PDA account
+    ApproveMilestone,
 
-    /// Packs a [RNDRInstruction](enum.RNDRInstruction.html) into a byte buffer.
-    pub fn pack(&self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(size_of::<Self>());
-        match *self {
-            Self::InitEscrow { owner } => {
-                buf.push(0);
-                buf.extend_from_slice(&owner.to_bytes());
-            }
-            Self::SetEscrowOwner { new_owner } => {
-                buf.push(1);
-                buf.extend_from_slice(&new_owner.to_bytes());
-            }
-            Self::FundJob { amount } => {
-                buf.push(2);
-                buf.extend_from_slice(&amount.to_le_bytes());
-            }
-            Self::DisburseFunds { amount } => {
-                buf.push(3);
-                buf.extend_from_slice(&amount.to_le_bytes());
-            }
-        }
-        buf
-    }
-}
+    // 7
+    /// Post a merkle root of payouts from a Job and reserve their total. Leaf amounts are paid as
+    /// committed, the price multiplier of the Job tier must already be applied to them.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Funder SOL account
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[signer]` Escrow owner authority
+    ///   3. `[writable]` Job PDA account
+    ///   4. `[writable]` Payout root PDA account
+    ///   5. `[]` Clock sysvar
+    ///   6. `[]` Rent sysvar
+    ///   7. `[]` System program id
+    PostPayoutRoot {
+        /// Merkle root of the (index, node, amount, job) payout leaves
+        root: [u8; 32],
+        /// Total amount of RNDR tokens in the payout leaves
+        amount: u64,
+        /// Number of leaves in the merkle tree
+        leaf_count: u32,
+        /// Time after which unclaimed tokens can be returned to the Job
+        expiry: UnixTimestamp,
+    },
 
-/// Creates an 'InitEscrow' instruction.
-pub fn init_escrow(
-    program_id: Pubkey,
-    owner: Pubkey,
-    token_mint: Pubkey,
-    funder: Pubkey,
-) -> Instruction {
-    let (escrow, _bump_seed) = Pubkey::find_program_address(
-        &[b"escrow", token_mint.as_ref(), spl_token::id().as_ref()],
-        &program_id,
-    );
-    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
-    Instruction {
-        program_id,
-        accounts: vec![
-            AccountMeta::new_readonly(token_mint, false),
-            AccountMeta::new(funder, true),
-            AccountMeta::new(escrow, false),
-            AccountMeta::new(escrow_associated_token, false),
-            AccountMeta::new_readonly(rent::id(), false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
-        ],
-        data: RNDRInstruction::InitEscrow { owner }.pack(),
-    }
-}
+    // 8
+    /// Claim a payout leaf of a payout root with a merkle proof.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable]` Escrow PDA account
+    ///   2. `[writable]` Escrow ATA account
+    ///   3. `[writable]` Job PDA account
+    ///   4. `[writable]` Payout root PDA account
+    ///   5. `[writable]` Destination RNDR token account owned by the node of the leaf
+    ///   6. `[]` Clock sysvar
+    ///   7. `[]` Token program id
+    ///   8. `[writable]` (Optional) Render node PDA account of the destination
+    ClaimPayout {
+        /// Index of the leaf
+        index: u32,
+        /// Amount of RNDR tokens in the leaf
+        amount: u64,
+        /// Sibling hashes from the leaf up to the root
+        proof: Vec<[u8; 32]>,
+    },
 
-/// Creates a 'SetEscrowOwner' instruction.
-pub fn set_escrow_owner(
-    program_id: Pubkey,
-    escrow: Pubkey,
-    current_owner: Pubkey,
-    new_owner: Pubkey,
-) -> Instruction {
-    Instruction {
-        program_id,
-        accounts: vec![
-            AccountMeta::new(escrow, false),
-            AccountMeta::new_readonly(current_owner, true),
-        ],
-        data: RNDRInstruction::SetEscrowOwner { new_owner }.pack(),
-    }
-}
+    // 9
+    /// Return the unclaimed tokens of an expired payout root to its Job and close it.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Job PDA account
+    ///   1. `[writable]` Payout root PDA account
+    ///   2. `[writable]` Payout root rent payer SOL account
+    ///   3. `[]` Clock sysvar
+    ReclaimPayoutRoot,
 
-/// Creates a 'FundJob' instruction.
-pub fn fund_job(
-    program_id: Pubkey,
-    amount: u64,
-    token_mint: Pubkey,
-    funder: Pubkey,
-    source_token: Pubkey,
-    authority: Pubkey,
-) -> Instruction {
-    let (escrow, _bump_seed) = Pubkey::find_program_address(
-        &[b"escrow", token_mint.as_ref(), spl_token::id().as_ref()],
-        &program_id,
-    );
-    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
-    let (job, _bump_seed) =
-        Pubkey::find_program_address(&[b"job", escrow.as_ref(), authority.as_ref()], &program_id);
-    Instruction {
-        program_id,
-        accounts: vec![
-            AccountMeta::new_readonly(token_mint, false),
-            AccountMeta::new(funder, true),
-            AccountMeta::new(source_token, false),
-            AccountMeta::new_readonly(authority, true),
-            AccountMeta::new(escrow, false),
-            AccountMeta::new(escrow_associated_token, false),
-            AccountMeta::new(job, false),
-            AccountMeta::new_readonly(rent::id(), false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-        ],
-        data: RNDRInstruction::FundJob { amount }.pack(),
-    }
-}
+    // 10
+    /// Redeem a payout voucher signed by the Escrow owner, transferring funds from the Escrow and
+    /// debiting a Job. The previous instruction must be an ed25519 program instruction verifying
+    /// the Escrow owner's signature of the packed voucher. The voucher amount is paid as signed,
+    /// the price multiplier of the Job tier must already be applied to it.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable,signer]` Funder SOL account
+    ///   2. `[writable]` Escrow PDA account
+    ///   3. `[writable]` Escrow ATA account
+    ///   4. `[writable]` Job PDA account
+    ///   5. `[writable]` Destination RNDR token account
+    ///   6. `[writable]` Redeemed voucher PDA account
+    ///   7. `[]` Instructions sysvar
+    ///   8. `[]` Clock sysvar
+    ///   9. `[]` Rent sysvar
+    ///   10. `[]` System program id
+    ///   11. `[]` Token program id
+    ///   12. `[writable]` (Optional) Render node PDA account of the destination
+    RedeemVoucher {
+        /// Voucher signed by the Escrow owner
+        voucher: Voucher,
+    },
 
-/// Creates a 'DisburseFunds' instruction.
-pub fn disburse_funds(
-    program_id: Pubkey,
-    amount: u64,
-    token_mint: Pubkey,
-    destination_token: Pubkey,
-    job: Pubkey,
-    escrow_owner: Pubkey,
-) -> Instruction {
-    let (escrow, _bump_seed) = Pubkey::find_program_address(
-        &[b"escrow", token_mint.as_ref(), spl_token::id().as_ref()],
-        &program_id,
-    );
-    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
-    Instruction {
-        program_id,
-        accounts: vec![
-            AccountMeta::new_readonly(token_mint, false),
-            AccountMeta::new(escrow, false),
-            AccountMeta::new_readonly(escrow_owner, true),
-            AccountMeta::new(escrow_associated_token, false),
-            AccountMeta::new(job, false),
-            AccountMeta::new(destination_token, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
+    // 11
+    /// Transfer funds from an Escrow to several destinations, debiting several Jobs.
+    /// Transfers to the same destination are grouped into a single token transfer.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable]` Escrow PDA account
+    ///   2. `[signer]` Escrow owner authority
+    ///   3. `[writable]` Escrow ATA account
+    ///   4. `[]` Token program id
+    ///   5. `[writable]` Job PDA accounts, as many as the job count
+    ///   6. `[writable]` Destination RNDR token accounts, making up the remaining accounts. If the
+    ///                     Escrow requires registered render nodes, each destination is followed by
+    ///                     the `[writable]` render node PDA account it belongs to
+    BatchDisburse {
+        /// Number of Job accounts, the remaining accounts are destinations
+        job_count: u8,
+        /// Transfers to make
+        disbursements: Vec<Disbursement>,
+    },
+
+    // 12
+    /// Initialize the program config. Only the upgrade authority of the RNDR program can
+    /// initialize it.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Funder SOL account
+    ///   1. `[signer]` RNDR program upgrade authority
+    ///   2. `[]` RNDR program data account
+    ///   3. `[writable]` Program config PDA account
+    ///   4. `[]` Rent sysvar
+    ///   5. `[]` System program id
+    InitProgramConfig {
+        /// Admin authority that can update the config and initialize Escrows
+        admin: Pubkey,
+        /// Token mints Escrows can be created for
+        allowed_mints: Vec<Pubkey>,
+        /// Token programs Escrows can be created for
+        allowed_token_programs: Vec<Pubkey>,
+        /// Owner of new Escrows initialized without an owner
+        default_escrow_owner: Pubkey,
+        /// Owner of the token accounts slashed stakes can be sent to, none if default
+        treasury: Pubkey,
+    },
+
+    // 13
+    /// Update the program config.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Program config PDA account
+    ///   1. `[signer]` Current program config admin
+    UpdateProgramConfig {
+        /// New admin authority
+        admin: Pubkey,
+        /// Token mints Escrows can be created for
+        allowed_mints: Vec<Pubkey>,
+        /// Token programs Escrows can be created for
+        allowed_token_programs: Vec<Pubkey>,
+        /// Owner of new Escrows initialized without an owner
+        default_escrow_owner: Pubkey,
+        /// Owner of the token accounts slashed stakes can be sent to, none if default
+        treasury: Pubkey,
+    },
+
+    // 14
+    /// Close an empty Job, returning its rent to the account that paid it.
+    /// The Job can't have funds left in escrow or reserved for pending payouts. Accounts of the Job
+    /// such as milestones are seeded by its generation, so they are left to the closed Job and a
+    /// Job created again at the same address starts with none.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[signer]` Job authority or Escrow owner authority
+    ///   2. `[writable]` Job PDA account
+    ///   3. `[writable]` Job rent payer SOL account
+    CloseJob,
+
+    // 15
+    /// Close an Escrow and its token account, sending their rent to a destination of the owner's
+    /// choosing. The Escrow can't hold funds for Jobs or have open Jobs. Tokens left in the Escrow
+    /// ATA that aren't held for any Job, such as tokens sent to it directly, are swept to a token
+    /// account of the owner's choosing first, which must be provided if there are any.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable]` Escrow PDA account
+    ///   2. `[signer]` Escrow owner authority
+    ///   3. `[writable]` Escrow ATA account
+    ///   4. `[writable]` Destination SOL account
+    ///   5. `[]` Token program id
+    ///   6. `[writable]` (Optional) Leftover RNDR token account
+    CloseEscrow,
+
+    // 16
+    /// Start migrating an Escrow to a new RNDR token mint. Creates the Escrow of the new mint
+    /// with the same owner, namespace, tier multipliers and node registry requirement, and links
+    /// the old Escrow to it. The old Escrow stops accepting funds, and Job balances are moved over
+    /// with MigrateJob.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Old RNDR SPL Token mint
+    ///   1. `[writable]` Old Escrow PDA account
+    ///   2. `[signer]` Escrow owner authority
+    ///   3. `[]` New RNDR SPL Token mint
+    ///   4. `[writable, signer]` Funder account
+    ///   5. `[]` Program config PDA account, may be uninitialized
+    ///   6. `[writable]` New Escrow PDA account
+    ///   7. `[writable]` New Escrow ATA account
+    ///   8. `[]` Rent sysvar
+    ///   9. `[]` System program id
+    ///   10. `[]` Token program id
+    ///   11. `[]` Associated Token program id
+    MigrateEscrow,
+
+    // 17
+    /// Move the balance of a Job 1:1 to the Escrow its Escrow is migrating to. The operator
+    /// provides the new tokens and receives the old tokens of the Job in exchange. The Job
+    /// can't have funds reserved for pending payouts, milestones, required render submissions or
+    /// a rate card, as those are kept in accounts of the old Job.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Old RNDR SPL Token mint
+    ///   1. `[writable]` Old Escrow PDA account
+    ///   2. `[signer]` Escrow owner authority
+    ///   3. `[writable]` Old Escrow ATA account
+    ///   4. `[writable]` Old Job PDA account
+    ///   5. `[writable]` Destination old RNDR token account
+    ///   6. `[]` New RNDR SPL Token mint
+    ///   7. `[writable]` New Escrow PDA account
+    ///   8. `[writable]` New Escrow ATA account
+    ///   9. `[writable]` New Job PDA account
+    ///   10. `[writable]` Source new RNDR token account
+    ///   11. `[signer]` Source token account authority
+    ///   12. `[writable, signer]` Funder account
+    ///   13. `[]` Rent sysvar
+    ///   14. `[]` System program id
+    ///   15. `[]` Token program id
+    MigrateJob,
+
+    // 18
+    /// Fund several Jobs with a single transfer of the total into the Escrow.
+    /// Jobs that don't exist yet are created.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable,signer]` Funder SOL account
+    ///   2. `[writable]` Source RNDR token account
+    ///   3. `[signer]` Source token account authority
+    ///   4. `[writable]` Escrow PDA account
+    ///   5. `[writable]` Escrow ATA account
+    ///   6. `[]` Rent sysvar
+    ///   7. `[]` System program id
+    ///   8. `[]` Token program id
+    ///   9. `[writable]` Job PDA accounts, one for each funding in order
+    BatchFundJob {
+        /// Job top ups to make
+        fundings: Vec<JobFunding>,
+    },
+
+    // 19
+    /// Create a schedule funding a Job every period. The Job authority must first approve the
+    /// Escrow as delegate of the source token account with `spl_token::approve`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable,signer]` Funder SOL account
+    ///   2. `[signer]` Job authority
+    ///   3. `[]` Source RNDR token account
+    ///   4. `[]` Escrow PDA account
+    ///   5. `[]` Job PDA account
+    ///   6. `[writable]` Funding schedule PDA account
+    ///   7. `[]` Rent sysvar
+    ///   8. `[]` System program id
+    ///   9. `[]` Token program id
+    InitFundingSchedule {
+        /// Amount of RNDR tokens to fund each period
+        amount: u64,
+        /// Length of a period in seconds
+        period: i64,
+        /// Time the first funding is due at
+        first_due: UnixTimestamp,
+    },
+
+    // 20
+    /// Fund a Job from its funding schedule once the period has elapsed. Anyone can crank a
+    /// schedule. The schedule is paused instead when the allowance of the Escrow runs out.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable]` Escrow PDA account
+    ///   2. `[writable]` Escrow ATA account
+    ///   3. `[writable]` Job PDA account
+    ///   4. `[writable]` Funding schedule PDA account
+    ///   5. `[writable]` Source RNDR token account
+    ///   6. `[]` Clock sysvar
+    ///   7. `[]` Token program id
+    CrankFunding,
+
+    // 21
+    /// Resume a paused funding schedule once the allowance of the Escrow is topped up.
+    /// Periods missed while paused are skipped.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Job authority
+    ///   1. `[writable]` Funding schedule PDA account
+    ///   2. `[]` Clock sysvar
+    ResumeFundingSchedule,
+
+    // 22
+    /// Close a funding schedule, returning its rent to the account that paid it.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Job authority
+    ///   1. `[writable]` Funding schedule PDA account
+    ///   2. `[writable]` Funding schedule rent payer SOL account
+    CloseFundingSchedule,
+
+    // 23
+    /// Post a payout from a Job and reserve its amount. The Job authority can challenge it
+    /// during the challenge period, after which anyone can finalize it.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Funder SOL account
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[signer]` Escrow owner authority
+    ///   3. `[writable]` Job PDA account
+    ///   4. `[]` Destination RNDR token account
+    ///   5. `[writable]` Pending payout PDA account
+    ///   6. `[]` Clock sysvar
+    ///   7. `[]` Rent sysvar
+    ///   8. `[]` System program id
+    ///   9. `[]` (Optional) Render node PDA account of the destination
+    PostPendingPayout {
+        /// Nonce distinguishing pending payouts of the same Job
+        nonce: u64,
+        /// Amount of RNDR tokens to pay out
+        amount: u64,
+    },
+
+    // 24
+    /// Cancel a pending payout, returning its amount to the Job. The Job authority can
+    /// challenge it during the challenge period, the Escrow owner can withdraw it any time
+    /// before it is finalized.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Escrow PDA account
+    ///   1. `[signer]` Job authority or Escrow owner authority
+    ///   2. `[writable]` Job PDA account
+    ///   3. `[writable]` Pending payout PDA account
+    ///   4. `[writable]` Pending payout rent payer SOL account
+    ///   5. `[]` Clock sysvar
+    ChallengePayout,
+
+    // 25
+    /// Transfer a pending payout to its destination once the challenge period is over.
+    /// Anyone can finalize a payout.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable]` Escrow PDA account
+    ///   2. `[writable]` Escrow ATA account
+    ///   3. `[writable]` Job PDA account
+    ///   4. `[writable]` Pending payout PDA account
+    ///   5. `[writable]` Destination RNDR token account
+    ///   6. `[writable]` Pending payout rent payer SOL account
+    ///   7. `[]` Clock sysvar
+    ///   8. `[]` Token program id
+    ///   9. `[writable]` (Optional) Render node PDA account of the destination
+    FinalizePayout,
+
+    // 26
+    /// Submit the frames a render node rendered for a Job, with the price it quotes for them.
+    /// A rejected or pending submission can be replaced, an accepted one can't.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Render node SOL account
+    ///   1. `[]` Job PDA account
+    ///   2. `[writable]` Render submission PDA account
+    ///   3. `[]` Rent sysvar
+    ///   4. `[]` System program id
+    SubmitRender {
+        /// Hash of the rendered frames
+        frames_hash: [u8; 32],
+        /// First frame rendered
+        frame_start: u32,
+        /// Last frame rendered
+        frame_end: u32,
+        /// Price quoted for the frames
+        price: u64,
+    },
+
+    // 27
+    /// Accept or reject a pending render submission.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Job authority
+    ///   1. `[]` Job PDA account
+    ///   2. `[writable]` Render submission PDA account
+    ReviewSubmission {
+        /// Whether the submission is accepted
+        accept: bool,
+    },
+
+    // 28
+    /// Pay a render node for an accepted submission, up to the price it quoted.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable]` Escrow PDA account
+    ///   2. `[signer]` Escrow owner authority
+    ///   3. `[writable]` Escrow ATA account
+    ///   4. `[writable]` Job PDA account
+    ///   5. `[writable]` Render submission PDA account
+    ///   6. `[writable]` Render node RNDR token account
+    ///   7. `[]` Token program id
+    ///   8. `[writable]` (Optional) Render node PDA account of the destination
+    PaySubmission {
+        /// Amount of RNDR tokens to pay
+        amount: u64,
+    },
+
+    // 29
+    /// Require render submissions for payouts from a Job. While required, the Job only pays
+    /// out through `PaySubmission`. It can't be turned on while payouts are pending.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Job authority
+    ///   1. `[writable]` Job PDA account
+    SetSubmissionsRequired {
+        /// Whether payouts require an accepted render submission
+        required: bool,
+    },
+
+    // 30
+    /// Set the rate card of a Job, pricing it per frame. The rate card can't be changed once
+    /// frames have been paid. Once it has a rate card, the Job only pays out through
+    /// `ClaimFrames`, so it can't be set while payouts are pending.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Funder SOL account
+    ///   1. `[signer]` Job authority
+    ///   2. `[writable]` Job PDA account
+    ///   3. `[writable]` Rate card PDA account
+    ///   4. `[]` Rent sysvar
+    ///   5. `[]` System program id
+    SetRateCard {
+        /// Amount of RNDR tokens paid per frame
+        price_per_frame: u64,
+        /// Total number of frames of the Job
+        total_frames: u32,
+    },
+
+    // 31
+    /// Disburse funds from a Job for a number of frames at the price of its rate card.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable]` Escrow PDA account
+    ///   2. `[signer]` Escrow owner authority
+    ///   3. `[writable]` Escrow ATA account
+    ///   4. `[writable]` Job PDA account
+    ///   5. `[writable]` Rate card PDA account
+    ///   6. `[writable]` Destination RNDR token account
+    ///   7. `[]` Token program id
+    ///   8. `[writable]` (Optional) Render node PDA account of the destination
+    ClaimFrames {
+        /// Number of frames to pay
+        count: u32,
+    },
+
+    // 32
+    /// Set the price multiplier of each priority tier of an Escrow, between 1x and 10x. A Job keeps
+    /// the multiplier of its tier from when the tier was selected, so new multipliers only apply to
+    /// Jobs selecting a tier afterwards. Payout amounts are base prices, every payout path applies
+    /// the multiplier of the Job to them, when funds are reserved for payouts that reserve them.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[signer]` Escrow owner authority
+    SetTierMultipliers {
+        /// Price multiplier of each tier, in basis points
+        multipliers: [u32; TIER_COUNT],
+    },
+
+    // 33
+    /// Select the priority tier of a Job, taking the current multiplier of the tier. Once funds
+    /// have been disbursed from the Job, the Escrow owner must consent to the change as well. The
+    /// tier can't be changed while payouts from the Job are pending.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Escrow PDA account
+    ///   1. `[signer]` Job authority
+    ///   2. `[writable]` Job PDA account
+    ///   3. `[signer]` (Optional) Escrow owner authority
+    SetJobTier {
+        /// Priority tier, 0 to make the Job untiered
+        tier: u8,
+    },
+
+    // 34
+    /// Assign work on a Job to a render node, reserving part of the Job balance for it until
+    /// the deadline. The reservation can only be paid to that node.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Funder SOL account
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[signer]` Escrow owner authority
+    ///   3. `[writable]` Job PDA account
+    ///   4. `[]` Render node
+    ///   5. `[writable]` Assignment PDA account
+    ///   6. `[]` Clock sysvar
+    ///   7. `[]` Rent sysvar
+    ///   8. `[]` System program id
+    ///   9. `[]` (Optional) Stake PDA account of the render node, required if the Escrow
+    ///           requires a minimum stake
+    CreateAssignment {
+        /// Amount of RNDR tokens to reserve, before the price multiplier of the Job tier
+        amount: u64,
+        /// Time after which the reservation can be released back to the Job
+        deadline: UnixTimestamp,
+    },
+
+    // 35
+    /// Pay a render node from its assignment. The assignment is closed once fully paid.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable]` Escrow PDA account
+    ///   2. `[signer]` Escrow owner authority
+    ///   3. `[writable]` Escrow ATA account
+    ///   4. `[writable]` Job PDA account
+    ///   5. `[writable]` Assignment PDA account
+    ///   6. `[writable]` Render node RNDR token account
+    ///   7. `[writable]` Assignment rent payer SOL account
+    ///   8. `[]` Token program id
+    ///   9. `[writable]` (Optional) Render node PDA account of the destination
+    PayAssignment {
+        /// Amount of RNDR tokens to pay
+        amount: u64,
+    },
+
+    // 36
+    /// Release what is left of an expired assignment back to its Job. Anyone can release an
+    /// expired assignment.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Job PDA account
+    ///   1. `[writable]` Assignment PDA account
+    ///   2. `[writable]` Assignment rent payer SOL account
+    ///   3. `[]` Clock sysvar
+    ReleaseAssignment,
+
+    // 37
+    /// Register a render node in the node registry.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Node operator SOL account
+    ///   1. `[]` Payout RNDR token account
+    ///   2. `[writable]` Render node PDA account
+    ///   3. `[]` Rent sysvar
+    ///   4. `[]` System program id
+    RegisterNode {
+        /// Priority tier of the node, 0 if the node is untiered
+        tier: u8,
+    },
+
+    // 38
+    /// Update the payout token account and tier of a registered render node.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Node operator
+    ///   1. `[]` Payout RNDR token account
+    ///   2. `[writable]` Render node PDA account
+    UpdateNode {
+        /// Priority tier of the node, 0 if the node is untiered
+        tier: u8,
+    },
+
+    // 39
+    /// Suspend a registered render node, or reinstate it. Escrows that only disburse to
+    /// registered render nodes can't pay suspended nodes.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Program config PDA account
+    ///   1. `[signer]` Program config admin
+    ///   2. `[writable]` Render node PDA account
+    SuspendNode {
+        /// Whether the node is suspended
+        suspended: bool,
+    },
+
+    // 40
+    /// Require disbursal destinations of an Escrow to belong to an active registered render
+    /// node. While required, every payout is made with the render node of its destination
+    /// provided, and payout roots are checked leaf by leaf as they are claimed.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[signer]` Escrow owner authority
+    SetNodeRegistryRequired {
+        /// Whether disbursal destinations must belong to a registered render node
+        required: bool,
+    },
+
+    // 41
+    /// Set the amount of tokens render nodes must stake in an Escrow before `CreateAssignment`
+    /// can assign them work.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[signer]` Escrow owner authority
+    SetMinStake {
+        /// Minimum amount of RNDR tokens staked, 0 to not require a stake
+        amount: u64,
+    },
+
+    // 42
+    /// Stake tokens of a node operator into the stake vault of an Escrow.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable,signer]` Node operator SOL account
+    ///   2. `[writable]` Source RNDR token account
+    ///                     $authority can transfer $amount
+    ///   3. `[signer]` Source token account authority ($authority)
+    ///   4. `[]` Escrow PDA account
+    ///   5. `[]` Stake vault PDA account
+    ///   6. `[writable]` Stake vault ATA account
+    ///   7. `[writable]` Stake PDA account
+    ///   8. `[]` Rent sysvar
+    ///   9. `[]` System program id
+    ///   10. `[]` Token program id
+    ///   11. `[]` Associated token program id
+    Stake {
+        /// Amount of RNDR tokens to stake
+        amount: u64,
+    },
+
+    // 43
+    /// Request to unstake tokens. They can be withdrawn with `Unstake` once the cooldown has
+    /// ended, and can still be slashed until then. Each request restarts the cooldown.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Node operator
+    ///   1. `[writable]` Stake PDA account
+    ///   2. `[]` Clock sysvar
+    RequestUnstake {
+        /// Amount of RNDR tokens to unstake
+        amount: u64,
+    },
+
+    // 44
+    /// Withdraw the unstaking tokens of a stake once their cooldown has ended.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[signer]` Node operator
+    ///   3. `[writable]` Stake PDA account
+    ///   4. `[]` Stake vault PDA account
+    ///   5. `[writable]` Stake vault ATA account
+    ///   6. `[writable]` Destination RNDR token account
+    ///   7. `[]` Clock sysvar
+    ///   8. `[]` Token program id
+    Unstake,
+
+    // 45
+    /// Slash the stake of a render node that failed its work, into the balance of the affected
+    /// Job or a token account of the treasury set in the program config. Staked tokens are
+    /// slashed before unstaking ones.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable]` Escrow PDA account
+    ///   2. `[signer]` Escrow owner authority or program config admin
+    ///   3. `[]` Program config PDA account
+    ///   4. `[writable]` Stake PDA account
+    ///   5. `[]` Stake vault PDA account
+    ///   6. `[writable]` Stake vault ATA account
+    ///   7. `[writable]` Job PDA account to credit, or RNDR token account of the treasury
+    ///   8. `[writable]` Escrow ATA account
+    ///   9. `[]` Token program id
+    SlashStake {
+        /// Amount of RNDR tokens to slash
+        amount: u64,
+    },
+
+    // 46
+    /// Close an idempotency marker once its lifetime has passed, returning its rent to the account
+    /// that paid it. Only the rent payer can close the marker, as the idempotency key can be used
+    /// again once it is closed.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Idempotency marker PDA account
+    ///   1. `[writable,signer]` Idempotency marker rent payer SOL account
+    ///   2. `[]` Clock sysvar
+    CloseIdempotencyMarker,
+}
+
+impl RNDRInstruction {
+    /// Unpacks a byte buffer into a [RNDRInstruction](enum.RNDRInstruction.html).
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (&tag, rest) = input
+            .split_first()
+            .ok_or(RNDRError::InstructionUnpackError)?;
+        Ok(match tag {
+            0 => {
+                let (owner, rest) = Self::unpack_pubkey(rest)?;
+                let namespace = if rest.is_empty() {
+                    None
+                } else {
+                    let (namespace, _rest) = Self::unpack_pubkey(rest)?;
+                    Some(namespace)
+                };
+                Self::InitEscrow { owner, namespace }
+            }
+            1 => {
+                let (new_owner, _rest) = Self::unpack_pubkey(rest)?;
+                Self::SetEscrowOwner { new_owner }
+            }
+            2 => {
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                let tier = if rest.is_empty() {
+                    0
+                } else {
+                    Self::unpack_u8(rest)?.0
+                };
+                Self::FundJob { amount, tier }
+            }
+            3 => {
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                let (reference, idempotency_key) = if rest.is_empty() {
+                    (None, None)
+                } else {
+                    let (has_reference, rest) = Self::unpack_bool(rest)?;
+                    let (reference, rest) = if has_reference {
+                        let (reference, rest) = Self::unpack_bytes32(rest)?;
+                        (Some(reference), rest)
+                    } else {
+                        (None, rest)
+                    };
+                    let (idempotency_key, _rest) = Self::unpack_idempotency_key(rest)?;
+                    (reference, idempotency_key)
+                };
+                Self::DisburseFunds {
+                    amount,
+                    reference,
+                    idempotency_key,
+                }
+            }
+            4 => {
+                let (scene_hash, rest) = Self::unpack_bytes32(rest)?;
+                let (content_id, rest) = Self::unpack_content_id(rest)?;
+                let (frame_start, rest) = Self::unpack_u32(rest)?;
+                let (frame_end, _rest) = Self::unpack_u32(rest)?;
+                Self::SetJobMetadata {
+                    scene_hash,
+                    content_id,
+                    frame_start,
+                    frame_end,
+                }
+            }
+            5 => {
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                let (description_hash, _rest) = Self::unpack_bytes32(rest)?;
+                Self::AddMilestone {
+                    amount,
+                    description_hash,
+                }
+            }
+            6 => Self::ApproveMilestone,
+            7 => {
+                let (root, rest) = Self::unpack_bytes32(rest)?;
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                let (leaf_count, rest) = Self::unpack_u32(rest)?;
+                let (expiry, _rest) = Self::unpack_i64(rest)?;
+                Self::PostPayoutRoot {
+                    root,
+                    amount,
+                    leaf_count,
+                    expiry,
+                }
+            }
+            8 => {
+                let (index, rest) = Self::unpack_u32(rest)?;
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                let (proof, _rest) = Self::unpack_proof(rest)?;
+                Self::ClaimPayout {
+                    index,
+                    amount,
+                    proof,
+                }
+            }
+            9 => Self::ReclaimPayoutRoot,
+            10 => {
+                let (voucher, _rest) = Self::unpack_voucher(rest)?;
+                Self::RedeemVoucher { voucher }
+            }
+            11 => {
+                let (job_count, rest) = Self::unpack_u8(rest)?;
+                let (disbursements, _rest) = Self::unpack_disbursements(rest)?;
+                Self::BatchDisburse {
+                    job_count,
+                    disbursements,
+                }
+            }
+            12 => {
+                let (admin, rest) = Self::unpack_pubkey(rest)?;
+                let (allowed_mints, rest) = Self::unpack_pubkeys(rest, MAX_ALLOWED_MINTS)?;
+                let (allowed_token_programs, rest) =
+                    Self::unpack_pubkeys(rest, MAX_ALLOWED_TOKEN_PROGRAMS)?;
+                let (default_escrow_owner, rest) = Self::unpack_pubkey(rest)?;
+                let (treasury, _rest) = Self::unpack_pubkey(rest)?;
+                Self::InitProgramConfig {
+                    admin,
+                    allowed_mints,
+                    allowed_token_programs,
+                    default_escrow_owner,
+                    treasury,
+                }
+            }
+            13 => {
+                let (admin, rest) = Self::unpack_pubkey(rest)?;
+                let (allowed_mints, rest) = Self::unpack_pubkeys(rest, MAX_ALLOWED_MINTS)?;
+                let (allowed_token_programs, rest) =
+                    Self::unpack_pubkeys(rest, MAX_ALLOWED_TOKEN_PROGRAMS)?;
+                let (default_escrow_owner, rest) = Self::unpack_pubkey(rest)?;
+                let (treasury, _rest) = Self::unpack_pubkey(rest)?;
+                Self::UpdateProgramConfig {
+                    admin,
+                    allowed_mints,
+                    allowed_token_programs,
+                    default_escrow_owner,
+                    treasury,
+                }
+            }
+            14 => Self::CloseJob,
+            15 => Self::CloseEscrow,
+            16 => Self::MigrateEscrow,
+            17 => Self::MigrateJob,
+            18 => {
+                let (fundings, _rest) = Self::unpack_fundings(rest)?;
+                Self::BatchFundJob { fundings }
+            }
+            19 => {
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                let (period, rest) = Self::unpack_i64(rest)?;
+                let (first_due, _rest) = Self::unpack_i64(rest)?;
+                Self::InitFundingSchedule {
+                    amount,
+                    period,
+                    first_due,
+                }
+            }
+            20 => Self::CrankFunding,
+            21 => Self::ResumeFundingSchedule,
+            22 => Self::CloseFundingSchedule,
+            23 => {
+                let (nonce, rest) = Self::unpack_u64(rest)?;
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::PostPendingPayout { nonce, amount }
+            }
+            24 => Self::ChallengePayout,
+            25 => Self::FinalizePayout,
+            26 => {
+                let (frames_hash, rest) = Self::unpack_bytes32(rest)?;
+                let (frame_start, rest) = Self::unpack_u32(rest)?;
+                let (frame_end, rest) = Self::unpack_u32(rest)?;
+                let (price, _rest) = Self::unpack_u64(rest)?;
+                Self::SubmitRender {
+                    frames_hash,
+                    frame_start,
+                    frame_end,
+                    price,
+                }
+            }
+            27 => {
+                let (accept, _rest) = Self::unpack_bool(rest)?;
+                Self::ReviewSubmission { accept }
+            }
+            28 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::PaySubmission { amount }
+            }
+            29 => {
+                let (required, _rest) = Self::unpack_bool(rest)?;
+                Self::SetSubmissionsRequired { required }
+            }
+            30 => {
+                let (price_per_frame, rest) = Self::unpack_u64(rest)?;
+                let (total_frames, _rest) = Self::unpack_u32(rest)?;
+                Self::SetRateCard {
+                    price_per_frame,
+                    total_frames,
+                }
+            }
+            31 => {
+                let (count, _rest) = Self::unpack_u32(rest)?;
+                Self::ClaimFrames { count }
+            }
+            32 => {
+                let mut multipliers = [0; TIER_COUNT];
+                let mut rest = rest;
+                for multiplier in multipliers.iter_mut() {
+                    let (value, remaining) = Self::unpack_u32(rest)?;
+                    *multiplier = value;
+                    rest = remaining;
+                }
+                Self::SetTierMultipliers { multipliers }
+            }
+            33 => {
+                let (tier, _rest) = Self::unpack_u8(rest)?;
+                Self::SetJobTier { tier }
+            }
+            34 => {
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                let (deadline, _rest) = Self::unpack_i64(rest)?;
+                Self::CreateAssignment { amount, deadline }
+            }
+            35 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::PayAssignment { amount }
+            }
+            36 => Self::ReleaseAssignment,
+            37 => {
+                let (tier, _rest) = Self::unpack_u8(rest)?;
+                Self::RegisterNode { tier }
+            }
+            38 => {
+                let (tier, _rest) = Self::unpack_u8(rest)?;
+                Self::UpdateNode { tier }
+            }
+            39 => {
+                let (suspended, _rest) = Self::unpack_bool(rest)?;
+                Self::SuspendNode { suspended }
+            }
+            40 => {
+                let (required, _rest) = Self::unpack_bool(rest)?;
+                Self::SetNodeRegistryRequired { required }
+            }
+            41 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::SetMinStake { amount }
+            }
+            42 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::Stake { amount }
+            }
+            43 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::RequestUnstake { amount }
+            }
+            44 => Self::Unstake,
+            45 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::SlashStake { amount }
+            }
+            46 => Self::CloseIdempotencyMarker,
+            _ => {
+                msg!("Instruction cannot be unpacked");
+                return Err(RNDRError::InstructionUnpackError.into());
+            }
+        })
+    }
+
+    fn unpack_u8(input: &[u8]) -> Result<(u8, &[u8]), ProgramError> {
+        if input.is_empty() {
+            msg!("u8 cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (bytes, rest) = input.split_at(1);
+        let value = bytes
+            .get(..1)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u8::from_le_bytes)
+            .ok_or(RNDRError::InstructionUnpackError)?;
+        Ok((value, rest))
+    }
+
+    fn unpack_bool(input: &[u8]) -> Result<(bool, &[u8]), ProgramError> {
+        let (value, rest) = Self::unpack_u8(input)?;
+        let value = match value {
+            0 => false,
+            1 => true,
+            _ => {
+                msg!("bool cannot be unpacked");
+                return Err(RNDRError::InstructionUnpackError.into());
+            }
+        };
+        Ok((value, rest))
+    }
+
+    fn unpack_u32(input: &[u8]) -> Result<(u32, &[u8]), ProgramError> {
+        if input.len() < 4 {
+            msg!("u32 cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (bytes, rest) = input.split_at(4);
+        let value = bytes
+            .get(..4)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u32::from_le_bytes)
+            .ok_or(RNDRError::InstructionUnpackError)?;
+        Ok((value, rest))
+    }
+
+    fn unpack_u64(input: &[u8]) -> Result<(u64, &[u8]), ProgramError> {
+        if input.len() < 8 {
+            msg!("u64 cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (bytes, rest) = input.split_at(8);
+        let value = bytes
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(RNDRError::InstructionUnpackError)?;
+        Ok((value, rest))
+    }
+
+    fn unpack_i64(input: &[u8]) -> Result<(i64, &[u8]), ProgramError> {
+        if input.len() < 8 {
+            msg!("i64 cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (bytes, rest) = input.split_at(8);
+        let value = bytes
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(i64::from_le_bytes)
+            .ok_or(RNDRError::InstructionUnpackError)?;
+        Ok((value, rest))
+    }
+
+    fn unpack_pubkey(input: &[u8]) -> Result<(Pubkey, &[u8]), ProgramError> {
+        if input.len() < PUBKEY_BYTES {
+            msg!("Pubkey cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (key, rest) = input.split_at(PUBKEY_BYTES);
+        let pk = Pubkey::new(key);
+        Ok((pk, rest))
+    }
+
+    fn unpack_pubkeys(input: &[u8], max_len: usize) -> Result<(Vec<Pubkey>, &[u8]), ProgramError> {
+        let (len, mut rest) = Self::unpack_u8(input)?;
+        if len as usize > max_len {
+            msg!("Pubkeys cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let mut pubkeys = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            let (pubkey, remaining) = Self::unpack_pubkey(rest)?;
+            pubkeys.push(pubkey);
+            rest = remaining;
+        }
+        Ok((pubkeys, rest))
+    }
+
+    fn unpack_bytes32(input: &[u8]) -> Result<([u8; 32], &[u8]), ProgramError> {
+        if input.len() < 32 {
+            msg!("32 bytes cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (bytes, rest) = input.split_at(32);
+        let value = bytes
+            .try_into()
+            .map_err(|_| RNDRError::InstructionUnpackError)?;
+        Ok((value, rest))
+    }
+
+    fn unpack_proof(input: &[u8]) -> Result<(Vec<[u8; 32]>, &[u8]), ProgramError> {
+        let (len, mut rest) = Self::unpack_u8(input)?;
+        if len as usize > MAX_PROOF_LEN {
+            msg!("Merkle proof cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let mut proof = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            let (hash, remaining) = Self::unpack_bytes32(rest)?;
+            proof.push(hash);
+            rest = remaining;
+        }
+        Ok((proof, rest))
+    }
+
+    fn unpack_voucher(input: &[u8]) -> Result<(Voucher, &[u8]), ProgramError> {
+        if input.len() < Voucher::LEN {
+            msg!("Voucher cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (bytes, rest) = input.split_at(Voucher::LEN);
+        let voucher = Voucher::unpack_from_slice(bytes)?;
+        Ok((voucher, rest))
+    }
+
+    fn unpack_disbursements(input: &[u8]) -> Result<(Vec<Disbursement>, &[u8]), ProgramError> {
+        let (len, mut rest) = Self::unpack_u8(input)?;
+        if len as usize > MAX_BATCH_DISBURSEMENTS {
+            msg!("Disbursements cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let mut disbursements = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            let (job_index, remaining) = Self::unpack_u8(rest)?;
+            let (destination_index, remaining) = Self::unpack_u8(remaining)?;
+            let (amount, remaining) = Self::unpack_u64(remaining)?;
+            disbursements.push(Disbursement {
+                job_index,
+                destination_index,
+                amount,
+            });
+            rest = remaining;
+        }
+        Ok((disbursements, rest))
+    }
+
+    fn unpack_fundings(input: &[u8]) -> Result<(Vec<JobFunding>, &[u8]), ProgramError> {
+        let (len, mut rest) = Self::unpack_u8(input)?;
+        if len as usize > MAX_BATCH_FUNDINGS {
+            msg!("Fundings cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let mut fundings = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            let (authority, remaining) = Self::unpack_pubkey(rest)?;
+            let (amount, remaining) = Self::unpack_u64(remaining)?;
+            fundings.push(JobFunding { authority, amount });
+            rest = remaining;
+        }
+        Ok((fundings, rest))
+    }
+
+    fn unpack_content_id(input: &[u8]) -> Result<(Vec<u8>, &[u8]), ProgramError> {
+        let (len, rest) = Self::unpack_u8(input)?;
+        let len = len as usize;
+        if len > MAX_CONTENT_ID_LEN || rest.len() < len {
+            msg!("Content identifier cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (content_id, rest) = rest.split_at(len);
+        Ok((content_id.to_vec(), rest))
+    }
+
+    fn unpack_idempotency_key(input: &[u8]) -> Result<(Option<Vec<u8>>, &[u8]), ProgramError> {
+        let (len, rest) = Self::unpack_u8(input)?;
+        let len = len as usize;
+        if len == 0 {
+            return Ok((None, rest));
+        }
+        if rest.len() < len || !is_valid_idempotency_key(&rest[..len]) {
+            msg!("Idempotency key cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (idempotency_key, rest) = rest.split_at(len);
+        Ok((Some(idempotency_key.to_vec()), rest))
+    }
+
+    /// Packs a [RNDRInstruction](enum.RNDRInstruction.html) into a byte buffer.
+    pub fn pack(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(size_of::<Self>());
+        match *self {
+            Self::InitEscrow { owner, namespace } => {
+                buf.push(0);
+                buf.extend_from_slice(&owner.to_bytes());
+                if let Some(namespace) = namespace {
+                    buf.extend_from_slice(&namespace.to_bytes());
+                }
+            }
+            Self::SetEscrowOwner { new_owner } => {
+                buf.push(1);
+                buf.extend_from_slice(&new_owner.to_bytes());
+            }
+            Self::FundJob { amount, tier } => {
+                buf.push(2);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                if tier != 0 {
+                    buf.push(tier);
+                }
+            }
+            Self::DisburseFunds {
+                amount,
+                reference,
+                ref idempotency_key,
+            } => {
+                buf.push(3);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                if reference.is_some() || idempotency_key.is_some() {
+                    match reference {
+                        Some(reference) => {
+                            buf.push(1);
+                            buf.extend_from_slice(&reference);
+                        }
+                        None => buf.push(0),
+                    }
+                    match idempotency_key {
+                        Some(idempotency_key) => {
+                            buf.push(idempotency_key.len() as u8);
+                            buf.extend_from_slice(idempotency_key);
+                        }
+                        None => buf.push(0),
+                    }
+                }
+            }
+            Self::SetJobMetadata {
+                scene_hash,
+                ref content_id,
+                frame_start,
+                frame_end,
+            } => {
+                buf.push(4);
+                buf.extend_from_slice(&scene_hash);
+                buf.push(content_id.len() as u8);
+                buf.extend_from_slice(content_id);
+                buf.extend_from_slice(&frame_start.to_le_bytes());
+                buf.extend_from_slice(&frame_end.to_le_bytes());
+            }
+            Self::AddMilestone {
+                amount,
+                description_hash,
+            } => {
+                buf.push(5);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&description_hash);
+            }
+            Self::ApproveMilestone => {
+                buf.push(6);
+            }
+            Self::PostPayoutRoot {
+                root,
+                amount,
+                leaf_count,
+                expiry,
+            } => {
+                buf.push(7);
+                buf.extend_from_slice(&root);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&leaf_count.to_le_bytes());
+                buf.extend_from_slice(&expiry.to_le_bytes());
+            }
+            Self::ClaimPayout {
+                index,
+                amount,
+                ref proof,
+            } => {
+                buf.push(8);
+                buf.extend_from_slice(&index.to_le_bytes());
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.push(proof.len() as u8);
+                for hash in proof {
+                    buf.extend_from_slice(hash);
+                }
+            }
+            Self::ReclaimPayoutRoot => {
+                buf.push(9);
+            }
+            Self::RedeemVoucher { ref voucher } => {
+                buf.push(10);
+                let mut data = [0; Voucher::LEN];
+                voucher.pack_into_slice(&mut data);
+                buf.extend_from_slice(&data);
+            }
+            Self::BatchDisburse {
+                job_count,
+                ref disbursements,
+            } => {
+                buf.push(11);
+                buf.push(job_count);
+                buf.push(disbursements.len() as u8);
+                for disbursement in disbursements {
+                    buf.push(disbursement.job_index);
+                    buf.push(disbursement.destination_index);
+                    buf.extend_from_slice(&disbursement.amount.to_le_bytes());
+                }
+            }
+            Self::InitProgramConfig {
+                admin,
+                ref allowed_mints,
+                ref allowed_token_programs,
+                default_escrow_owner,
+                treasury,
+            } => {
+                buf.push(12);
+                Self::pack_program_config(
+                    &mut buf,
+                    admin,
+                    allowed_mints,
+                    allowed_token_programs,
+                    default_escrow_owner,
+                    treasury,
+                );
+            }
+            Self::UpdateProgramConfig {
+                admin,
+                ref allowed_mints,
+                ref allowed_token_programs,
+                default_escrow_owner,
+                treasury,
+            } => {
+                buf.push(13);
+                Self::pack_program_config(
+                    &mut buf,
+                    admin,
+                    allowed_mints,
+                    allowed_token_programs,
+                    default_escrow_owner,
+                    treasury,
+                );
+            }
+            Self::CloseJob => {
+                buf.push(14);
+            }
+            Self::CloseEscrow => {
+                buf.push(15);
+            }
+            Self::MigrateEscrow => {
+                buf.push(16);
+            }
+            Self::MigrateJob => {
+                buf.push(17);
+            }
+            Self::BatchFundJob { ref fundings } => {
+                buf.push(18);
+                buf.push(fundings.len() as u8);
+                for funding in fundings {
+                    buf.extend_from_slice(funding.authority.as_ref());
+                    buf.extend_from_slice(&funding.amount.to_le_bytes());
+                }
+            }
+            Self::InitFundingSchedule {
+                amount,
+                period,
+                first_due,
+            } => {
+                buf.push(19);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&period.to_le_bytes());
+                buf.extend_from_slice(&first_due.to_le_bytes());
+            }
+            Self::CrankFunding => {
+                buf.push(20);
+            }
+            Self::ResumeFundingSchedule => {
+                buf.push(21);
+            }
+            Self::CloseFundingSchedule => {
+                buf.push(22);
+            }
+            Self::PostPendingPayout { nonce, amount } => {
+                buf.push(23);
+                buf.extend_from_slice(&nonce.to_le_bytes());
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::ChallengePayout => {
+                buf.push(24);
+            }
+            Self::FinalizePayout => {
+                buf.push(25);
+            }
+            Self::SubmitRender {
+                frames_hash,
+                frame_start,
+                frame_end,
+                price,
+            } => {
+                buf.push(26);
+                buf.extend_from_slice(&frames_hash);
+                buf.extend_from_slice(&frame_start.to_le_bytes());
+                buf.extend_from_slice(&frame_end.to_le_bytes());
+                buf.extend_from_slice(&price.to_le_bytes());
+            }
+            Self::ReviewSubmission { accept } => {
+                buf.push(27);
+                buf.push(accept as u8);
+            }
+            Self::PaySubmission { amount } => {
+                buf.push(28);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::SetSubmissionsRequired { required } => {
+                buf.push(29);
+                buf.push(required as u8);
+            }
+            Self::SetRateCard {
+                price_per_frame,
+                total_frames,
+            } => {
+                buf.push(30);
+                buf.extend_from_slice(&price_per_frame.to_le_bytes());
+                buf.extend_from_slice(&total_frames.to_le_bytes());
+            }
+            Self::ClaimFrames { count } => {
+                buf.push(31);
+                buf.extend_from_slice(&count.to_le_bytes());
+            }
+            Self::SetTierMultipliers { multipliers } => {
+                buf.push(32);
+                for multiplier in multipliers {
+                    buf.extend_from_slice(&multiplier.to_le_bytes());
+                }
+            }
+            Self::SetJobTier { tier } => {
+                buf.push(33);
+                buf.push(tier);
+            }
+            Self::CreateAssignment { amount, deadline } => {
+                buf.push(34);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&deadline.to_le_bytes());
+            }
+            Self::PayAssignment { amount } => {
+                buf.push(35);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::ReleaseAssignment => {
+                buf.push(36);
+            }
+            Self::RegisterNode { tier } => {
+                buf.push(37);
+                buf.push(tier);
+            }
+            Self::UpdateNode { tier } => {
+                buf.push(38);
+                buf.push(tier);
+            }
+            Self::SuspendNode { suspended } => {
+                buf.push(39);
+                buf.push(suspended as u8);
+            }
+            Self::SetNodeRegistryRequired { required } => {
+                buf.push(40);
+                buf.push(required as u8);
+            }
+            Self::SetMinStake { amount } => {
+                buf.push(41);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::Stake { amount } => {
+                buf.push(42);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::RequestUnstake { amount } => {
+                buf.push(43);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::Unstake => {
+                buf.push(44);
+            }
+            Self::SlashStake { amount } => {
+                buf.push(45);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::CloseIdempotencyMarker => {
+                buf.push(46);
+            }
+        }
+        buf
+    }
+
+    fn pack_program_config(
+        buf: &mut Vec<u8>,
+        admin: Pubkey,
+        allowed_mints: &[Pubkey],
+        allowed_token_programs: &[Pubkey],
+        default_escrow_owner: Pubkey,
+        treasury: Pubkey,
+    ) {
+        buf.extend_from_slice(&admin.to_bytes());
+        buf.push(allowed_mints.len() as u8);
+        for mint in allowed_mints {
+            buf.extend_from_slice(&mint.to_bytes());
+        }
+        buf.push(allowed_token_programs.len() as u8);
+        for token_program in allowed_token_programs {
+            buf.extend_from_slice(&token_program.to_bytes());
+        }
+        buf.extend_from_slice(&default_escrow_owner.to_bytes());
+        buf.extend_from_slice(&treasury.to_bytes());
+    }
+}
+
+/// Finds the Escrow program derived address for a token mint and an optional namespace
+pub fn find_escrow_address(
+    program_id: &Pubkey,
+    token_mint: &Pubkey,
+    namespace: Option<&Pubkey>,
+) -> (Pubkey, u8) {
+    let token_program = spl_token::id();
+    let mut seeds = vec![
+        b"escrow".as_ref(),
+        token_mint.as_ref(),
+        token_program.as_ref(),
+    ];
+    if let Some(namespace) = namespace {
+        seeds.push(namespace.as_ref());
+    }
+    Pubkey::find_program_address(&seeds, program_id)
+}
+
+/// Finds the Job program derived address for an Escrow and a Job authority
+pub fn find_job_address(program_id: &Pubkey, escrow: &Pubkey, authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"job", escrow.as_ref(), authority.as_ref()], program_id)
+}
+
+/// Finds the program config program derived address
+pub fn find_program_config_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], program_id)
+}
+
+/// Finds the funding schedule program derived address of a job
+pub fn find_funding_schedule_address(
+    program_id: &Pubkey,
+    job: &Pubkey,
+    generation: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"funding_schedule", job.as_ref(), &generation.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// Finds the pending payout program derived address of a job
+pub fn find_pending_payout_address(
+    program_id: &Pubkey,
+    job: &Pubkey,
+    generation: u64,
+    nonce: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"pending_payout",
+            job.as_ref(),
+            &generation.to_le_bytes(),
+            &nonce.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Finds the render submission program derived address of a render node for a job
+pub fn find_render_submission_address(
+    program_id: &Pubkey,
+    job: &Pubkey,
+    generation: u64,
+    node: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"render_submission",
+            job.as_ref(),
+            &generation.to_le_bytes(),
+            node.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Finds the rate card program derived address of a job
+pub fn find_rate_card_address(program_id: &Pubkey, job: &Pubkey, generation: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"rate_card", job.as_ref(), &generation.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// Finds the assignment program derived address of a render node for a job
+pub fn find_assignment_address(
+    program_id: &Pubkey,
+    job: &Pubkey,
+    generation: u64,
+    node: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"assignment",
+            job.as_ref(),
+            &generation.to_le_bytes(),
+            node.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Finds the render node program derived address of a node operator
+pub fn find_node_address(program_id: &Pubkey, operator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"node", operator.as_ref()], program_id)
+}
+
+/// Finds the node earnings program derived address of a destination owner in an escrow
+pub fn find_node_earnings_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    owner: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"node_earnings", escrow.as_ref(), owner.as_ref()],
+        program_id,
+    )
+}
+
+/// Finds the receipt program derived address of a payout from a generation of a job
+pub fn find_receipt_address(
+    program_id: &Pubkey,
+    job: &Pubkey,
+    generation: u64,
+    payout_index: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"receipt",
+            job.as_ref(),
+            &generation.to_le_bytes(),
+            &payout_index.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Finds the idempotency marker program derived address of a disbursal from a job
+pub fn find_idempotency_marker_address(
+    program_id: &Pubkey,
+    job: &Pubkey,
+    generation: u64,
+    idempotency_key: &[u8],
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"idempotency",
+            job.as_ref(),
+            &generation.to_le_bytes(),
+            idempotency_key,
+        ],
+        program_id,
+    )
+}
+
+/// Finds the stake vault program derived address of an escrow
+pub fn find_stake_vault_address(program_id: &Pubkey, escrow: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"stake_vault", escrow.as_ref()], program_id)
+}
+
+/// Finds the stake program derived address of a node operator in an escrow
+pub fn find_stake_address(program_id: &Pubkey, escrow: &Pubkey, operator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"stake", escrow.as_ref(), operator.as_ref()], program_id)
+}
+
+/// Finds the ProgramData account of an upgradeable program
+pub fn find_program_data_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id())
+}
+
+/// Creates an 'InitEscrow' instruction.
+pub fn init_escrow(
+    program_id: Pubkey,
+    owner: Pubkey,
+    token_mint: Pubkey,
+    funder: Pubkey,
+    authority: Pubkey,
+    namespace: Option<Pubkey>,
+    escrow: Option<Pubkey>,
+) -> Instruction {
+    let escrow = escrow
+        .unwrap_or_else(|| find_escrow_address(&program_id, &token_mint, namespace.as_ref()).0);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let (program_data, _bump_seed) = find_program_data_address(&program_id);
+    let (program_config, _bump_seed) = find_program_config_address(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(funder, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(program_data, false),
+            AccountMeta::new_readonly(program_config, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data: RNDRInstruction::InitEscrow { owner, namespace }.pack(),
+    }
+}
+
+/// Creates a 'SetEscrowOwner' instruction.
+pub fn set_escrow_owner(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    current_owner: Pubkey,
+    new_owner: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(current_owner, true),
+        ],
+        data: RNDRInstruction::SetEscrowOwner { new_owner }.pack(),
+    }
+}
+
+/// Creates a 'FundJob' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn fund_job(
+    program_id: Pubkey,
+    amount: u64,
+    tier: u8,
+    token_mint: Pubkey,
+    funder: Pubkey,
+    source_token: Pubkey,
+    authority: Pubkey,
+    namespace: Option<Pubkey>,
+    escrow: Option<Pubkey>,
+    job: Option<Pubkey>,
+) -> Instruction {
+    let escrow = escrow
+        .unwrap_or_else(|| find_escrow_address(&program_id, &token_mint, namespace.as_ref()).0);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let job = job.unwrap_or_else(|| find_job_address(&program_id, &escrow, &authority).0);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(funder, true),
+            AccountMeta::new(source_token, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::FundJob { amount, tier }.pack(),
+    }
+}
+
+/// Creates a 'DisburseFunds' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn disburse_funds(
+    program_id: Pubkey,
+    amount: u64,
+    token_mint: Pubkey,
+    destination_token: Pubkey,
+    destination_owner: Pubkey,
+    job: Pubkey,
+    escrow_owner: Pubkey,
+    rent_payer: Pubkey,
+    namespace: Option<Pubkey>,
+    escrow: Option<Pubkey>,
+) -> Instruction {
+    let escrow = escrow
+        .unwrap_or_else(|| find_escrow_address(&program_id, &token_mint, namespace.as_ref()).0);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let (node_earnings, _bump_seed) =
+        find_node_earnings_address(&program_id, &escrow, &destination_owner);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(escrow_owner, true),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(destination_token, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(node_earnings, false),
+            AccountMeta::new(rent_payer, true),
+            AccountMeta::new_readonly(clock::id(), false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::DisburseFunds {
+            amount,
+            reference: None,
+            idempotency_key: None,
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'SetJobMetadata' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn set_job_metadata(
+    program_id: Pubkey,
+    scene_hash: [u8; 32],
+    content_id: Vec<u8>,
+    frame_start: u32,
+    frame_end: u32,
+    funder: Pubkey,
+    job: Pubkey,
+    generation: u64,
+    authority: Pubkey,
+) -> Instruction {
+    let (job_metadata, _bump_seed) = Pubkey::find_program_address(
+        &[b"job_metadata", job.as_ref(), &generation.to_le_bytes()],
+        &program_id,
+    );
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(job, false),
+            AccountMeta::new(job_metadata, false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::SetJobMetadata {
+            scene_hash,
+            content_id,
+            frame_start,
+            frame_end,
+        }
+        .pack(),
+    }
+}
+
+/// Creates an 'AddMilestone' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn add_milestone(
+    program_id: Pubkey,
+    amount: u64,
+    description_hash: [u8; 32],
+    index: u8,
+    funder: Pubkey,
+    job: Pubkey,
+    generation: u64,
+    authority: Pubkey,
+) -> Instruction {
+    let (milestone, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"milestone",
+            job.as_ref(),
+            &generation.to_le_bytes(),
+            &[index],
+        ],
+        &program_id,
+    );
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(job, false),
+            AccountMeta::new(milestone, false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::AddMilestone {
+            amount,
+            description_hash,
+        }
+        .pack(),
+    }
+}
+
+/// Creates an 'ApproveMilestone' instruction.
+pub fn approve_milestone(
+    program_id: Pubkey,
+    index: u8,
+    job: Pubkey,
+    generation: u64,
+    authority: Pubkey,
+) -> Instruction {
+    let (milestone, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"milestone",
+            job.as_ref(),
+            &generation.to_le_bytes(),
+            &[index],
+        ],
+        &program_id,
+    );
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(job, false),
+            AccountMeta::new(milestone, false),
+        ],
+        data: RNDRInstruction::ApproveMilestone.pack(),
+    }
+}
+
+/// Creates a 'PostPayoutRoot' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn post_payout_root(
+    program_id: Pubkey,
+    root: [u8; 32],
+    amount: u64,
+    leaf_count: u32,
+    expiry: UnixTimestamp,
+    funder: Pubkey,
+    escrow: Pubkey,
+    escrow_owner: Pubkey,
+    job: Pubkey,
+    generation: u64,
+) -> Instruction {
+    let (payout_root, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"payout_root",
+            job.as_ref(),
+            &generation.to_le_bytes(),
+            &root,
+        ],
+        &program_id,
+    );
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new_readonly(escrow_owner, true),
+            AccountMeta::new(job, false),
+            AccountMeta::new(payout_root, false),
+            AccountMeta::new_readonly(clock::id(), false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::PostPayoutRoot {
+            root,
+            amount,
+            leaf_count,
+            expiry,
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'ClaimPayout' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_payout(
+    program_id: Pubkey,
+    index: u32,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+    token_mint: Pubkey,
+    job: Pubkey,
+    payout_root: Pubkey,
+    destination_token: Pubkey,
+    namespace: Option<Pubkey>,
+    escrow: Option<Pubkey>,
+) -> Instruction {
+    let escrow = escrow
+        .unwrap_or_else(|| find_escrow_address(&program_id, &token_mint, namespace.as_ref()).0);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(payout_root, false),
+            AccountMeta::new(destination_token, false),
+            AccountMeta::new_readonly(clock::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::ClaimPayout {
+            index,
+            amount,
+            proof,
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'ReclaimPayoutRoot' instruction.
+pub fn reclaim_payout_root(
+    program_id: Pubkey,
+    job: Pubkey,
+    payout_root: Pubkey,
+    rent_payer: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(job, false),
+            AccountMeta::new(payout_root, false),
+            AccountMeta::new(rent_payer, false),
+            AccountMeta::new_readonly(clock::id(), false),
+        ],
+        data: RNDRInstruction::ReclaimPayoutRoot.pack(),
+    }
+}
+
+/// Creates a 'RedeemVoucher' instruction.
+pub fn redeem_voucher(
+    program_id: Pubkey,
+    voucher: Voucher,
+    token_mint: Pubkey,
+    funder: Pubkey,
+) -> Instruction {
+    let escrow_associated_token = get_associated_token_address(&voucher.escrow, &token_mint);
+    let (redeemed_voucher, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"voucher",
+            voucher.escrow.as_ref(),
+            &voucher.nonce.to_le_bytes(),
+        ],
+        &program_id,
+    );
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(funder, true),
+            AccountMeta::new(voucher.escrow, false),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(voucher.job, false),
+            AccountMeta::new(voucher.destination, false),
+            AccountMeta::new(redeemed_voucher, false),
+            AccountMeta::new_readonly(instructions::id(), false),
+            AccountMeta::new_readonly(clock::id(), false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::RedeemVoucher { voucher }.pack(),
+    }
+}
+
+/// Creates a 'BatchDisburse' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn batch_disburse(
+    program_id: Pubkey,
+    disbursements: Vec<Disbursement>,
+    token_mint: Pubkey,
+    jobs: &[Pubkey],
+    destination_tokens: &[Pubkey],
+    destination_operators: Option<&[Pubkey]>,
+    escrow_owner: Pubkey,
+    namespace: Option<Pubkey>,
+    escrow: Option<Pubkey>,
+) -> Instruction {
+    let escrow = escrow
+        .unwrap_or_else(|| find_escrow_address(&program_id, &token_mint, namespace.as_ref()).0);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let mut accounts = vec![
+        AccountMeta::new_readonly(token_mint, false),
+        AccountMeta::new(escrow, false),
+        AccountMeta::new_readonly(escrow_owner, true),
+        AccountMeta::new(escrow_associated_token, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    accounts.extend(jobs.iter().map(|job| AccountMeta::new(*job, false)));
+    match destination_operators {
+        Some(destination_operators) => {
+            for (destination_token, operator) in
+                destination_tokens.iter().zip(destination_operators)
+            {
+                let (node, _bump_seed) = find_node_address(&program_id, operator);
+                accounts.push(AccountMeta::new(*destination_token, false));
+                accounts.push(AccountMeta::new(node, false));
+            }
+        }
+        None => accounts.extend(
+            destination_tokens
+                .iter()
+                .map(|destination_token| AccountMeta::new(*destination_token, false)),
+        ),
+    }
+    Instruction {
+        program_id,
+        accounts,
+        data: RNDRInstruction::BatchDisburse {
+            job_count: jobs.len() as u8,
+            disbursements,
+        }
+        .pack(),
+    }
+}
+
+/// Creates an 'InitProgramConfig' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn init_program_config(
+    program_id: Pubkey,
+    admin: Pubkey,
+    allowed_mints: Vec<Pubkey>,
+    allowed_token_programs: Vec<Pubkey>,
+    default_escrow_owner: Pubkey,
+    treasury: Pubkey,
+    funder: Pubkey,
+    upgrade_authority: Pubkey,
+) -> Instruction {
+    let (program_data, _bump_seed) = find_program_data_address(&program_id);
+    let (program_config, _bump_seed) = find_program_config_address(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new_readonly(upgrade_authority, true),
+            AccountMeta::new_readonly(program_data, false),
+            AccountMeta::new(program_config, false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::InitProgramConfig {
+            admin,
+            allowed_mints,
+            allowed_token_programs,
+            default_escrow_owner,
+            treasury,
+        }
+        .pack(),
+    }
+}
+
+/// Creates an 'UpdateProgramConfig' instruction.
+pub fn update_program_config(
+    program_id: Pubkey,
+    admin: Pubkey,
+    allowed_mints: Vec<Pubkey>,
+    allowed_token_programs: Vec<Pubkey>,
+    default_escrow_owner: Pubkey,
+    treasury: Pubkey,
+    current_admin: Pubkey,
+) -> Instruction {
+    let (program_config, _bump_seed) = find_program_config_address(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(program_config, false),
+            AccountMeta::new_readonly(current_admin, true),
+        ],
+        data: RNDRInstruction::UpdateProgramConfig {
+            admin,
+            allowed_mints,
+            allowed_token_programs,
+            default_escrow_owner,
+            treasury,
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'CloseJob' instruction.
+pub fn close_job(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    authority: Pubkey,
+    job: Pubkey,
+    rent_payer: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(job, false),
+            AccountMeta::new(rent_payer, false),
+        ],
+        data: RNDRInstruction::CloseJob.pack(),
+    }
+}
+
+/// Creates a 'CloseEscrow' instruction.
+pub fn close_escrow(
+    program_id: Pubkey,
+    token_mint: Pubkey,
+    escrow_owner: Pubkey,
+    destination: Pubkey,
+    namespace: Option<Pubkey>,
+    escrow: Option<Pubkey>,
+) -> Instruction {
+    let escrow = escrow
+        .unwrap_or_else(|| find_escrow_address(&program_id, &token_mint, namespace.as_ref()).0);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(escrow_owner, true),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(destination, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::CloseEscrow.pack(),
+    }
+}
+
+/// Adds the token account receiving the leftover tokens of the escrow to a 'CloseEscrow'
+/// instruction.
+pub fn with_leftover_token(mut instruction: Instruction, leftover_token: Pubkey) -> Instruction {
+    instruction
+        .accounts
+        .push(AccountMeta::new(leftover_token, false));
+    instruction
+}
+
+/// Creates a 'MigrateEscrow' instruction.
+pub fn migrate_escrow(
+    program_id: Pubkey,
+    old_token_mint: Pubkey,
+    new_token_mint: Pubkey,
+    escrow_owner: Pubkey,
+    funder: Pubkey,
+    namespace: Option<Pubkey>,
+) -> Instruction {
+    let (old_escrow, _bump_seed) =
+        find_escrow_address(&program_id, &old_token_mint, namespace.as_ref());
+    let (new_escrow, _bump_seed) =
+        find_escrow_address(&program_id, &new_token_mint, namespace.as_ref());
+    let new_escrow_associated_token = get_associated_token_address(&new_escrow, &new_token_mint);
+    let (program_config, _bump_seed) = find_program_config_address(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(old_token_mint, false),
+            AccountMeta::new(old_escrow, false),
+            AccountMeta::new_readonly(escrow_owner, true),
+            AccountMeta::new_readonly(new_token_mint, false),
+            AccountMeta::new(funder, true),
+            AccountMeta::new_readonly(program_config, false),
+            AccountMeta::new(new_escrow, false),
+            AccountMeta::new(new_escrow_associated_token, false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data: RNDRInstruction::MigrateEscrow.pack(),
+    }
+}
+
+/// Creates a 'MigrateJob' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn migrate_job(
+    program_id: Pubkey,
+    old_token_mint: Pubkey,
+    new_token_mint: Pubkey,
+    escrow_owner: Pubkey,
+    job_authority: Pubkey,
+    destination_token: Pubkey,
+    source_token: Pubkey,
+    source_authority: Pubkey,
+    funder: Pubkey,
+    namespace: Option<Pubkey>,
+) -> Instruction {
+    let (old_escrow, _bump_seed) =
+        find_escrow_address(&program_id, &old_token_mint, namespace.as_ref());
+    let old_escrow_associated_token = get_associated_token_address(&old_escrow, &old_token_mint);
+    let (old_job, _bump_seed) = find_job_address(&program_id, &old_escrow, &job_authority);
+    let (new_escrow, _bump_seed) =
+        find_escrow_address(&program_id, &new_token_mint, namespace.as_ref());
+    let new_escrow_associated_token = get_associated_token_address(&new_escrow, &new_token_mint);
+    let (new_job, _bump_seed) = find_job_address(&program_id, &new_escrow, &job_authority);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(old_token_mint, false),
+            AccountMeta::new(old_escrow, false),
+            AccountMeta::new_readonly(escrow_owner, true),
+            AccountMeta::new(old_escrow_associated_token, false),
+            AccountMeta::new(old_job, false),
+            AccountMeta::new(destination_token, false),
+            AccountMeta::new_readonly(new_token_mint, false),
+            AccountMeta::new(new_escrow, false),
+            AccountMeta::new(new_escrow_associated_token, false),
+            AccountMeta::new(new_job, false),
+            AccountMeta::new(source_token, false),
+            AccountMeta::new_readonly(source_authority, true),
+            AccountMeta::new(funder, true),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::MigrateJob.pack(),
+    }
+}
+
+/// Creates a 'BatchFundJob' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn batch_fund_job(
+    program_id: Pubkey,
+    fundings: Vec<JobFunding>,
+    token_mint: Pubkey,
+    funder: Pubkey,
+    source_token: Pubkey,
+    source_authority: Pubkey,
+    namespace: Option<Pubkey>,
+    escrow: Option<Pubkey>,
+) -> Instruction {
+    let escrow = escrow
+        .unwrap_or_else(|| find_escrow_address(&program_id, &token_mint, namespace.as_ref()).0);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let mut accounts = vec![
+        AccountMeta::new_readonly(token_mint, false),
+        AccountMeta::new(funder, true),
+        AccountMeta::new(source_token, false),
+        AccountMeta::new_readonly(source_authority, true),
+        AccountMeta::new(escrow, false),
+        AccountMeta::new(escrow_associated_token, false),
+        AccountMeta::new_readonly(rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    accounts.extend(fundings.iter().map(|funding| {
+        AccountMeta::new(
+            find_job_address(&program_id, &escrow, &funding.authority).0,
+            false,
+        )
+    }));
+    Instruction {
+        program_id,
+        accounts,
+        data: RNDRInstruction::BatchFundJob { fundings }.pack(),
+    }
+}
+
+/// Creates an 'InitFundingSchedule' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn init_funding_schedule(
+    program_id: Pubkey,
+    amount: u64,
+    period: i64,
+    first_due: UnixTimestamp,
+    token_mint: Pubkey,
+    funder: Pubkey,
+    authority: Pubkey,
+    source_token: Pubkey,
+    generation: u64,
+    namespace: Option<Pubkey>,
+    escrow: Option<Pubkey>,
+) -> Instruction {
+    let escrow = escrow
+        .unwrap_or_else(|| find_escrow_address(&program_id, &token_mint, namespace.as_ref()).0);
+    let (job, _bump_seed) = find_job_address(&program_id, &escrow, &authority);
+    let (funding_schedule, _bump_seed) =
+        find_funding_schedule_address(&program_id, &job, generation);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(funder, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(source_token, false),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new_readonly(job, false),
+            AccountMeta::new(funding_schedule, false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::InitFundingSchedule {
+            amount,
+            period,
+            first_due,
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'CrankFunding' instruction.
+pub fn crank_funding(
+    program_id: Pubkey,
+    token_mint: Pubkey,
+    escrow: Pubkey,
+    job: Pubkey,
+    generation: u64,
+    source_token: Pubkey,
+) -> Instruction {
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let (funding_schedule, _bump_seed) =
+        find_funding_schedule_address(&program_id, &job, generation);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(funding_schedule, false),
+            AccountMeta::new(source_token, false),
+            AccountMeta::new_readonly(clock::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::CrankFunding.pack(),
+    }
+}
+
+/// Creates a 'ResumeFundingSchedule' instruction.
+pub fn resume_funding_schedule(
+    program_id: Pubkey,
+    authority: Pubkey,
+    job: Pubkey,
+    generation: u64,
+) -> Instruction {
+    let (funding_schedule, _bump_seed) =
+        find_funding_schedule_address(&program_id, &job, generation);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(funding_schedule, false),
+            AccountMeta::new_readonly(clock::id(), false),
+        ],
+        data: RNDRInstruction::ResumeFundingSchedule.pack(),
+    }
+}
+
+/// Creates a 'CloseFundingSchedule' instruction.
+pub fn close_funding_schedule(
+    program_id: Pubkey,
+    authority: Pubkey,
+    job: Pubkey,
+    generation: u64,
+    rent_payer: Pubkey,
+) -> Instruction {
+    let (funding_schedule, _bump_seed) =
+        find_funding_schedule_address(&program_id, &job, generation);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(funding_schedule, false),
+            AccountMeta::new(rent_payer, false),
+        ],
+        data: RNDRInstruction::CloseFundingSchedule.pack(),
+    }
+}
+
+/// Creates a 'PostPendingPayout' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn post_pending_payout(
+    program_id: Pubkey,
+    nonce: u64,
+    amount: u64,
+    funder: Pubkey,
+    escrow: Pubkey,
+    escrow_owner: Pubkey,
+    job: Pubkey,
+    generation: u64,
+    destination_token: Pubkey,
+) -> Instruction {
+    let (pending_payout, _bump_seed) =
+        find_pending_payout_address(&program_id, &job, generation, nonce);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new_readonly(escrow_owner, true),
+            AccountMeta::new(job, false),
+            AccountMeta::new_readonly(destination_token, false),
+            AccountMeta::new(pending_payout, false),
+            AccountMeta::new_readonly(clock::id(), false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::PostPendingPayout { nonce, amount }.pack(),
+    }
+}
+
+/// Creates a 'ChallengePayout' instruction.
+pub fn challenge_payout(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    authority: Pubkey,
+    job: Pubkey,
+    pending_payout: Pubkey,
+    rent_payer: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(job, false),
+            AccountMeta::new(pending_payout, false),
+            AccountMeta::new(rent_payer, false),
+            AccountMeta::new_readonly(clock::id(), false),
+        ],
+        data: RNDRInstruction::ChallengePayout.pack(),
+    }
+}
+
+/// Creates a 'FinalizePayout' instruction.
+pub fn finalize_payout(
+    program_id: Pubkey,
+    token_mint: Pubkey,
+    escrow: Pubkey,
+    job: Pubkey,
+    pending_payout: Pubkey,
+    destination_token: Pubkey,
+    rent_payer: Pubkey,
+) -> Instruction {
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(pending_payout, false),
+            AccountMeta::new(destination_token, false),
+            AccountMeta::new(rent_payer, false),
+            AccountMeta::new_readonly(clock::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::FinalizePayout.pack(),
+    }
+}
+
+/// Creates a 'SubmitRender' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn submit_render(
+    program_id: Pubkey,
+    frames_hash: [u8; 32],
+    frame_start: u32,
+    frame_end: u32,
+    price: u64,
+    node: Pubkey,
+    job: Pubkey,
+    generation: u64,
+) -> Instruction {
+    let (render_submission, _bump_seed) =
+        find_render_submission_address(&program_id, &job, generation, &node);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(node, true),
+            AccountMeta::new_readonly(job, false),
+            AccountMeta::new(render_submission, false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::SubmitRender {
+            frames_hash,
+            frame_start,
+            frame_end,
+            price,
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'ReviewSubmission' instruction.
+pub fn review_submission(
+    program_id: Pubkey,
+    accept: bool,
+    authority: Pubkey,
+    job: Pubkey,
+    render_submission: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(job, false),
+            AccountMeta::new(render_submission, false),
+        ],
+        data: RNDRInstruction::ReviewSubmission { accept }.pack(),
+    }
+}
+
+/// Creates a 'PaySubmission' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_submission(
+    program_id: Pubkey,
+    amount: u64,
+    token_mint: Pubkey,
+    escrow: Pubkey,
+    escrow_owner: Pubkey,
+    job: Pubkey,
+    render_submission: Pubkey,
+    node_token: Pubkey,
+) -> Instruction {
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(escrow_owner, true),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(render_submission, false),
+            AccountMeta::new(node_token, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::PaySubmission { amount }.pack(),
+    }
+}
+
+/// Creates a 'SetSubmissionsRequired' instruction.
+pub fn set_submissions_required(
+    program_id: Pubkey,
+    required: bool,
+    authority: Pubkey,
+    job: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(job, false),
+        ],
+        data: RNDRInstruction::SetSubmissionsRequired { required }.pack(),
+    }
+}
+
+/// Creates a 'SetRateCard' instruction.
+pub fn set_rate_card(
+    program_id: Pubkey,
+    price_per_frame: u64,
+    total_frames: u32,
+    funder: Pubkey,
+    authority: Pubkey,
+    job: Pubkey,
+    generation: u64,
+) -> Instruction {
+    let (rate_card, _bump_seed) = find_rate_card_address(&program_id, &job, generation);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(job, false),
+            AccountMeta::new(rate_card, false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::SetRateCard {
+            price_per_frame,
+            total_frames,
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'ClaimFrames' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_frames(
+    program_id: Pubkey,
+    count: u32,
+    token_mint: Pubkey,
+    escrow: Pubkey,
+    escrow_owner: Pubkey,
+    job: Pubkey,
+    generation: u64,
+    destination_token: Pubkey,
+) -> Instruction {
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let (rate_card, _bump_seed) = find_rate_card_address(&program_id, &job, generation);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(escrow_owner, true),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(rate_card, false),
+            AccountMeta::new(destination_token, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::ClaimFrames { count }.pack(),
+    }
+}
+
+/// Creates a 'SetTierMultipliers' instruction.
+pub fn set_tier_multipliers(
+    program_id: Pubkey,
+    multipliers: [u32; TIER_COUNT],
+    escrow: Pubkey,
+    escrow_owner: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(escrow_owner, true),
+        ],
+        data: RNDRInstruction::SetTierMultipliers { multipliers }.pack(),
+    }
+}
+
+/// Creates a 'SetJobTier' instruction.
+pub fn set_job_tier(
+    program_id: Pubkey,
+    tier: u8,
+    escrow: Pubkey,
+    authority: Pubkey,
+    job: Pubkey,
+    escrow_owner: Option<Pubkey>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(escrow, false),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new(job, false),
+    ];
+    if let Some(escrow_owner) = escrow_owner {
+        accounts.push(AccountMeta::new_readonly(escrow_owner, true));
+    }
+    Instruction {
+        program_id,
+        accounts,
+        data: RNDRInstruction::SetJobTier { tier }.pack(),
+    }
+}
+
+/// Creates a 'CreateAssignment' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn create_assignment(
+    program_id: Pubkey,
+    amount: u64,
+    deadline: UnixTimestamp,
+    funder: Pubkey,
+    escrow: Pubkey,
+    escrow_owner: Pubkey,
+    job: Pubkey,
+    generation: u64,
+    node: Pubkey,
+) -> Instruction {
+    let (assignment, _bump_seed) = find_assignment_address(&program_id, &job, generation, &node);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new_readonly(escrow_owner, true),
+            AccountMeta::new(job, false),
+            AccountMeta::new_readonly(node, false),
+            AccountMeta::new(assignment, false),
+            AccountMeta::new_readonly(clock::id(), false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::CreateAssignment { amount, deadline }.pack(),
+    }
+}
+
+/// Creates a 'PayAssignment' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_assignment(
+    program_id: Pubkey,
+    amount: u64,
+    token_mint: Pubkey,
+    escrow: Pubkey,
+    escrow_owner: Pubkey,
+    job: Pubkey,
+    assignment: Pubkey,
+    node_token: Pubkey,
+    rent_payer: Pubkey,
+) -> Instruction {
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(escrow_owner, true),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(assignment, false),
+            AccountMeta::new(node_token, false),
+            AccountMeta::new(rent_payer, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::PayAssignment { amount }.pack(),
+    }
+}
+
+/// Creates a 'ReleaseAssignment' instruction.
+pub fn release_assignment(
+    program_id: Pubkey,
+    job: Pubkey,
+    assignment: Pubkey,
+    rent_payer: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(job, false),
+            AccountMeta::new(assignment, false),
+            AccountMeta::new(rent_payer, false),
+            AccountMeta::new_readonly(clock::id(), false),
+        ],
+        data: RNDRInstruction::ReleaseAssignment.pack(),
+    }
+}
+
+/// Creates a 'RegisterNode' instruction.
+pub fn register_node(
+    program_id: Pubkey,
+    tier: u8,
+    operator: Pubkey,
+    payout_token: Pubkey,
+) -> Instruction {
+    let (node, _bump_seed) = find_node_address(&program_id, &operator);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(operator, true),
+            AccountMeta::new_readonly(payout_token, false),
+            AccountMeta::new(node, false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::RegisterNode { tier }.pack(),
+    }
+}
+
+/// Creates an 'UpdateNode' instruction.
+pub fn update_node(
+    program_id: Pubkey,
+    tier: u8,
+    operator: Pubkey,
+    payout_token: Pubkey,
+) -> Instruction {
+    let (node, _bump_seed) = find_node_address(&program_id, &operator);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(operator, true),
+            AccountMeta::new_readonly(payout_token, false),
+            AccountMeta::new(node, false),
+        ],
+        data: RNDRInstruction::UpdateNode { tier }.pack(),
+    }
+}
+
+/// Creates a 'SuspendNode' instruction.
+pub fn suspend_node(
+    program_id: Pubkey,
+    suspended: bool,
+    admin: Pubkey,
+    operator: Pubkey,
+) -> Instruction {
+    let (program_config, _bump_seed) = find_program_config_address(&program_id);
+    let (node, _bump_seed) = find_node_address(&program_id, &operator);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(program_config, false),
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new(node, false),
+        ],
+        data: RNDRInstruction::SuspendNode { suspended }.pack(),
+    }
+}
+
+/// Creates a 'SetNodeRegistryRequired' instruction.
+pub fn set_node_registry_required(
+    program_id: Pubkey,
+    required: bool,
+    escrow: Pubkey,
+    escrow_owner: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(escrow_owner, true),
+        ],
+        data: RNDRInstruction::SetNodeRegistryRequired { required }.pack(),
+    }
+}
+
+/// Adds the render node of a node operator to a disbursal instruction, for the `DisburseFunds`,
+/// `ClaimPayout`, `RedeemVoucher`, `PostPendingPayout`, `FinalizePayout`, `ClaimFrames`,
+/// `PaySubmission` and `PayAssignment` instructions.
+pub fn with_node(
+    program_id: Pubkey,
+    mut instruction: Instruction,
+    operator: Pubkey,
+) -> Instruction {
+    let (node, _bump_seed) = find_node_address(&program_id, &operator);
+    instruction.accounts.push(AccountMeta::new(node, false));
+    instruction
+}
+
+/// Creates a 'SetMinStake' instruction.
+pub fn set_min_stake(
+    program_id: Pubkey,
+    amount: u64,
+    escrow: Pubkey,
+    escrow_owner: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(escrow_owner, true),
+        ],
+        data: RNDRInstruction::SetMinStake { amount }.pack(),
+    }
+}
+
+/// Creates a 'Stake' instruction.
+pub fn stake(
+    program_id: Pubkey,
+    amount: u64,
+    token_mint: Pubkey,
+    operator: Pubkey,
+    source_token: Pubkey,
+    source_authority: Pubkey,
+    escrow: Pubkey,
+) -> Instruction {
+    let (stake_vault, _bump_seed) = find_stake_vault_address(&program_id, &escrow);
+    let stake_vault_associated_token = get_associated_token_address(&stake_vault, &token_mint);
+    let (stake, _bump_seed) = find_stake_address(&program_id, &escrow, &operator);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(operator, true),
+            AccountMeta::new(source_token, false),
+            AccountMeta::new_readonly(source_authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new_readonly(stake_vault, false),
+            AccountMeta::new(stake_vault_associated_token, false),
+            AccountMeta::new(stake, false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data: RNDRInstruction::Stake { amount }.pack(),
+    }
+}
+
+/// Creates a 'RequestUnstake' instruction.
+pub fn request_unstake(
+    program_id: Pubkey,
+    amount: u64,
+    operator: Pubkey,
+    escrow: Pubkey,
+) -> Instruction {
+    let (stake, _bump_seed) = find_stake_address(&program_id, &escrow, &operator);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(operator, true),
+            AccountMeta::new(stake, false),
+            AccountMeta::new_readonly(clock::id(), false),
+        ],
+        data: RNDRInstruction::RequestUnstake { amount }.pack(),
+    }
+}
+
+/// Creates an 'Unstake' instruction.
+pub fn unstake(
+    program_id: Pubkey,
+    token_mint: Pubkey,
+    escrow: Pubkey,
+    operator: Pubkey,
+    destination_token: Pubkey,
+) -> Instruction {
+    let (stake_vault, _bump_seed) = find_stake_vault_address(&program_id, &escrow);
+    let stake_vault_associated_token = get_associated_token_address(&stake_vault, &token_mint);
+    let (stake, _bump_seed) = find_stake_address(&program_id, &escrow, &operator);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new_readonly(operator, true),
+            AccountMeta::new(stake, false),
+            AccountMeta::new_readonly(stake_vault, false),
+            AccountMeta::new(stake_vault_associated_token, false),
+            AccountMeta::new(destination_token, false),
+            AccountMeta::new_readonly(clock::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::Unstake.pack(),
+    }
+}
+
+/// Creates a 'SlashStake' instruction.
+pub fn slash_stake(
+    program_id: Pubkey,
+    amount: u64,
+    token_mint: Pubkey,
+    escrow: Pubkey,
+    authority: Pubkey,
+    operator: Pubkey,
+    destination: Pubkey,
+) -> Instruction {
+    let (program_config, _bump_seed) = find_program_config_address(&program_id);
+    let (stake_vault, _bump_seed) = find_stake_vault_address(&program_id, &escrow);
+    let stake_vault_associated_token = get_associated_token_address(&stake_vault, &token_mint);
+    let (stake, _bump_seed) = find_stake_address(&program_id, &escrow, &operator);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(program_config, false),
+            AccountMeta::new(stake, false),
+            AccountMeta::new_readonly(stake_vault, false),
+            AccountMeta::new(stake_vault_associated_token, false),
+            AccountMeta::new(destination, false),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::SlashStake { amount }.pack(),
+    }
+}
+
+/// Adds the stake of a render node to a 'CreateAssignment' instruction.
+pub fn with_stake(
+    program_id: Pubkey,
+    mut instruction: Instruction,
+    escrow: Pubkey,
+    node: Pubkey,
+) -> Instruction {
+    let (stake, _bump_seed) = find_stake_address(&program_id, &escrow, &node);
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(stake, false));
+    instruction
+}
+
+/// Adds a receipt of the payout to a 'DisburseFunds' instruction. It must be added before the
+/// idempotency key and the render node, `payout_index` being the payout count of the job.
+pub fn with_receipt(
+    program_id: Pubkey,
+    mut instruction: Instruction,
+    job: Pubkey,
+    generation: u64,
+    payout_index: u64,
+    reference: [u8; 32],
+) -> Instruction {
+    let (receipt, _bump_seed) = find_receipt_address(&program_id, &job, generation, payout_index);
+    instruction.accounts.push(AccountMeta::new(receipt, false));
+    if let Ok(RNDRInstruction::DisburseFunds {
+        amount,
+        idempotency_key,
+        ..
+    }) = RNDRInstruction::unpack(&instruction.data)
+    {
+        instruction.data = RNDRInstruction::DisburseFunds {
+            amount,
+            reference: Some(reference),
+            idempotency_key,
+        }
+        .pack();
+    }
+    instruction
+}
+
+/// Adds an idempotency key to a 'DisburseFunds' instruction. It must be added before the render
+/// node.
+pub fn with_idempotency_key(
+    program_id: Pubkey,
+    mut instruction: Instruction,
+    job: Pubkey,
+    generation: u64,
+    idempotency_key: &[u8],
+) -> Instruction {
+    let (idempotency_marker, _bump_seed) =
+        find_idempotency_marker_address(&program_id, &job, generation, idempotency_key);
+    instruction
+        .accounts
+        .push(AccountMeta::new(idempotency_marker, false));
+    if let Ok(RNDRInstruction::DisburseFunds {
+        amount, reference, ..
+    }) = RNDRInstruction::unpack(&instruction.data)
+    {
+        instruction.data = RNDRInstruction::DisburseFunds {
+            amount,
+            reference,
+            idempotency_key: Some(idempotency_key.to_vec()),
+        }
+        .pack();
+    }
+    instruction
+}
+
+/// Creates a 'CloseIdempotencyMarker' instruction.
+pub fn close_idempotency_marker(
+    program_id: Pubkey,
+    job: Pubkey,
+    generation: u64,
+    idempotency_key: &[u8],
+    rent_payer: Pubkey,
+) -> Instruction {
+    let (idempotency_marker, _bump_seed) =
+        find_idempotency_marker_address(&program_id, &job, generation, idempotency_key);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(idempotency_marker, false),
+            AccountMeta::new(rent_payer, true),
+            AccountMeta::new_readonly(clock::id(), false),
         ],
-        data: RNDRInstruction::DisburseFunds { amount }.pack(),
+        data: RNDRInstruction::CloseIdempotencyMarker.pack(),
     }
 }
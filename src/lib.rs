@@ -5,7 +5,9 @@
 pub mod entrypoint;
 pub mod error;
 pub mod instruction;
+pub mod merkle;
 pub mod processor;
 pub mod state;
+pub mod voucher;
 
 solana_program::declare_id!("7g4aX3DYhk6xHiGuoAbEnVTp9HMgLqyENoK53AVm267E");
@@ -0,0 +1,38 @@
+//! Merkle tree hashing for payout distributions
+
+use solana_program::{hash::hashv, pubkey::Pubkey};
+
+/// Maximum number of hashes in a merkle proof
+pub const MAX_PROOF_LEN: usize = 32;
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Hash a payout leaf of `amount` tokens to `node` from `job`
+pub fn leaf_hash(index: u32, node: &Pubkey, amount: u64, job: &Pubkey) -> [u8; 32] {
+    hashv(&[
+        LEAF_PREFIX,
+        &index.to_le_bytes(),
+        node.as_ref(),
+        &amount.to_le_bytes(),
+        job.as_ref(),
+    ])
+    .to_bytes()
+}
+
+/// Hash two sibling nodes, ordered so proofs don't need to encode their position
+pub fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    if left <= right {
+        hashv(&[NODE_PREFIX, left, right]).to_bytes()
+    } else {
+        hashv(&[NODE_PREFIX, right, left]).to_bytes()
+    }
+}
+
+/// Check that a leaf and its proof hash up to the root
+pub fn verify_proof(leaf: [u8; 32], proof: &[[u8; 32]], root: &[u8; 32]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(leaf, |hash, sibling| node_hash(&hash, sibling));
+    &computed == root
+}
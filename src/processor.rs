@@ -3,20 +3,37 @@
 use {
     crate::{
         error::RNDRError,
-        instruction::RNDRInstruction,
-        state::{Escrow, InitEscrowParams, InitJobParams, Job},
+        instruction::{Disbursement, JobFunding, RNDRInstruction},
+        merkle,
+        state::{
+            Assignment, Escrow, FundingSchedule, IdempotencyMarker, InitAssignmentParams,
+            InitEscrowParams, InitFundingScheduleParams, InitIdempotencyMarkerParams,
+            InitJobMetadataParams, InitJobParams, InitMilestoneParams, InitNodeEarningsParams,
+            InitNodeParams, InitPayoutRootParams, InitPendingPayoutParams, InitProgramConfigParams,
+            InitRateCardParams, InitReceiptParams, InitRedeemedVoucherParams,
+            InitRenderSubmissionParams, InitStakeParams, Job, JobMetadata, Milestone, Node,
+            NodeEarnings, NodeStatus, PayoutRoot, PendingPayout, PodEscrow, PodJob, PodState,
+            ProgramConfig, RateCard, Receipt, RedeemedVoucher, RenderSubmission, Stake,
+            SubmissionStatus, IDEMPOTENCY_MARKER_LIFETIME, MAX_CONTENT_ID_LEN, MAX_PAYOUT_LEAVES,
+            MAX_TIER_MULTIPLIER, PAYOUT_CHALLENGE_PERIOD, TIER_COUNT, TIER_MULTIPLIER_BASE,
+            UNSTAKE_COOLDOWN,
+        },
+        voucher::{self, Voucher},
     },
     solana_program::{
         account_info::{next_account_info, AccountInfo},
+        bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+        clock::{Clock, UnixTimestamp},
         entrypoint::ProgramResult,
         instruction::{AccountMeta, Instruction},
         msg,
         program::{invoke, invoke_signed},
         program_error::ProgramError,
+        program_option::COption,
         program_pack::Pack,
         pubkey::Pubkey,
         system_instruction,
-        sysvar::{rent::Rent, Sysvar},
+        sysvar::{self, rent::Rent, Sysvar},
     },
     spl_associated_token_account::get_associated_token_address,
 };
@@ -29,21 +46,271 @@ pub fn process_instruction(
 ) -> ProgramResult {
     let instruction = RNDRInstruction::unpack(input)?;
     match instruction {
-        RNDRInstruction::InitEscrow { owner } => {
+        RNDRInstruction::InitEscrow { owner, namespace } => {
             msg!("Instruction: InitEscrow");
-            process_init_escrow(program_id, owner, accounts)
+            process_init_escrow(program_id, owner, namespace, accounts)
         }
         RNDRInstruction::SetEscrowOwner { new_owner } => {
             msg!("Instruction: SetEscrowOwner");
             process_set_escrow_owner(program_id, new_owner, accounts)
         }
-        RNDRInstruction::FundJob { amount } => {
+        RNDRInstruction::FundJob { amount, tier } => {
             msg!("Instruction: FundJob");
-            process_fund_job(program_id, amount, accounts)
+            process_fund_job(program_id, amount, tier, accounts)
         }
-        RNDRInstruction::DisburseFunds { amount } => {
+        RNDRInstruction::DisburseFunds {
+            amount,
+            reference,
+            idempotency_key,
+        } => {
             msg!("Instruction: DisburseFunds");
-            process_disburse_funds(program_id, amount, accounts)
+            process_disburse_funds(program_id, amount, reference, idempotency_key, accounts)
+        }
+        RNDRInstruction::SetJobMetadata {
+            scene_hash,
+            content_id,
+            frame_start,
+            frame_end,
+        } => {
+            msg!("Instruction: SetJobMetadata");
+            process_set_job_metadata(
+                program_id,
+                scene_hash,
+                content_id,
+                frame_start,
+                frame_end,
+                accounts,
+            )
+        }
+        RNDRInstruction::AddMilestone {
+            amount,
+            description_hash,
+        } => {
+            msg!("Instruction: AddMilestone");
+            process_add_milestone(program_id, amount, description_hash, accounts)
+        }
+        RNDRInstruction::ApproveMilestone => {
+            msg!("Instruction: ApproveMilestone");
+            process_approve_milestone(program_id, accounts)
+        }
+        RNDRInstruction::PostPayoutRoot {
+            root,
+            amount,
+            leaf_count,
+            expiry,
+        } => {
+            msg!("Instruction: PostPayoutRoot");
+            process_post_payout_root(program_id, root, amount, leaf_count, expiry, accounts)
+        }
+        RNDRInstruction::ClaimPayout {
+            index,
+            amount,
+            proof,
+        } => {
+            msg!("Instruction: ClaimPayout");
+            process_claim_payout(program_id, index, amount, &proof, accounts)
+        }
+        RNDRInstruction::ReclaimPayoutRoot => {
+            msg!("Instruction: ReclaimPayoutRoot");
+            process_reclaim_payout_root(program_id, accounts)
+        }
+        RNDRInstruction::RedeemVoucher { voucher } => {
+            msg!("Instruction: RedeemVoucher");
+            process_redeem_voucher(program_id, voucher, accounts)
+        }
+        RNDRInstruction::BatchDisburse {
+            job_count,
+            disbursements,
+        } => {
+            msg!("Instruction: BatchDisburse");
+            process_batch_disburse(program_id, job_count, &disbursements, accounts)
+        }
+        RNDRInstruction::InitProgramConfig {
+            admin,
+            allowed_mints,
+            allowed_token_programs,
+            default_escrow_owner,
+            treasury,
+        } => {
+            msg!("Instruction: InitProgramConfig");
+            process_init_program_config(
+                program_id,
+                admin,
+                allowed_mints,
+                allowed_token_programs,
+                default_escrow_owner,
+                treasury,
+                accounts,
+            )
+        }
+        RNDRInstruction::UpdateProgramConfig {
+            admin,
+            allowed_mints,
+            allowed_token_programs,
+            default_escrow_owner,
+            treasury,
+        } => {
+            msg!("Instruction: UpdateProgramConfig");
+            process_update_program_config(
+                program_id,
+                admin,
+                allowed_mints,
+                allowed_token_programs,
+                default_escrow_owner,
+                treasury,
+                accounts,
+            )
+        }
+        RNDRInstruction::CloseJob => {
+            msg!("Instruction: CloseJob");
+            process_close_job(program_id, accounts)
+        }
+        RNDRInstruction::CloseEscrow => {
+            msg!("Instruction: CloseEscrow");
+            process_close_escrow(program_id, accounts)
+        }
+        RNDRInstruction::MigrateEscrow => {
+            msg!("Instruction: MigrateEscrow");
+            process_migrate_escrow(program_id, accounts)
+        }
+        RNDRInstruction::MigrateJob => {
+            msg!("Instruction: MigrateJob");
+            process_migrate_job(program_id, accounts)
+        }
+        RNDRInstruction::BatchFundJob { fundings } => {
+            msg!("Instruction: BatchFundJob");
+            process_batch_fund_job(program_id, &fundings, accounts)
+        }
+        RNDRInstruction::InitFundingSchedule {
+            amount,
+            period,
+            first_due,
+        } => {
+            msg!("Instruction: InitFundingSchedule");
+            process_init_funding_schedule(program_id, amount, period, first_due, accounts)
+        }
+        RNDRInstruction::CrankFunding => {
+            msg!("Instruction: CrankFunding");
+            process_crank_funding(program_id, accounts)
+        }
+        RNDRInstruction::ResumeFundingSchedule => {
+            msg!("Instruction: ResumeFundingSchedule");
+            process_resume_funding_schedule(program_id, accounts)
+        }
+        RNDRInstruction::CloseFundingSchedule => {
+            msg!("Instruction: CloseFundingSchedule");
+            process_close_funding_schedule(program_id, accounts)
+        }
+        RNDRInstruction::PostPendingPayout { nonce, amount } => {
+            msg!("Instruction: PostPendingPayout");
+            process_post_pending_payout(program_id, nonce, amount, accounts)
+        }
+        RNDRInstruction::ChallengePayout => {
+            msg!("Instruction: ChallengePayout");
+            process_challenge_payout(program_id, accounts)
+        }
+        RNDRInstruction::FinalizePayout => {
+            msg!("Instruction: FinalizePayout");
+            process_finalize_payout(program_id, accounts)
+        }
+        RNDRInstruction::SubmitRender {
+            frames_hash,
+            frame_start,
+            frame_end,
+            price,
+        } => {
+            msg!("Instruction: SubmitRender");
+            process_submit_render(
+                program_id,
+                frames_hash,
+                frame_start,
+                frame_end,
+                price,
+                accounts,
+            )
+        }
+        RNDRInstruction::ReviewSubmission { accept } => {
+            msg!("Instruction: ReviewSubmission");
+            process_review_submission(program_id, accept, accounts)
+        }
+        RNDRInstruction::PaySubmission { amount } => {
+            msg!("Instruction: PaySubmission");
+            process_pay_submission(program_id, amount, accounts)
+        }
+        RNDRInstruction::SetSubmissionsRequired { required } => {
+            msg!("Instruction: SetSubmissionsRequired");
+            process_set_submissions_required(program_id, required, accounts)
+        }
+        RNDRInstruction::SetRateCard {
+            price_per_frame,
+            total_frames,
+        } => {
+            msg!("Instruction: SetRateCard");
+            process_set_rate_card(program_id, price_per_frame, total_frames, accounts)
+        }
+        RNDRInstruction::ClaimFrames { count } => {
+            msg!("Instruction: ClaimFrames");
+            process_claim_frames(program_id, count, accounts)
+        }
+        RNDRInstruction::SetTierMultipliers { multipliers } => {
+            msg!("Instruction: SetTierMultipliers");
+            process_set_tier_multipliers(program_id, multipliers, accounts)
+        }
+        RNDRInstruction::SetJobTier { tier } => {
+            msg!("Instruction: SetJobTier");
+            process_set_job_tier(program_id, tier, accounts)
+        }
+        RNDRInstruction::CreateAssignment { amount, deadline } => {
+            msg!("Instruction: CreateAssignment");
+            process_create_assignment(program_id, amount, deadline, accounts)
+        }
+        RNDRInstruction::PayAssignment { amount } => {
+            msg!("Instruction: PayAssignment");
+            process_pay_assignment(program_id, amount, accounts)
+        }
+        RNDRInstruction::ReleaseAssignment => {
+            msg!("Instruction: ReleaseAssignment");
+            process_release_assignment(program_id, accounts)
+        }
+        RNDRInstruction::RegisterNode { tier } => {
+            msg!("Instruction: RegisterNode");
+            process_register_node(program_id, tier, accounts)
+        }
+        RNDRInstruction::UpdateNode { tier } => {
+            msg!("Instruction: UpdateNode");
+            process_update_node(program_id, tier, accounts)
+        }
+        RNDRInstruction::SuspendNode { suspended } => {
+            msg!("Instruction: SuspendNode");
+            process_suspend_node(program_id, suspended, accounts)
+        }
+        RNDRInstruction::SetNodeRegistryRequired { required } => {
+            msg!("Instruction: SetNodeRegistryRequired");
+            process_set_node_registry_required(program_id, required, accounts)
+        }
+        RNDRInstruction::SetMinStake { amount } => {
+            msg!("Instruction: SetMinStake");
+            process_set_min_stake(program_id, amount, accounts)
+        }
+        RNDRInstruction::Stake { amount } => {
+            msg!("Instruction: Stake");
+            process_stake(program_id, amount, accounts)
+        }
+        RNDRInstruction::RequestUnstake { amount } => {
+            msg!("Instruction: RequestUnstake");
+            process_request_unstake(program_id, amount, accounts)
+        }
+        RNDRInstruction::Unstake => {
+            msg!("Instruction: Unstake");
+            process_unstake(program_id, accounts)
+        }
+        RNDRInstruction::SlashStake { amount } => {
+            msg!("Instruction: SlashStake");
+            process_slash_stake(program_id, amount, accounts)
+        }
+        RNDRInstruction::CloseIdempotencyMarker => {
+            msg!("Instruction: CloseIdempotencyMarker");
+            process_close_idempotency_marker(program_id, accounts)
         }
     }
 }
@@ -52,6 +319,7 @@ pub fn process_instruction(
 fn process_init_escrow(
     program_id: &Pubkey,
     owner: Pubkey,
+    namespace: Option<Pubkey>,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
@@ -59,6 +327,10 @@ fn process_init_escrow(
     let token_mint_info = next_account_info(account_info_iter)?;
     // Source accounts
     let funder_info = next_account_info(account_info_iter)?;
+    // Authority accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let program_data_info = next_account_info(account_info_iter)?;
+    let program_config_info = next_account_info(account_info_iter)?;
     // Destination accounts
     let escrow_info = next_account_info(account_info_iter)?;
     let escrow_associated_token_info = next_account_info(account_info_iter)?;
@@ -69,11 +341,68 @@ fn process_init_escrow(
     let token_program_info = next_account_info(account_info_iter)?;
     let associated_token_program_info = next_account_info(account_info_iter)?;
 
-    let mut escrow_seeds: Vec<&[_]> = vec![
-        b"escrow",
-        token_mint_info.key.as_ref(),
-        token_program_info.key.as_ref(),
-    ];
+    let owner = match get_program_config(program_id, program_config_info)? {
+        Some(program_config) => {
+            check_allowed_by_program_config(&program_config, token_mint_info, token_program_info)?;
+            if program_config.admin == *authority_info.key {
+                if !authority_info.is_signer {
+                    msg!("Program config admin provided must be a signer");
+                    return Err(RNDRError::UnspecifiedError.into());
+                }
+            } else {
+                check_upgrade_authority(program_id, program_data_info, authority_info)?;
+            }
+            if owner == Pubkey::default() {
+                program_config.default_escrow_owner
+            } else {
+                owner
+            }
+        }
+        None => {
+            check_upgrade_authority(program_id, program_data_info, authority_info)?;
+            owner
+        }
+    };
+    if owner == Pubkey::default() {
+        msg!("Escrow owner can't be the default pubkey");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    if namespace == Some(Pubkey::default()) {
+        msg!("Escrow namespace can't be the default pubkey");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    create_escrow(
+        program_id,
+        owner,
+        namespace.unwrap_or_default(),
+        token_mint_info,
+        funder_info,
+        escrow_info,
+        escrow_associated_token_info,
+        rent_info,
+        system_program_info,
+        token_program_info,
+        associated_token_program_info,
+    )
+}
+
+/// Creates an escrow program derived address and its associated token account
+#[allow(clippy::too_many_arguments)]
+fn create_escrow<'a>(
+    program_id: &Pubkey,
+    owner: Pubkey,
+    namespace: Pubkey,
+    token_mint_info: &AccountInfo<'a>,
+    funder_info: &AccountInfo<'a>,
+    escrow_info: &AccountInfo<'a>,
+    escrow_associated_token_info: &AccountInfo<'a>,
+    rent_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    token_program_info: &AccountInfo<'a>,
+    associated_token_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    let mut escrow_seeds = escrow_seeds(token_mint_info.key, token_program_info.key, &namespace);
 
     let (escrow_address, bump_seed) = Pubkey::find_program_address(&escrow_seeds, program_id);
     if &escrow_address != escrow_info.key {
@@ -109,36 +438,131 @@ fn process_init_escrow(
         ],
     )?;
 
-    let rent = &Rent::from_account_info(rent_info)?;
-    let required_lamports = rent
-        .minimum_balance(Escrow::LEN)
-        .max(1)
-        .saturating_sub(escrow_info.lamports());
-    if required_lamports > 0 {
-        invoke(
-            &system_instruction::transfer(funder_info.key, escrow_info.key, required_lamports),
-            &[
-                funder_info.clone(),
-                escrow_info.clone(),
-                system_program_info.clone(),
-            ],
-        )?;
+    create_program_account(
+        program_id,
+        funder_info,
+        escrow_info,
+        &escrow_seeds,
+        Escrow::LEN,
+        &Rent::from_account_info(rent_info)?,
+        system_program_info,
+    )?;
+
+    let escrow = Escrow::new(InitEscrowParams {
+        owner,
+        bump_seed: bump_seed[0],
+        namespace,
+    });
+    Escrow::pack(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_init_program_config(
+    program_id: &Pubkey,
+    admin: Pubkey,
+    allowed_mints: Vec<Pubkey>,
+    allowed_token_programs: Vec<Pubkey>,
+    default_escrow_owner: Pubkey,
+    treasury: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    // Authority accounts
+    let upgrade_authority_info = next_account_info(account_info_iter)?;
+    let program_data_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let program_config_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let rent_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    check_upgrade_authority(program_id, program_data_info, upgrade_authority_info)?;
+
+    let mut program_config_seeds: Vec<&[_]> = vec![b"config"];
+
+    let (program_config_address, bump_seed) =
+        Pubkey::find_program_address(&program_config_seeds, program_id);
+    if &program_config_address != program_config_info.key {
+        msg!("Program config program derived address does not match the address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !program_config_info.try_data_is_empty()? {
+        msg!("Program config is already initialized");
+        return Err(RNDRError::UnspecifiedError.into());
     }
 
-    invoke_signed(
-        &system_instruction::allocate(escrow_info.key, Escrow::LEN as u64),
-        &[escrow_info.clone(), system_program_info.clone()],
-        &[&escrow_seeds],
+    let bump_seed = &[bump_seed];
+    program_config_seeds.push(bump_seed);
+
+    create_program_account(
+        program_id,
+        funder_info,
+        program_config_info,
+        &program_config_seeds,
+        ProgramConfig::LEN,
+        &Rent::from_account_info(rent_info)?,
+        system_program_info,
     )?;
 
-    invoke_signed(
-        &system_instruction::assign(escrow_info.key, program_id),
-        &[escrow_info.clone(), system_program_info.clone()],
-        &[&escrow_seeds],
+    let program_config = ProgramConfig::new(InitProgramConfigParams {
+        admin,
+        allowed_mints,
+        allowed_token_programs,
+        default_escrow_owner,
+        treasury,
+        bump_seed: bump_seed[0],
+    });
+    ProgramConfig::pack(
+        program_config,
+        &mut program_config_info.try_borrow_mut_data()?,
     )?;
 
-    let escrow = Escrow::new(InitEscrowParams { owner });
-    Escrow::pack(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_update_program_config(
+    program_id: &Pubkey,
+    admin: Pubkey,
+    allowed_mints: Vec<Pubkey>,
+    allowed_token_programs: Vec<Pubkey>,
+    default_escrow_owner: Pubkey,
+    treasury: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let program_config_info = next_account_info(account_info_iter)?;
+    let current_admin_info = next_account_info(account_info_iter)?;
+
+    let mut program_config = ProgramConfig::unpack(&program_config_info.try_borrow_data()?)?;
+    if program_config_info.owner != program_id {
+        msg!("Program config provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if program_config.admin != *current_admin_info.key {
+        msg!("Program config admin does not match the current admin provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !current_admin_info.is_signer {
+        msg!("Current admin provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    program_config.admin = admin;
+    program_config.allowed_mints = allowed_mints;
+    program_config.allowed_token_programs = allowed_token_programs;
+    program_config.default_escrow_owner = default_escrow_owner;
+    program_config.treasury = treasury;
+    ProgramConfig::pack(
+        program_config,
+        &mut program_config_info.try_borrow_mut_data()?,
+    )?;
 
     Ok(())
 }
@@ -154,12 +578,13 @@ fn process_set_escrow_owner(
     let escrow_info = next_account_info(account_info_iter)?;
     let current_owner_info = next_account_info(account_info_iter)?;
 
-    let mut escrow = Escrow::unpack(&escrow_info.try_borrow_data()?)?;
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
     if escrow_info.owner != program_id {
         msg!("Escrow provided is not owned by the RNDR program");
         return Err(RNDRError::UnspecifiedError.into());
     }
-    if &escrow.owner != current_owner_info.key {
+    if escrow.owner != *current_owner_info.key {
         msg!("Escrow owner does not match the current owner provided");
         return Err(RNDRError::UnspecifiedError.into());
     }
@@ -168,18 +593,26 @@ fn process_set_escrow_owner(
         return Err(RNDRError::UnspecifiedError.into());
     }
 
-    escrow.owner = new_owner;
-    Escrow::pack(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+    escrow.owner = new_owner.into();
 
     Ok(())
 }
 
 #[inline(never)] // avoid stack frame limit
-fn process_fund_job(program_id: &Pubkey, amount: u64, accounts: &[AccountInfo]) -> ProgramResult {
+fn process_fund_job(
+    program_id: &Pubkey,
+    amount: u64,
+    tier: u8,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
     if amount == 0 {
         msg!("Amount of tokens to fund can't be zero");
         return Err(RNDRError::UnspecifiedError.into());
     }
+    if tier as usize > TIER_COUNT {
+        msg!("Job tier is invalid");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
 
     let account_info_iter = &mut accounts.iter();
     // RNDR token mint
@@ -198,22 +631,25 @@ fn process_fund_job(program_id: &Pubkey, amount: u64, accounts: &[AccountInfo])
     let system_program_info = next_account_info(account_info_iter)?;
     let token_program_info = next_account_info(account_info_iter)?;
 
-    let (escrow_address, _bump_seed) = Pubkey::find_program_address(
-        &[
-            b"escrow",
-            token_mint_info.key.as_ref(),
-            token_program_info.key.as_ref(),
-        ],
-        program_id,
-    );
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let namespace = Pubkey::from(escrow.namespace);
+    let bump_seed = &[escrow.bump_seed];
+    let mut escrow_seeds = escrow_seeds(token_mint_info.key, token_program_info.key, &namespace);
+    escrow_seeds.push(bump_seed);
+
+    let escrow_address = Pubkey::create_program_address(&escrow_seeds, program_id)?;
     if &escrow_address != escrow_info.key {
         msg!("Escrow program derived address does not match the escrow address provided");
         return Err(ProgramError::InvalidSeeds);
     }
-
-    let mut escrow = Escrow::unpack(&escrow_info.try_borrow_data()?)?;
-    if escrow_info.owner != program_id {
-        msg!("Escrow provided is not owned by the RNDR program");
+    if escrow.successor != Pubkey::default() {
+        msg!("Escrow is migrating to a new token mint and can't be funded");
         return Err(RNDRError::UnspecifiedError.into());
     }
 
@@ -226,60 +662,29 @@ fn process_fund_job(program_id: &Pubkey, amount: u64, accounts: &[AccountInfo])
         return Err(ProgramError::InvalidSeeds);
     }
 
-    let mut job_seeds: Vec<&[_]> = vec![
-        b"job",
-        escrow_info.key.as_ref(),
-        authority_info.key.as_ref(),
-    ];
-
-    let (job_pubkey, bump_seed) = Pubkey::find_program_address(&job_seeds, program_id);
-    if &job_pubkey != job_info.key {
-        msg!("Job program derived address does not match the job address provided");
-        return Err(ProgramError::InvalidSeeds);
+    let job_created = init_or_check_job(
+        program_id,
+        escrow_info.key,
+        authority_info.key,
+        escrow.jobs_created.into(),
+        tier,
+        escrow.tier_multiplier(tier)?,
+        job_info,
+        funder_info,
+        rent_info,
+        system_program_info,
+    )?;
+    if job_created {
+        escrow.job_count = u64::from(escrow.job_count)
+            .checked_add(1)
+            .ok_or(RNDRError::MathError)?
+            .into();
+        escrow.jobs_created = u64::from(escrow.jobs_created)
+            .checked_add(1)
+            .ok_or(RNDRError::MathError)?
+            .into();
     }
 
-    let mut job = if job_info.try_data_is_empty()? {
-        let bump_seed = &[bump_seed];
-        job_seeds.push(bump_seed);
-
-        let rent = &Rent::from_account_info(rent_info)?;
-        let required_lamports = rent
-            .minimum_balance(Job::LEN)
-            .max(1)
-            .saturating_sub(job_info.lamports());
-        if required_lamports > 0 {
-            invoke(
-                &system_instruction::transfer(funder_info.key, job_info.key, required_lamports),
-                &[
-                    funder_info.clone(),
-                    job_info.clone(),
-                    system_program_info.clone(),
-                ],
-            )?;
-        }
-
-        invoke_signed(
-            &system_instruction::allocate(job_info.key, Job::LEN as u64),
-            &[job_info.clone(), system_program_info.clone()],
-            &[&job_seeds],
-        )?;
-
-        invoke_signed(
-            &system_instruction::assign(job_info.key, program_id),
-            &[job_info.clone(), system_program_info.clone()],
-            &[&job_seeds],
-        )?;
-
-        Job::new(InitJobParams {
-            authority: *authority_info.key,
-        })
-    } else if job_info.owner != program_id {
-        msg!("Job provided is not owned by the RNDR program");
-        return Err(RNDRError::UnspecifiedError.into());
-    } else {
-        Job::unpack(&job_info.try_borrow_data()?)?
-    };
-
     invoke(
         &spl_token::instruction::transfer(
             token_program_info.key,
@@ -297,14 +702,20 @@ fn process_fund_job(program_id: &Pubkey, amount: u64, accounts: &[AccountInfo])
         ],
     )?;
 
-    job.amount = job.amount.checked_add(amount).ok_or(RNDRError::MathError)?;
-    escrow.amount = escrow
-        .amount
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if !job_created && tier != 0 && tier != job.tier {
+        msg!("Tier of an existing job can only be changed with SetJobTier");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    job.amount = u64::from(job.amount)
         .checked_add(amount)
-        .ok_or(RNDRError::MathError)?;
-
-    Job::pack(job, &mut job_info.try_borrow_mut_data()?)?;
-    Escrow::pack(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+        .ok_or(RNDRError::MathError)?
+        .into();
+    escrow.amount = u64::from(escrow.amount)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
 
     Ok(())
 }
@@ -313,6 +724,8 @@ fn process_fund_job(program_id: &Pubkey, amount: u64, accounts: &[AccountInfo])
 fn process_disburse_funds(
     program_id: &Pubkey,
     amount: u64,
+    reference: Option<[u8; 32]>,
+    idempotency_key: Option<Vec<u8>>,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
     if amount == 0 {
@@ -332,28 +745,45 @@ fn process_disburse_funds(
     let destination_token_info = next_account_info(account_info_iter)?;
     // Programs
     let token_program_info = next_account_info(account_info_iter)?;
+    // Node earnings accounts
+    let node_earnings_info = next_account_info(account_info_iter)?;
+    let rent_payer_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let clock_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    // Receipt, only if a reference is provided
+    let receipt_info = match reference {
+        Some(_) => Some(next_account_info(account_info_iter)?),
+        None => None,
+    };
+    // Idempotency marker, only if an idempotency key is provided
+    let idempotency_marker_info = match idempotency_key {
+        Some(_) => Some(next_account_info(account_info_iter)?),
+        None => None,
+    };
+    // Optional render node
+    let node_info = account_info_iter.next();
 
-    let mut escrow_seeds: Vec<&[_]> = vec![
-        b"escrow",
-        token_mint_info.key.as_ref(),
-        token_program_info.key.as_ref(),
-    ];
-
-    let (escrow_address, bump_seed) = Pubkey::find_program_address(&escrow_seeds, program_id);
-    if &escrow_address != escrow_info.key {
-        msg!("Escrow program derived address does not match the escrow address provided");
-        return Err(ProgramError::InvalidSeeds);
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
     }
 
-    let bump_seed = &[bump_seed];
+    let namespace = Pubkey::from(escrow.namespace);
+    let bump_seed = &[escrow.bump_seed];
+    let mut escrow_seeds = escrow_seeds(token_mint_info.key, token_program_info.key, &namespace);
     escrow_seeds.push(bump_seed);
 
-    let mut escrow = Escrow::unpack(&escrow_info.try_borrow_data()?)?;
-    if escrow_info.owner != program_id {
-        msg!("Escrow provided is not owned by the RNDR program");
-        return Err(RNDRError::UnspecifiedError.into());
+    let escrow_address = Pubkey::create_program_address(&escrow_seeds, program_id)?;
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
     }
-    if &escrow.owner != escrow_owner_info.key {
+    if escrow.owner != *escrow_owner_info.key {
         msg!("Escrow owner does not match the escrow owner provided");
         return Err(RNDRError::UnspecifiedError.into());
     }
@@ -371,29 +801,112 @@ fn process_disburse_funds(
         return Err(ProgramError::InvalidSeeds);
     }
 
-    let mut job = Job::unpack(&job_info.try_borrow_data()?)?;
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
     if job_info.owner != program_id {
         msg!("Job provided is not owned by the RNDR program");
         return Err(RNDRError::UnspecifiedError.into());
     }
 
-    let (job_pubkey, _bump_seed) = Pubkey::find_program_address(
-        &[b"job", escrow_info.key.as_ref(), job.authority.as_ref()],
+    let job_pubkey = Pubkey::create_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.authority.as_ref(),
+            &[job.bump_seed],
+        ],
         program_id,
-    );
+    )?;
     if &job_pubkey != job_info.key {
         msg!("Job program derived address does not match the job address provided");
         return Err(ProgramError::InvalidSeeds);
     }
+    if job.submissions_required != 0 {
+        msg!("Job only pays out through accepted render submissions");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.priced_per_frame != 0 {
+        msg!("Job with a rate card only pays out through claimed frames");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
 
-    job.amount = job.amount.checked_sub(amount).ok_or(RNDRError::MathError)?;
-    escrow.amount = escrow
-        .amount
+    if let (Some(idempotency_marker_info), Some(idempotency_key)) =
+        (idempotency_marker_info, idempotency_key)
+    {
+        create_idempotency_marker(
+            program_id,
+            rent_payer_info,
+            idempotency_marker_info,
+            job_info.key,
+            u64::from(job.generation),
+            idempotency_key,
+            clock_info,
+            rent_info,
+            system_program_info,
+        )?;
+    }
+
+    let amount = job.apply_tier_multiplier(amount)?;
+    job.amount = u64::from(job.amount)
         .checked_sub(amount)
-        .ok_or(RNDRError::MathError)?;
+        .ok_or(RNDRError::MathError)?
+        .into();
+    job.disbursed = u64::from(job.disbursed)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    if job.exceeds_approved_amount()? {
+        msg!("Amount of tokens to disburse exceeds the approved milestones of the job");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
 
-    Job::pack(job, &mut job_info.try_borrow_mut_data()?)?;
-    Escrow::pack(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+    credit_node(
+        program_id,
+        escrow,
+        node_info,
+        None,
+        destination_token_info,
+        amount,
+    )?;
+
+    record_node_earnings(
+        program_id,
+        escrow_info.key,
+        rent_payer_info,
+        destination_token_info,
+        node_earnings_info,
+        clock_info,
+        rent_info,
+        system_program_info,
+        amount,
+    )?;
+
+    let payout_index = u64::from(job.payout_count);
+    job.payout_count = payout_index
+        .checked_add(1)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    if let (Some(receipt_info), Some(reference)) = (receipt_info, reference) {
+        write_receipt(
+            program_id,
+            rent_payer_info,
+            receipt_info,
+            job_info.key,
+            u64::from(job.generation),
+            payout_index,
+            escrow_owner_info.key,
+            destination_token_info.key,
+            amount,
+            reference,
+            clock_info,
+            rent_info,
+            system_program_info,
+        )?;
+    }
+
+    // The escrow signs the transfer, so its data can't be borrowed during it
+    drop(escrow_data);
 
     invoke_signed(
         &spl_token::instruction::transfer(
@@ -413,5 +926,4789 @@ fn process_disburse_funds(
         &[&escrow_seeds],
     )?;
 
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
+    escrow.amount = u64::from(escrow.amount)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_job_metadata(
+    program_id: &Pubkey,
+    scene_hash: [u8; 32],
+    content_id: Vec<u8>,
+    frame_start: u32,
+    frame_end: u32,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if content_id.len() > MAX_CONTENT_ID_LEN {
+        msg!("Content identifier is too long");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if frame_start > frame_end {
+        msg!("Frame range start can't be after the frame range end");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let job_metadata_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let rent_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let job_data = job_info.try_borrow_data()?;
+    let job = PodJob::load(&job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.authority != *authority_info.key {
+        msg!("Job authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !authority_info.is_signer {
+        msg!("Job authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if u64::from(job.disbursed) > 0 || u64::from(job.reserved) > 0 {
+        msg!("Job metadata can't be changed after funds have been disbursed or reserved");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut job_metadata_seeds: Vec<&[_]> = vec![
+        b"job_metadata",
+        job_info.key.as_ref(),
+        job.generation.as_ref(),
+    ];
+
+    let (job_metadata_address, bump_seed) =
+        Pubkey::find_program_address(&job_metadata_seeds, program_id);
+    if &job_metadata_address != job_metadata_info.key {
+        msg!(
+            "Job metadata program derived address does not match the job metadata address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if job_metadata_info.try_data_is_empty()? {
+        let bump_seed = &[bump_seed];
+        job_metadata_seeds.push(bump_seed);
+
+        create_program_account(
+            program_id,
+            funder_info,
+            job_metadata_info,
+            &job_metadata_seeds,
+            JobMetadata::LEN,
+            &Rent::from_account_info(rent_info)?,
+            system_program_info,
+        )?;
+    } else if job_metadata_info.owner != program_id {
+        msg!("Job metadata provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_metadata = JobMetadata::new(InitJobMetadataParams {
+        job: *job_info.key,
+        scene_hash,
+        content_id,
+        frame_start,
+        frame_end,
+    });
+    JobMetadata::pack(job_metadata, &mut job_metadata_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_add_milestone(
+    program_id: &Pubkey,
+    amount: u64,
+    description_hash: [u8; 32],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens for the milestone can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let job_info = next_account_info(account_info_iter)?;
+    let milestone_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let rent_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.authority != *authority_info.key {
+        msg!("Job authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !authority_info.is_signer {
+        msg!("Job authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let index = job.milestone_count;
+    let index_bytes = &[index];
+    let generation = job.generation;
+    let mut milestone_seeds: Vec<&[_]> = vec![
+        b"milestone",
+        job_info.key.as_ref(),
+        generation.as_ref(),
+        index_bytes,
+    ];
+
+    let (milestone_address, bump_seed) = Pubkey::find_program_address(&milestone_seeds, program_id);
+    if &milestone_address != milestone_info.key {
+        msg!("Milestone program derived address does not match the milestone address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let bump_seed = &[bump_seed];
+    milestone_seeds.push(bump_seed);
+
+    create_program_account(
+        program_id,
+        funder_info,
+        milestone_info,
+        &milestone_seeds,
+        Milestone::LEN,
+        &Rent::from_account_info(rent_info)?,
+        system_program_info,
+    )?;
+
+    job.milestone_count = job
+        .milestone_count
+        .checked_add(1)
+        .ok_or(RNDRError::MathError)?;
+
+    let milestone = Milestone::new(InitMilestoneParams {
+        job: *job_info.key,
+        index,
+        amount,
+        description_hash,
+    });
+
+    Milestone::pack(milestone, &mut milestone_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_approve_milestone(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let milestone_info = next_account_info(account_info_iter)?;
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.authority != *authority_info.key {
+        msg!("Job authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !authority_info.is_signer {
+        msg!("Job authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut milestone = Milestone::unpack(&milestone_info.try_borrow_data()?)?;
+    if milestone_info.owner != program_id {
+        msg!("Milestone provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &milestone.job != job_info.key {
+        msg!("Milestone job does not match the job provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    // Milestones of a closed job of the same address have another generation
+    let (milestone_address, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"milestone",
+            job_info.key.as_ref(),
+            job.generation.as_ref(),
+            &[milestone.index],
+        ],
+        program_id,
+    );
+    if &milestone_address != milestone_info.key {
+        msg!("Milestone program derived address does not match the milestone address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if milestone.approved {
+        msg!("Milestone has already been approved");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    milestone.approved = true;
+    job.approved_amount = u64::from(job.approved_amount)
+        .checked_add(milestone.amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    Milestone::pack(milestone, &mut milestone_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_post_payout_root(
+    program_id: &Pubkey,
+    root: [u8; 32],
+    amount: u64,
+    leaf_count: u32,
+    expiry: UnixTimestamp,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to pay out can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if leaf_count == 0 || leaf_count > MAX_PAYOUT_LEAVES {
+        msg!("Number of payout leaves is invalid");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_owner_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let payout_root_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let clock_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let clock = &Clock::from_account_info(clock_info)?;
+    if expiry <= clock.unix_timestamp {
+        msg!("Payout root expiry must be in the future");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if escrow.owner != *escrow_owner_info.key {
+        msg!("Escrow owner does not match the escrow owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !escrow_owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_pubkey = Pubkey::create_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.authority.as_ref(),
+            &[job.bump_seed],
+        ],
+        program_id,
+    )?;
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if job.submissions_required != 0 {
+        msg!("Job only pays out through accepted render submissions");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.priced_per_frame != 0 {
+        msg!("Job with a rate card only pays out through claimed frames");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let generation = job.generation;
+    let mut payout_root_seeds: Vec<&[_]> = vec![
+        b"payout_root",
+        job_info.key.as_ref(),
+        generation.as_ref(),
+        &root,
+    ];
+
+    let (payout_root_address, bump_seed) =
+        Pubkey::find_program_address(&payout_root_seeds, program_id);
+    if &payout_root_address != payout_root_info.key {
+        msg!("Payout root program derived address does not match the payout root address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let bump_seed = &[bump_seed];
+    payout_root_seeds.push(bump_seed);
+
+    create_program_account(
+        program_id,
+        funder_info,
+        payout_root_info,
+        &payout_root_seeds,
+        PayoutRoot::account_len(leaf_count),
+        &Rent::from_account_info(rent_info)?,
+        system_program_info,
+    )?;
+
+    // Leaf amounts are committed in the root, so the multiplier of the job tier is applied to
+    // them before the root is posted rather than here
+    job.amount = u64::from(job.amount)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    job.reserved = u64::from(job.reserved)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    if job.exceeds_approved_amount()? {
+        msg!("Amount of tokens to pay out exceeds the approved milestones of the job");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let payout_root = PayoutRoot::new(InitPayoutRootParams {
+        escrow: *escrow_info.key,
+        job: *job_info.key,
+        root,
+        amount,
+        leaf_count,
+        expiry,
+        rent_payer: *funder_info.key,
+    });
+
+    PayoutRoot::pack(
+        payout_root,
+        &mut payout_root_info.try_borrow_mut_data()?[..PayoutRoot::LEN],
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_claim_payout(
+    program_id: &Pubkey,
+    index: u32,
+    amount: u64,
+    proof: &[[u8; 32]],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let payout_root_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let clock_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+    // Optional render node
+    let node_info = account_info_iter.next();
+
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let namespace = Pubkey::from(escrow.namespace);
+    let bump_seed = &[escrow.bump_seed];
+    let mut escrow_seeds = escrow_seeds(token_mint_info.key, token_program_info.key, &namespace);
+    escrow_seeds.push(bump_seed);
+
+    let escrow_address = Pubkey::create_program_address(&escrow_seeds, program_id)?;
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let escrow_associated_token_address =
+        get_associated_token_address(&escrow_address, token_mint_info.key);
+    if &escrow_associated_token_address != escrow_associated_token_info.key {
+        msg!(
+            "Escrow associated token address does not match the associated token address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut payout_root = unpack_payout_root(program_id, payout_root_info)?;
+    if &payout_root.escrow != escrow_info.key {
+        msg!("Payout root escrow does not match the escrow provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &payout_root.job != job_info.key {
+        msg!("Payout root job does not match the job provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let clock = &Clock::from_account_info(clock_info)?;
+    if clock.unix_timestamp > payout_root.expiry {
+        msg!("Payout root has expired");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if index >= payout_root.leaf_count {
+        msg!("Payout leaf index is out of range");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if PayoutRoot::is_claimed(
+        &payout_root_info.try_borrow_data()?[PayoutRoot::LEN..],
+        index,
+    ) {
+        msg!("Payout leaf has already been claimed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let destination_token =
+        spl_token::state::Account::unpack(&destination_token_info.try_borrow_data()?)?;
+    let leaf = merkle::leaf_hash(index, &destination_token.owner, amount, job_info.key);
+    if !merkle::verify_proof(leaf, proof, &payout_root.root) {
+        msg!("Merkle proof is invalid for the payout leaf");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    payout_root.claimed_amount = payout_root
+        .claimed_amount
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?;
+    if payout_root.claimed_amount > payout_root.amount {
+        msg!("Amount of tokens claimed exceeds the payout root amount");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    job.reserved = u64::from(job.reserved)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    job.disbursed = u64::from(job.disbursed)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    credit_node(
+        program_id,
+        escrow,
+        node_info,
+        None,
+        destination_token_info,
+        amount,
+    )?;
+
+    // The escrow signs the transfer, so its data can't be borrowed during it
+    drop(escrow_data);
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            escrow_associated_token_info.key,
+            destination_token_info.key,
+            escrow_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            escrow_associated_token_info.clone(),
+            destination_token_info.clone(),
+            escrow_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[&escrow_seeds],
+    )?;
+
+    let mut payout_root_data = payout_root_info.try_borrow_mut_data()?;
+    let (payout_root_data, bitmap) = payout_root_data.split_at_mut(PayoutRoot::LEN);
+    PayoutRoot::set_claimed(bitmap, index);
+    PayoutRoot::pack(payout_root, payout_root_data)?;
+
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
+    escrow.amount = u64::from(escrow.amount)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_reclaim_payout_root(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let job_info = next_account_info(account_info_iter)?;
+    let payout_root_info = next_account_info(account_info_iter)?;
+    let rent_payer_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let clock_info = next_account_info(account_info_iter)?;
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let payout_root = unpack_payout_root(program_id, payout_root_info)?;
+    if &payout_root.job != job_info.key {
+        msg!("Payout root job does not match the job provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &payout_root.rent_payer != rent_payer_info.key {
+        msg!("Payout root rent payer does not match the rent payer provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let clock = &Clock::from_account_info(clock_info)?;
+    if clock.unix_timestamp <= payout_root.expiry {
+        msg!("Payout root has not expired yet");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let unclaimed_amount = payout_root
+        .amount
+        .checked_sub(payout_root.claimed_amount)
+        .ok_or(RNDRError::MathError)?;
+    job.reserved = u64::from(job.reserved)
+        .checked_sub(unclaimed_amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    job.amount = u64::from(job.amount)
+        .checked_add(unclaimed_amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    close_program_account(payout_root_info, rent_payer_info)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_redeem_voucher(
+    program_id: &Pubkey,
+    voucher: Voucher,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if voucher.amount == 0 {
+        msg!("Amount of tokens to redeem can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_info = next_account_info(account_info_iter)?;
+    let redeemed_voucher_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let instructions_info = next_account_info(account_info_iter)?;
+    let clock_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    // Optional render node
+    let node_info = account_info_iter.next();
+
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let namespace = Pubkey::from(escrow.namespace);
+    let bump_seed = &[escrow.bump_seed];
+    let mut escrow_seeds = escrow_seeds(token_mint_info.key, token_program_info.key, &namespace);
+    escrow_seeds.push(bump_seed);
+
+    let escrow_address = Pubkey::create_program_address(&escrow_seeds, program_id)?;
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let escrow_associated_token_address =
+        get_associated_token_address(&escrow_address, token_mint_info.key);
+    if &escrow_associated_token_address != escrow_associated_token_info.key {
+        msg!(
+            "Escrow associated token address does not match the associated token address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if &voucher.escrow != escrow_info.key {
+        msg!("Voucher escrow does not match the escrow provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &voucher.job != job_info.key {
+        msg!("Voucher job does not match the job provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &voucher.destination != destination_token_info.key {
+        msg!("Voucher destination does not match the destination provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let clock = &Clock::from_account_info(clock_info)?;
+    if clock.unix_timestamp > voucher.expiry {
+        msg!("Voucher has expired");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    if instructions_info.key != &sysvar::instructions::id() {
+        msg!("Instructions sysvar provided is invalid");
+        return Err(ProgramError::InvalidArgument);
+    }
+    let ed25519_instruction = {
+        let instructions_data = instructions_info.try_borrow_data()?;
+        let current_index = sysvar::instructions::load_current_index(&instructions_data);
+        if current_index == 0 {
+            msg!("Voucher must be preceded by an ed25519 program instruction");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        sysvar::instructions::load_instruction_at(current_index as usize - 1, &instructions_data)
+            .map_err(|_| ProgramError::InvalidAccountData)?
+    };
+    let mut message = [0; Voucher::LEN];
+    voucher.pack_into_slice(&mut message);
+    voucher::verify_ed25519_instruction(&ed25519_instruction, &escrow.owner.into(), &message)?;
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_pubkey = Pubkey::create_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.authority.as_ref(),
+            &[job.bump_seed],
+        ],
+        program_id,
+    )?;
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if job.submissions_required != 0 {
+        msg!("Job only pays out through accepted render submissions");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.priced_per_frame != 0 {
+        msg!("Job with a rate card only pays out through claimed frames");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let nonce_bytes = voucher.nonce.to_le_bytes();
+    let mut redeemed_voucher_seeds: Vec<&[_]> =
+        vec![b"voucher", escrow_info.key.as_ref(), &nonce_bytes];
+
+    let (redeemed_voucher_address, bump_seed) =
+        Pubkey::find_program_address(&redeemed_voucher_seeds, program_id);
+    if &redeemed_voucher_address != redeemed_voucher_info.key {
+        msg!("Redeemed voucher program derived address does not match the address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !redeemed_voucher_info.try_data_is_empty()? {
+        msg!("Voucher has already been redeemed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = &[bump_seed];
+    redeemed_voucher_seeds.push(bump_seed);
+
+    create_program_account(
+        program_id,
+        funder_info,
+        redeemed_voucher_info,
+        &redeemed_voucher_seeds,
+        RedeemedVoucher::LEN,
+        &Rent::from_account_info(rent_info)?,
+        system_program_info,
+    )?;
+
+    // The voucher amount is signed by the escrow owner, so the multiplier of the job tier is
+    // applied to it before signing rather than here
+    let amount = voucher.amount;
+    job.amount = u64::from(job.amount)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    job.disbursed = u64::from(job.disbursed)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    if job.exceeds_approved_amount()? {
+        msg!("Amount of tokens to redeem exceeds the approved milestones of the job");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    credit_node(
+        program_id,
+        escrow,
+        node_info,
+        None,
+        destination_token_info,
+        amount,
+    )?;
+
+    // The escrow signs the transfer, so its data can't be borrowed during it
+    drop(escrow_data);
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            escrow_associated_token_info.key,
+            destination_token_info.key,
+            escrow_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            escrow_associated_token_info.clone(),
+            destination_token_info.clone(),
+            escrow_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[&escrow_seeds],
+    )?;
+
+    let redeemed_voucher = RedeemedVoucher::new(InitRedeemedVoucherParams {
+        escrow: *escrow_info.key,
+        nonce: voucher.nonce,
+    });
+
+    RedeemedVoucher::pack(
+        redeemed_voucher,
+        &mut redeemed_voucher_info.try_borrow_mut_data()?,
+    )?;
+
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
+    escrow.amount = u64::from(escrow.amount)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_batch_disburse(
+    program_id: &Pubkey,
+    job_count: u8,
+    disbursements: &[Disbursement],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if disbursements.is_empty() {
+        msg!("Batch must contain at least one disbursement");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_owner_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+    // Jobs, destination and render node accounts
+    let remaining_infos = account_info_iter.as_slice();
+    if remaining_infos.len() < job_count as usize {
+        msg!("Number of jobs exceeds the number of accounts provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (job_infos, remaining_infos) = remaining_infos.split_at(job_count as usize);
+
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let namespace = Pubkey::from(escrow.namespace);
+    let bump_seed = &[escrow.bump_seed];
+    let mut escrow_seeds = escrow_seeds(token_mint_info.key, token_program_info.key, &namespace);
+    escrow_seeds.push(bump_seed);
+
+    let escrow_address = Pubkey::create_program_address(&escrow_seeds, program_id)?;
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if escrow.owner != *escrow_owner_info.key {
+        msg!("Escrow owner does not match the escrow owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !escrow_owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let escrow_associated_token_address =
+        get_associated_token_address(&escrow_address, token_mint_info.key);
+    if &escrow_associated_token_address != escrow_associated_token_info.key {
+        msg!(
+            "Escrow associated token address does not match the associated token address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Escrows requiring registered render nodes take the node of each destination after it
+    let (destination_token_infos, node_infos): (Vec<_>, Vec<_>) =
+        if escrow.node_registry_required != 0 {
+            if remaining_infos.len() % 2 != 0 {
+                msg!("Render node must be provided after each destination");
+                return Err(ProgramError::NotEnoughAccountKeys);
+            }
+            remaining_infos
+                .chunks_exact(2)
+                .map(|infos| (&infos[0], Some(&infos[1])))
+                .unzip()
+        } else {
+            remaining_infos.iter().map(|info| (info, None)).unzip()
+        };
+
+    for (i, job_info) in job_infos.iter().enumerate() {
+        if job_infos[..i].iter().any(|other| other.key == job_info.key) {
+            msg!("Job provided more than once");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    }
+
+    // Validate each job once, no matter how many disbursements debit it
+    let mut job_datas = job_infos
+        .iter()
+        .map(|job_info| job_info.try_borrow_mut_data())
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut jobs = job_datas
+        .iter_mut()
+        .map(|job_data| PodJob::load_mut(job_data))
+        .collect::<Result<Vec<_>, _>>()?;
+    for (job_info, job) in job_infos.iter().zip(jobs.iter()) {
+        if job_info.owner != program_id {
+            msg!("Job provided is not owned by the RNDR program");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+
+        let job_pubkey = Pubkey::create_program_address(
+            &[
+                b"job",
+                escrow_info.key.as_ref(),
+                job.authority.as_ref(),
+                &[job.bump_seed],
+            ],
+            program_id,
+        )?;
+        if &job_pubkey != job_info.key {
+            msg!("Job program derived address does not match the job address provided");
+            return Err(ProgramError::InvalidSeeds);
+        }
+        if job.submissions_required != 0 {
+            msg!("Job only pays out through accepted render submissions");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        if job.priced_per_frame != 0 {
+            msg!("Job with a rate card only pays out through claimed frames");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    }
+
+    let mut destination_amounts = vec![0u64; destination_token_infos.len()];
+    let mut total_amount: u64 = 0;
+    for disbursement in disbursements {
+        if disbursement.amount == 0 {
+            msg!("Amount of tokens to disburse can't be zero");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        if disbursement.job_index as usize >= jobs.len() {
+            msg!("Disbursement job index is out of range");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        if disbursement.destination_index as usize >= destination_amounts.len() {
+            msg!("Disbursement destination index is out of range");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+
+        let job = &mut jobs[disbursement.job_index as usize];
+        let amount = job.apply_tier_multiplier(disbursement.amount)?;
+        job.amount = u64::from(job.amount)
+            .checked_sub(amount)
+            .ok_or(RNDRError::MathError)?
+            .into();
+        job.disbursed = u64::from(job.disbursed)
+            .checked_add(amount)
+            .ok_or(RNDRError::MathError)?
+            .into();
+
+        let destination_amount = &mut destination_amounts[disbursement.destination_index as usize];
+        *destination_amount = destination_amount
+            .checked_add(amount)
+            .ok_or(RNDRError::MathError)?;
+        total_amount = total_amount
+            .checked_add(amount)
+            .ok_or(RNDRError::MathError)?;
+    }
+
+    for job in jobs {
+        if job.exceeds_approved_amount()? {
+            msg!("Amount of tokens to disburse exceeds the approved milestones of the job");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    }
+
+    for ((destination_token_info, node_info), amount) in destination_token_infos
+        .iter()
+        .zip(node_infos)
+        .zip(destination_amounts.iter())
+    {
+        if *amount == 0 {
+            continue;
+        }
+        credit_node(
+            program_id,
+            escrow,
+            node_info,
+            None,
+            destination_token_info,
+            *amount,
+        )?;
+    }
+
+    // The escrow signs the transfers, so its data can't be borrowed during them
+    drop(escrow_data);
+
+    for (destination_token_info, amount) in
+        destination_token_infos.into_iter().zip(destination_amounts)
+    {
+        if amount == 0 {
+            continue;
+        }
+
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                escrow_associated_token_info.key,
+                destination_token_info.key,
+                escrow_info.key,
+                &[],
+                amount,
+            )?,
+            &[
+                escrow_associated_token_info.clone(),
+                destination_token_info.clone(),
+                escrow_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[&escrow_seeds],
+        )?;
+    }
+
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
+    escrow.amount = u64::from(escrow.amount)
+        .checked_sub(total_amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    Ok(())
+}
+
+/// Seeds of an escrow program derived address, without the namespace for the legacy escrow
+fn escrow_seeds<'a>(
+    token_mint: &'a Pubkey,
+    token_program: &'a Pubkey,
+    namespace: &'a Pubkey,
+) -> Vec<&'a [u8]> {
+    let mut seeds: Vec<&[u8]> = vec![b"escrow", token_mint.as_ref(), token_program.as_ref()];
+    if namespace != &Pubkey::default() {
+        seeds.push(namespace.as_ref());
+    }
+    seeds
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_close_job(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let rent_payer_info = next_account_info(account_info_iter)?;
+
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_data = job_info.try_borrow_data()?;
+    let job = PodJob::load(&job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_address = Pubkey::create_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.authority.as_ref(),
+            &[job.bump_seed],
+        ],
+        program_id,
+    )?;
+    if &job_address != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if job.authority != *authority_info.key && escrow.owner != *authority_info.key {
+        msg!("Job authority or escrow owner does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !authority_info.is_signer {
+        msg!("Authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.rent_payer != *rent_payer_info.key {
+        msg!("Job rent payer does not match the rent payer provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if u64::from(job.amount) != 0 {
+        msg!("Job can't be closed while it has funds in escrow");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if u64::from(job.reserved) != 0 {
+        msg!("Job can't be closed while funds are reserved for pending payouts");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    escrow.job_count = u64::from(escrow.job_count)
+        .checked_sub(1)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    drop(job_data);
+    // The zeroed data can't be loaded as a Job, even if the account is kept alive with new lamports
+    close_program_account(job_info, rent_payer_info)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_close_escrow(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_owner_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+    // Optional leftover destination account
+    let leftover_token_info = account_info_iter.next();
+
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let namespace = Pubkey::from(escrow.namespace);
+    let bump_seed = &[escrow.bump_seed];
+    let mut escrow_seeds = escrow_seeds(token_mint_info.key, token_program_info.key, &namespace);
+    escrow_seeds.push(bump_seed);
+
+    let escrow_address = Pubkey::create_program_address(&escrow_seeds, program_id)?;
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if escrow.owner != *escrow_owner_info.key {
+        msg!("Escrow owner does not match the escrow owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !escrow_owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let escrow_associated_token_address =
+        get_associated_token_address(&escrow_address, token_mint_info.key);
+    if &escrow_associated_token_address != escrow_associated_token_info.key {
+        msg!(
+            "Escrow associated token address does not match the associated token address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if u64::from(escrow.amount) != 0 {
+        msg!("Escrow can't be closed while it holds funds for jobs");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if u64::from(escrow.job_count) != 0 {
+        msg!("Escrow can't be closed while it has open jobs");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    // Tokens sent straight to the escrow token account aren't held for any job, and must be swept
+    // out before the token account can be closed
+    let leftover_amount =
+        spl_token::state::Account::unpack(&escrow_associated_token_info.try_borrow_data()?)?.amount;
+
+    // The escrow signs the token transfer and account closure, so its data can't be borrowed
+    // during them
+    drop(escrow_data);
+
+    if leftover_amount != 0 {
+        let leftover_token_info = leftover_token_info.ok_or_else(|| {
+            msg!("Escrow token account holds leftover tokens, a leftover account must be provided");
+            ProgramError::NotEnoughAccountKeys
+        })?;
+        if leftover_token_info.key == escrow_associated_token_info.key {
+            msg!("Leftover token account can't be the escrow token account");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                escrow_associated_token_info.key,
+                leftover_token_info.key,
+                escrow_info.key,
+                &[],
+                leftover_amount,
+            )?,
+            &[
+                escrow_associated_token_info.clone(),
+                leftover_token_info.clone(),
+                escrow_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[&escrow_seeds],
+        )?;
+    }
+
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            token_program_info.key,
+            escrow_associated_token_info.key,
+            destination_info.key,
+            escrow_info.key,
+            &[],
+        )?,
+        &[
+            escrow_associated_token_info.clone(),
+            destination_info.clone(),
+            escrow_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[&escrow_seeds],
+    )?;
+
+    close_program_account(escrow_info, destination_info)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_migrate_escrow(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Old escrow accounts
+    let old_token_mint_info = next_account_info(account_info_iter)?;
+    let old_escrow_info = next_account_info(account_info_iter)?;
+    let escrow_owner_info = next_account_info(account_info_iter)?;
+    // New escrow accounts
+    let new_token_mint_info = next_account_info(account_info_iter)?;
+    let funder_info = next_account_info(account_info_iter)?;
+    let program_config_info = next_account_info(account_info_iter)?;
+    let new_escrow_info = next_account_info(account_info_iter)?;
+    let new_escrow_associated_token_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let rent_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let associated_token_program_info = next_account_info(account_info_iter)?;
+
+    let mut old_escrow_data = old_escrow_info.try_borrow_mut_data()?;
+    let old_escrow = PodEscrow::load_mut(&mut old_escrow_data)?;
+    if old_escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let namespace = Pubkey::from(old_escrow.namespace);
+    let bump_seed = &[old_escrow.bump_seed];
+    let mut old_escrow_seeds =
+        escrow_seeds(old_token_mint_info.key, token_program_info.key, &namespace);
+    old_escrow_seeds.push(bump_seed);
+
+    let old_escrow_address = Pubkey::create_program_address(&old_escrow_seeds, program_id)?;
+    if &old_escrow_address != old_escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if old_escrow.owner != *escrow_owner_info.key {
+        msg!("Escrow owner does not match the escrow owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !escrow_owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if old_escrow.successor != Pubkey::default() {
+        msg!("Escrow is already migrating to a new token mint");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    if old_token_mint_info.key == new_token_mint_info.key {
+        msg!("New token mint must differ from the token mint of the escrow");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    // Job balances are moved over 1:1, which only holds for mints of the same precision
+    let old_token_mint = spl_token::state::Mint::unpack(&old_token_mint_info.try_borrow_data()?)?;
+    let new_token_mint = spl_token::state::Mint::unpack(&new_token_mint_info.try_borrow_data()?)?;
+    if old_token_mint.decimals != new_token_mint.decimals {
+        msg!("New token mint decimals do not match the token mint decimals of the escrow");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    if let Some(program_config) = get_program_config(program_id, program_config_info)? {
+        check_allowed_by_program_config(&program_config, new_token_mint_info, token_program_info)?;
+    }
+
+    create_escrow(
+        program_id,
+        *escrow_owner_info.key,
+        namespace,
+        new_token_mint_info,
+        funder_info,
+        new_escrow_info,
+        new_escrow_associated_token_info,
+        rent_info,
+        system_program_info,
+        token_program_info,
+        associated_token_program_info,
+    )?;
+
+    let mut new_escrow_data = new_escrow_info.try_borrow_mut_data()?;
+    let new_escrow = PodEscrow::load_mut(&mut new_escrow_data)?;
+    new_escrow.tier_multipliers = old_escrow.tier_multipliers;
+    new_escrow.node_registry_required = old_escrow.node_registry_required;
+
+    old_escrow.successor = (*new_escrow_info.key).into();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_migrate_job(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Old escrow accounts
+    let old_token_mint_info = next_account_info(account_info_iter)?;
+    let old_escrow_info = next_account_info(account_info_iter)?;
+    let escrow_owner_info = next_account_info(account_info_iter)?;
+    let old_escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let old_job_info = next_account_info(account_info_iter)?;
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // New escrow accounts
+    let new_token_mint_info = next_account_info(account_info_iter)?;
+    let new_escrow_info = next_account_info(account_info_iter)?;
+    let new_escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let new_job_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let source_authority_info = next_account_info(account_info_iter)?;
+    let funder_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let rent_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    let old_escrow_data = old_escrow_info.try_borrow_data()?;
+    let old_escrow = PodEscrow::load(&old_escrow_data)?;
+    if old_escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let namespace = Pubkey::from(old_escrow.namespace);
+    let old_bump_seed = &[old_escrow.bump_seed];
+    let mut old_escrow_seeds =
+        escrow_seeds(old_token_mint_info.key, token_program_info.key, &namespace);
+    old_escrow_seeds.push(old_bump_seed);
+
+    let old_escrow_address = Pubkey::create_program_address(&old_escrow_seeds, program_id)?;
+    if &old_escrow_address != old_escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if old_escrow.owner != *escrow_owner_info.key {
+        msg!("Escrow owner does not match the escrow owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !escrow_owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if old_escrow.successor == Pubkey::default() {
+        msg!("Escrow is not migrating to a new token mint");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if old_escrow.successor != *new_escrow_info.key {
+        msg!("Escrow successor does not match the new escrow provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let old_escrow_associated_token_address =
+        get_associated_token_address(&old_escrow_address, old_token_mint_info.key);
+    if &old_escrow_associated_token_address != old_escrow_associated_token_info.key {
+        msg!(
+            "Escrow associated token address does not match the associated token address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let new_escrow_data = new_escrow_info.try_borrow_data()?;
+    let new_escrow = PodEscrow::load(&new_escrow_data)?;
+    if new_escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let new_bump_seed = &[new_escrow.bump_seed];
+    let mut new_escrow_seeds =
+        escrow_seeds(new_token_mint_info.key, token_program_info.key, &namespace);
+    new_escrow_seeds.push(new_bump_seed);
+
+    let new_escrow_address = Pubkey::create_program_address(&new_escrow_seeds, program_id)?;
+    if &new_escrow_address != new_escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let new_escrow_associated_token_address =
+        get_associated_token_address(&new_escrow_address, new_token_mint_info.key);
+    if &new_escrow_associated_token_address != new_escrow_associated_token_info.key {
+        msg!(
+            "Escrow associated token address does not match the associated token address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let old_job_data = old_job_info.try_borrow_data()?;
+    let old_job = PodJob::load(&old_job_data)?;
+    if old_job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let authority = Pubkey::from(old_job.authority);
+    let old_job_pubkey = Pubkey::create_program_address(
+        &[
+            b"job",
+            old_escrow_info.key.as_ref(),
+            authority.as_ref(),
+            &[old_job.bump_seed],
+        ],
+        program_id,
+    )?;
+    if &old_job_pubkey != old_job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if u64::from(old_job.reserved) != 0 {
+        msg!("Job can't be migrated while funds are reserved for pending payouts");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    // Milestones, render submissions and rate cards are seeded by the old job, so the new job
+    // can't gate its payouts on them
+    if old_job.milestone_count != 0 {
+        msg!("Job with milestones can't be migrated");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if old_job.submissions_required != 0 {
+        msg!("Job requiring render submissions can't be migrated");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if old_job.priced_per_frame != 0 {
+        msg!("Job with a rate card can't be migrated");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let amount = u64::from(old_job.amount);
+    if amount == 0 {
+        msg!("Job has no funds to migrate");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let new_job_generation = u64::from(new_escrow.jobs_created);
+    let tier = old_job.tier;
+    let tier_multiplier = u32::from(old_job.tier_multiplier);
+
+    // The old escrow signs the transfer of the old tokens, so its data can't be borrowed during it
+    drop(old_escrow_data);
+    drop(new_escrow_data);
+    drop(old_job_data);
+
+    let new_job_created = init_or_check_job(
+        program_id,
+        new_escrow_info.key,
+        &authority,
+        new_job_generation,
+        tier,
+        tier_multiplier,
+        new_job_info,
+        funder_info,
+        rent_info,
+        system_program_info,
+    )?;
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_token_info.key,
+            new_escrow_associated_token_info.key,
+            source_authority_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            source_token_info.clone(),
+            new_escrow_associated_token_info.clone(),
+            source_authority_info.clone(),
+            token_program_info.clone(),
+        ],
+    )?;
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            old_escrow_associated_token_info.key,
+            destination_token_info.key,
+            old_escrow_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            old_escrow_associated_token_info.clone(),
+            destination_token_info.clone(),
+            old_escrow_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[&old_escrow_seeds],
+    )?;
+
+    let mut old_escrow_data = old_escrow_info.try_borrow_mut_data()?;
+    let old_escrow = PodEscrow::load_mut(&mut old_escrow_data)?;
+    old_escrow.amount = u64::from(old_escrow.amount)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    let mut old_job_data = old_job_info.try_borrow_mut_data()?;
+    let old_job = PodJob::load_mut(&mut old_job_data)?;
+    old_job.amount = 0.into();
+
+    let mut new_escrow_data = new_escrow_info.try_borrow_mut_data()?;
+    let new_escrow = PodEscrow::load_mut(&mut new_escrow_data)?;
+    new_escrow.amount = u64::from(new_escrow.amount)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    if new_job_created {
+        new_escrow.job_count = u64::from(new_escrow.job_count)
+            .checked_add(1)
+            .ok_or(RNDRError::MathError)?
+            .into();
+        new_escrow.jobs_created = u64::from(new_escrow.jobs_created)
+            .checked_add(1)
+            .ok_or(RNDRError::MathError)?
+            .into();
+    }
+
+    let mut new_job_data = new_job_info.try_borrow_mut_data()?;
+    let new_job = PodJob::load_mut(&mut new_job_data)?;
+    new_job.amount = u64::from(new_job.amount)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_batch_fund_job(
+    program_id: &Pubkey,
+    fundings: &[JobFunding],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if fundings.is_empty() {
+        msg!("Batch must contain at least one funding");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let source_authority_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let rent_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    // Jobs
+    let job_infos = account_info_iter.as_slice();
+    if job_infos.len() != fundings.len() {
+        msg!("Number of jobs does not match the number of fundings");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let namespace = Pubkey::from(escrow.namespace);
+    let bump_seed = &[escrow.bump_seed];
+    let mut escrow_seeds = escrow_seeds(token_mint_info.key, token_program_info.key, &namespace);
+    escrow_seeds.push(bump_seed);
+
+    let escrow_address = Pubkey::create_program_address(&escrow_seeds, program_id)?;
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if escrow.successor != Pubkey::default() {
+        msg!("Escrow is migrating to a new token mint and can't be funded");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let escrow_associated_token_address =
+        get_associated_token_address(&escrow_address, token_mint_info.key);
+    if &escrow_associated_token_address != escrow_associated_token_info.key {
+        msg!(
+            "Escrow associated token address does not match the associated token address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    for (i, job_info) in job_infos.iter().enumerate() {
+        if job_infos[..i].iter().any(|other| other.key == job_info.key) {
+            msg!("Job provided more than once");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    }
+
+    let mut total_amount: u64 = 0;
+    let mut created_count: u64 = 0;
+    for (funding, job_info) in fundings.iter().zip(job_infos) {
+        if funding.amount == 0 {
+            msg!("Amount of tokens to fund can't be zero");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        total_amount = total_amount
+            .checked_add(funding.amount)
+            .ok_or(RNDRError::MathError)?;
+
+        if init_or_check_job(
+            program_id,
+            escrow_info.key,
+            &funding.authority,
+            escrow.jobs_created.into(),
+            0,
+            TIER_MULTIPLIER_BASE,
+            job_info,
+            funder_info,
+            rent_info,
+            system_program_info,
+        )? {
+            created_count += 1;
+            escrow.jobs_created = u64::from(escrow.jobs_created)
+                .checked_add(1)
+                .ok_or(RNDRError::MathError)?
+                .into();
+        }
+    }
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_token_info.key,
+            escrow_associated_token_info.key,
+            source_authority_info.key,
+            &[],
+            total_amount,
+        )?,
+        &[
+            source_token_info.clone(),
+            escrow_associated_token_info.clone(),
+            source_authority_info.clone(),
+            token_program_info.clone(),
+        ],
+    )?;
+
+    for (funding, job_info) in fundings.iter().zip(job_infos) {
+        let mut job_data = job_info.try_borrow_mut_data()?;
+        let job = PodJob::load_mut(&mut job_data)?;
+        job.amount = u64::from(job.amount)
+            .checked_add(funding.amount)
+            .ok_or(RNDRError::MathError)?
+            .into();
+    }
+    escrow.amount = u64::from(escrow.amount)
+        .checked_add(total_amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    escrow.job_count = u64::from(escrow.job_count)
+        .checked_add(created_count)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_init_funding_schedule(
+    program_id: &Pubkey,
+    amount: u64,
+    period: i64,
+    first_due: UnixTimestamp,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to fund can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if period <= 0 {
+        msg!("Funding period must be positive");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let funding_schedule_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let rent_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let namespace = Pubkey::from(escrow.namespace);
+    let bump_seed = &[escrow.bump_seed];
+    let mut escrow_seeds = escrow_seeds(token_mint_info.key, token_program_info.key, &namespace);
+    escrow_seeds.push(bump_seed);
+
+    let escrow_address = Pubkey::create_program_address(&escrow_seeds, program_id)?;
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if escrow.successor != Pubkey::default() {
+        msg!("Escrow is migrating to a new token mint and can't be funded");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_data = job_info.try_borrow_data()?;
+    let job = PodJob::load(&job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_pubkey = Pubkey::create_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.authority.as_ref(),
+            &[job.bump_seed],
+        ],
+        program_id,
+    )?;
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if job.authority != *authority_info.key {
+        msg!("Job authority does not match the job authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !authority_info.is_signer {
+        msg!("Job authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    if source_token_info.owner != token_program_info.key {
+        msg!("Source token account provided is not owned by the token program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let source_token = spl_token::state::Account::unpack(&source_token_info.try_borrow_data()?)?;
+    if source_token.mint != *token_mint_info.key {
+        msg!("Source token account mint does not match the token mint provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if source_token.owner != *authority_info.key {
+        msg!("Source token account must be owned by the job authority");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if source_token.delegate != COption::Some(*escrow_info.key) {
+        msg!("Escrow must be approved as delegate of the source token account");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    if !funding_schedule_info.try_data_is_empty()? {
+        msg!("Funding schedule already exists for the job");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut funding_schedule_seeds: Vec<&[_]> = vec![
+        b"funding_schedule",
+        job_info.key.as_ref(),
+        job.generation.as_ref(),
+    ];
+    let (funding_schedule_address, bump_seed) =
+        Pubkey::find_program_address(&funding_schedule_seeds, program_id);
+    if &funding_schedule_address != funding_schedule_info.key {
+        msg!("Funding schedule program derived address does not match the address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let bump_seed = &[bump_seed];
+    funding_schedule_seeds.push(bump_seed);
+
+    create_program_account(
+        program_id,
+        funder_info,
+        funding_schedule_info,
+        &funding_schedule_seeds,
+        FundingSchedule::LEN,
+        &Rent::from_account_info(rent_info)?,
+        system_program_info,
+    )?;
+
+    let funding_schedule = FundingSchedule::new(InitFundingScheduleParams {
+        escrow: *escrow_info.key,
+        job: *job_info.key,
+        authority: *authority_info.key,
+        source_token: *source_token_info.key,
+        amount,
+        period,
+        next_due: first_due,
+        bump_seed: bump_seed[0],
+        rent_payer: *funder_info.key,
+    });
+    FundingSchedule::pack(
+        funding_schedule,
+        &mut funding_schedule_info.try_borrow_mut_data()?,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_crank_funding(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let funding_schedule_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let source_token_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let clock_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let namespace = Pubkey::from(escrow.namespace);
+    let bump_seed = &[escrow.bump_seed];
+    let mut escrow_seeds = escrow_seeds(token_mint_info.key, token_program_info.key, &namespace);
+    escrow_seeds.push(bump_seed);
+
+    let escrow_address = Pubkey::create_program_address(&escrow_seeds, program_id)?;
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if escrow.successor != Pubkey::default() {
+        msg!("Escrow is migrating to a new token mint and can't be funded");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let escrow_associated_token_address =
+        get_associated_token_address(&escrow_address, token_mint_info.key);
+    if &escrow_associated_token_address != escrow_associated_token_info.key {
+        msg!(
+            "Escrow associated token address does not match the associated token address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut funding_schedule = FundingSchedule::unpack(&funding_schedule_info.try_borrow_data()?)?;
+    if funding_schedule_info.owner != program_id {
+        msg!("Funding schedule provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    if funding_schedule.escrow != *escrow_info.key {
+        msg!("Funding schedule escrow does not match the escrow provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if funding_schedule.job != *job_info.key {
+        msg!("Funding schedule job does not match the job provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if funding_schedule.source_token != *source_token_info.key {
+        msg!("Funding schedule source token account does not match the account provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    // The job can have been closed since the schedule was created, and a job created again at the
+    // same address has another generation
+    let generation = PodJob::load(&job_info.try_borrow_data()?)?.generation;
+    let funding_schedule_address = Pubkey::create_program_address(
+        &[
+            b"funding_schedule",
+            job_info.key.as_ref(),
+            generation.as_ref(),
+            &[funding_schedule.bump_seed],
+        ],
+        program_id,
+    )?;
+    if &funding_schedule_address != funding_schedule_info.key {
+        msg!("Funding schedule program derived address does not match the address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if funding_schedule.paused {
+        msg!("Funding schedule is paused");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let clock = &Clock::from_account_info(clock_info)?;
+    if clock.unix_timestamp < funding_schedule.next_due {
+        msg!("Funding schedule is not due yet");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let amount = funding_schedule.amount;
+    let source_token = spl_token::state::Account::unpack(&source_token_info.try_borrow_data()?)?;
+    if source_token.delegate != COption::Some(*escrow_info.key)
+        || source_token.delegated_amount < amount
+        || source_token.amount < amount
+    {
+        // Pausing is recorded, so the crank succeeds without moving funds
+        msg!("Escrow allowance ran out, pausing the funding schedule");
+        funding_schedule.paused = true;
+        FundingSchedule::pack(
+            funding_schedule,
+            &mut funding_schedule_info.try_borrow_mut_data()?,
+        )?;
+        return Ok(());
+    }
+
+    funding_schedule.next_due = funding_schedule
+        .next_due
+        .checked_add(funding_schedule.period)
+        .ok_or(RNDRError::MathError)?;
+    FundingSchedule::pack(
+        funding_schedule,
+        &mut funding_schedule_info.try_borrow_mut_data()?,
+    )?;
+
+    // The escrow signs the transfer as delegate, so its data can't be borrowed during it
+    drop(escrow_data);
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_token_info.key,
+            escrow_associated_token_info.key,
+            escrow_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            source_token_info.clone(),
+            escrow_associated_token_info.clone(),
+            escrow_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[&escrow_seeds],
+    )?;
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    job.amount = u64::from(job.amount)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
+    escrow.amount = u64::from(escrow.amount)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_resume_funding_schedule(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let funding_schedule_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let clock_info = next_account_info(account_info_iter)?;
+
+    let mut funding_schedule = FundingSchedule::unpack(&funding_schedule_info.try_borrow_data()?)?;
+    if funding_schedule_info.owner != program_id {
+        msg!("Funding schedule provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if funding_schedule.authority != *authority_info.key {
+        msg!("Funding schedule authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !authority_info.is_signer {
+        msg!("Funding schedule authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !funding_schedule.paused {
+        msg!("Funding schedule is not paused");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let clock = &Clock::from_account_info(clock_info)?;
+    funding_schedule.paused = false;
+    funding_schedule.next_due = funding_schedule.next_due.max(clock.unix_timestamp);
+    FundingSchedule::pack(
+        funding_schedule,
+        &mut funding_schedule_info.try_borrow_mut_data()?,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_close_funding_schedule(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let funding_schedule_info = next_account_info(account_info_iter)?;
+    let rent_payer_info = next_account_info(account_info_iter)?;
+
+    let funding_schedule = FundingSchedule::unpack(&funding_schedule_info.try_borrow_data()?)?;
+    if funding_schedule_info.owner != program_id {
+        msg!("Funding schedule provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if funding_schedule.authority != *authority_info.key {
+        msg!("Funding schedule authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !authority_info.is_signer {
+        msg!("Funding schedule authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if funding_schedule.rent_payer != *rent_payer_info.key {
+        msg!("Funding schedule rent payer does not match the rent payer provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    close_program_account(funding_schedule_info, rent_payer_info)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_post_pending_payout(
+    program_id: &Pubkey,
+    nonce: u64,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to pay out can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_owner_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_info = next_account_info(account_info_iter)?;
+    let pending_payout_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let clock_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    // Optional render node
+    let node_info = account_info_iter.next();
+
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if escrow.owner != *escrow_owner_info.key {
+        msg!("Escrow owner does not match the escrow owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !escrow_owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_pubkey = Pubkey::create_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.authority.as_ref(),
+            &[job.bump_seed],
+        ],
+        program_id,
+    )?;
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if job.submissions_required != 0 {
+        msg!("Job only pays out through accepted render submissions");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.priced_per_frame != 0 {
+        msg!("Job with a rate card only pays out through claimed frames");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    match node_info {
+        Some(node_info) => {
+            check_node(program_id, node_info, None, destination_token_info)?;
+        }
+        None if escrow.node_registry_required != 0 => {
+            msg!("Escrow only disburses to registered render nodes");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        None => {}
+    }
+
+    let nonce_bytes = nonce.to_le_bytes();
+    let generation = job.generation;
+    let mut pending_payout_seeds: Vec<&[_]> = vec![
+        b"pending_payout",
+        job_info.key.as_ref(),
+        generation.as_ref(),
+        &nonce_bytes,
+    ];
+
+    let (pending_payout_address, bump_seed) =
+        Pubkey::find_program_address(&pending_payout_seeds, program_id);
+    if &pending_payout_address != pending_payout_info.key {
+        msg!("Pending payout program derived address does not match the address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let bump_seed = &[bump_seed];
+    pending_payout_seeds.push(bump_seed);
+
+    create_program_account(
+        program_id,
+        funder_info,
+        pending_payout_info,
+        &pending_payout_seeds,
+        PendingPayout::LEN,
+        &Rent::from_account_info(rent_info)?,
+        system_program_info,
+    )?;
+
+    let amount = job.apply_tier_multiplier(amount)?;
+    job.amount = u64::from(job.amount)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    job.reserved = u64::from(job.reserved)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    if job.exceeds_approved_amount()? {
+        msg!("Amount of tokens to pay out exceeds the approved milestones of the job");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let clock = &Clock::from_account_info(clock_info)?;
+    let pending_payout = PendingPayout::new(InitPendingPayoutParams {
+        escrow: *escrow_info.key,
+        job: *job_info.key,
+        destination: *destination_token_info.key,
+        amount,
+        finalize_after: clock
+            .unix_timestamp
+            .checked_add(PAYOUT_CHALLENGE_PERIOD)
+            .ok_or(RNDRError::MathError)?,
+        nonce,
+        bump_seed: bump_seed[0],
+        rent_payer: *funder_info.key,
+    });
+    PendingPayout::pack(
+        pending_payout,
+        &mut pending_payout_info.try_borrow_mut_data()?,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_challenge_payout(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let pending_payout_info = next_account_info(account_info_iter)?;
+    let rent_payer_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let clock_info = next_account_info(account_info_iter)?;
+
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let pending_payout = PendingPayout::unpack(&pending_payout_info.try_borrow_data()?)?;
+    if pending_payout_info.owner != program_id {
+        msg!("Pending payout provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &pending_payout.escrow != escrow_info.key {
+        msg!("Pending payout escrow does not match the escrow provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &pending_payout.job != job_info.key {
+        msg!("Pending payout job does not match the job provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &pending_payout.rent_payer != rent_payer_info.key {
+        msg!("Pending payout rent payer does not match the rent payer provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    if !authority_info.is_signer {
+        msg!("Authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if escrow.owner != *authority_info.key {
+        if job.authority != *authority_info.key {
+            msg!("Job authority or escrow owner does not match the authority provided");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        let clock = &Clock::from_account_info(clock_info)?;
+        if clock.unix_timestamp > pending_payout.finalize_after {
+            msg!("Challenge period of the pending payout is over");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    }
+
+    job.reserved = u64::from(job.reserved)
+        .checked_sub(pending_payout.amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    job.amount = u64::from(job.amount)
+        .checked_add(pending_payout.amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    close_program_account(pending_payout_info, rent_payer_info)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_finalize_payout(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let pending_payout_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_info = next_account_info(account_info_iter)?;
+    let rent_payer_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let clock_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+    // Optional render node
+    let node_info = account_info_iter.next();
+
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let namespace = Pubkey::from(escrow.namespace);
+    let bump_seed = &[escrow.bump_seed];
+    let mut escrow_seeds = escrow_seeds(token_mint_info.key, token_program_info.key, &namespace);
+    escrow_seeds.push(bump_seed);
+
+    let escrow_address = Pubkey::create_program_address(&escrow_seeds, program_id)?;
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let escrow_associated_token_address =
+        get_associated_token_address(&escrow_address, token_mint_info.key);
+    if &escrow_associated_token_address != escrow_associated_token_info.key {
+        msg!(
+            "Escrow associated token address does not match the associated token address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let pending_payout = PendingPayout::unpack(&pending_payout_info.try_borrow_data()?)?;
+    if pending_payout_info.owner != program_id {
+        msg!("Pending payout provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &pending_payout.escrow != escrow_info.key {
+        msg!("Pending payout escrow does not match the escrow provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &pending_payout.job != job_info.key {
+        msg!("Pending payout job does not match the job provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &pending_payout.destination != destination_token_info.key {
+        msg!("Pending payout destination does not match the destination provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &pending_payout.rent_payer != rent_payer_info.key {
+        msg!("Pending payout rent payer does not match the rent payer provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let clock = &Clock::from_account_info(clock_info)?;
+    if clock.unix_timestamp <= pending_payout.finalize_after {
+        msg!("Challenge period of the pending payout is not over yet");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let amount = pending_payout.amount;
+    job.reserved = u64::from(job.reserved)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    job.disbursed = u64::from(job.disbursed)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    credit_node(
+        program_id,
+        escrow,
+        node_info,
+        None,
+        destination_token_info,
+        amount,
+    )?;
+
+    // The escrow signs the transfer, so its data can't be borrowed during it
+    drop(escrow_data);
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            escrow_associated_token_info.key,
+            destination_token_info.key,
+            escrow_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            escrow_associated_token_info.clone(),
+            destination_token_info.clone(),
+            escrow_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[&escrow_seeds],
+    )?;
+
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
+    escrow.amount = u64::from(escrow.amount)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    close_program_account(pending_payout_info, rent_payer_info)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_submit_render(
+    program_id: &Pubkey,
+    frames_hash: [u8; 32],
+    frame_start: u32,
+    frame_end: u32,
+    price: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if frame_start > frame_end {
+        msg!("Frame range start can't be after the frame range end");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if price == 0 {
+        msg!("Price of the render submission can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let node_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let render_submission_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let rent_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !node_info.is_signer {
+        msg!("Render node provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_data = job_info.try_borrow_data()?;
+    let job = PodJob::load(&job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut render_submission_seeds: Vec<&[_]> = vec![
+        b"render_submission",
+        job_info.key.as_ref(),
+        job.generation.as_ref(),
+        node_info.key.as_ref(),
+    ];
+
+    let (render_submission_address, bump_seed) =
+        Pubkey::find_program_address(&render_submission_seeds, program_id);
+    if &render_submission_address != render_submission_info.key {
+        msg!("Render submission program derived address does not match the address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if render_submission_info.try_data_is_empty()? {
+        let bump_seed = &[bump_seed];
+        render_submission_seeds.push(bump_seed);
+
+        create_program_account(
+            program_id,
+            node_info,
+            render_submission_info,
+            &render_submission_seeds,
+            RenderSubmission::LEN,
+            &Rent::from_account_info(rent_info)?,
+            system_program_info,
+        )?;
+
+        let render_submission = RenderSubmission::new(InitRenderSubmissionParams {
+            job: *job_info.key,
+            node: *node_info.key,
+            frames_hash,
+            frame_start,
+            frame_end,
+            price,
+            bump_seed: bump_seed[0],
+            rent_payer: *node_info.key,
+        });
+        RenderSubmission::pack(
+            render_submission,
+            &mut render_submission_info.try_borrow_mut_data()?,
+        )?;
+    } else {
+        if render_submission_info.owner != program_id {
+            msg!("Render submission provided is not owned by the RNDR program");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        let mut render_submission =
+            RenderSubmission::unpack(&render_submission_info.try_borrow_data()?)?;
+        if render_submission.status == SubmissionStatus::Accepted {
+            msg!("Render submission has already been accepted and can't be replaced");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+
+        render_submission.submit(frames_hash, frame_start, frame_end, price);
+        RenderSubmission::pack(
+            render_submission,
+            &mut render_submission_info.try_borrow_mut_data()?,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_review_submission(
+    program_id: &Pubkey,
+    accept: bool,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let render_submission_info = next_account_info(account_info_iter)?;
+
+    let job_data = job_info.try_borrow_data()?;
+    let job = PodJob::load(&job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.authority != *authority_info.key {
+        msg!("Job authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !authority_info.is_signer {
+        msg!("Job authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut render_submission =
+        RenderSubmission::unpack(&render_submission_info.try_borrow_data()?)?;
+    if render_submission_info.owner != program_id {
+        msg!("Render submission provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &render_submission.job != job_info.key {
+        msg!("Render submission job does not match the job provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let render_submission_address = Pubkey::create_program_address(
+        &[
+            b"render_submission",
+            job_info.key.as_ref(),
+            job.generation.as_ref(),
+            render_submission.node.as_ref(),
+            &[render_submission.bump_seed],
+        ],
+        program_id,
+    )?;
+    if &render_submission_address != render_submission_info.key {
+        msg!("Render submission program derived address does not match the address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if render_submission.status != SubmissionStatus::Pending {
+        msg!("Render submission has already been reviewed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    render_submission.status = if accept {
+        SubmissionStatus::Accepted
+    } else {
+        SubmissionStatus::Rejected
+    };
+    RenderSubmission::pack(
+        render_submission,
+        &mut render_submission_info.try_borrow_mut_data()?,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_pay_submission(
+    program_id: &Pubkey,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to pay can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_owner_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let render_submission_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let node_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+    // Optional render node
+    let node_info = account_info_iter.next();
+
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let namespace = Pubkey::from(escrow.namespace);
+    let bump_seed = &[escrow.bump_seed];
+    let mut escrow_seeds = escrow_seeds(token_mint_info.key, token_program_info.key, &namespace);
+    escrow_seeds.push(bump_seed);
+
+    let escrow_address = Pubkey::create_program_address(&escrow_seeds, program_id)?;
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if escrow.owner != *escrow_owner_info.key {
+        msg!("Escrow owner does not match the escrow owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !escrow_owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let escrow_associated_token_address =
+        get_associated_token_address(&escrow_address, token_mint_info.key);
+    if &escrow_associated_token_address != escrow_associated_token_info.key {
+        msg!(
+            "Escrow associated token address does not match the associated token address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_pubkey = Pubkey::create_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.authority.as_ref(),
+            &[job.bump_seed],
+        ],
+        program_id,
+    )?;
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if job.priced_per_frame != 0 {
+        msg!("Job with a rate card only pays out through claimed frames");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut render_submission =
+        RenderSubmission::unpack(&render_submission_info.try_borrow_data()?)?;
+    if render_submission_info.owner != program_id {
+        msg!("Render submission provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &render_submission.job != job_info.key {
+        msg!("Render submission job does not match the job provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let render_submission_address = Pubkey::create_program_address(
+        &[
+            b"render_submission",
+            job_info.key.as_ref(),
+            job.generation.as_ref(),
+            render_submission.node.as_ref(),
+            &[render_submission.bump_seed],
+        ],
+        program_id,
+    )?;
+    if &render_submission_address != render_submission_info.key {
+        msg!("Render submission program derived address does not match the address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if render_submission.status != SubmissionStatus::Accepted {
+        msg!("Render submission has not been accepted");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let node_token = spl_token::state::Account::unpack(&node_token_info.try_borrow_data()?)?;
+    if node_token.owner != render_submission.node {
+        msg!("Render node token account is not owned by the render node of the submission");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    render_submission.paid = render_submission
+        .paid
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?;
+    if render_submission.paid > render_submission.price {
+        msg!("Amount of tokens to pay exceeds the price of the render submission");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    // The render node quotes its price before the tier multiplier of the job
+    let amount = job.apply_tier_multiplier(amount)?;
+
+    credit_node(
+        program_id,
+        escrow,
+        node_info,
+        Some(&render_submission.node),
+        node_token_info,
+        amount,
+    )?;
+
+    job.amount = u64::from(job.amount)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    job.disbursed = u64::from(job.disbursed)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    if job.exceeds_approved_amount()? {
+        msg!("Amount of tokens to pay exceeds the approved milestones of the job");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    RenderSubmission::pack(
+        render_submission,
+        &mut render_submission_info.try_borrow_mut_data()?,
+    )?;
+
+    // The escrow signs the transfer, so its data can't be borrowed during it
+    drop(escrow_data);
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            escrow_associated_token_info.key,
+            node_token_info.key,
+            escrow_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            escrow_associated_token_info.clone(),
+            node_token_info.clone(),
+            escrow_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[&escrow_seeds],
+    )?;
+
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
+    escrow.amount = u64::from(escrow.amount)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_submissions_required(
+    program_id: &Pubkey,
+    required: bool,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.authority != *authority_info.key {
+        msg!("Job authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !authority_info.is_signer {
+        msg!("Job authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if required && u64::from(job.reserved) > 0 {
+        msg!("Job submissions can't be required while payouts from the job are pending");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    job.submissions_required = required as u8;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_rate_card(
+    program_id: &Pubkey,
+    price_per_frame: u64,
+    total_frames: u32,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if price_per_frame == 0 {
+        msg!("Price per frame can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if total_frames == 0 {
+        msg!("Total frames can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let rate_card_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let rent_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.authority != *authority_info.key {
+        msg!("Job authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !authority_info.is_signer {
+        msg!("Job authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if u64::from(job.reserved) > 0 {
+        msg!("Job rate card can't be set while payouts from the job are pending");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut rate_card_seeds: Vec<&[_]> =
+        vec![b"rate_card", job_info.key.as_ref(), job.generation.as_ref()];
+
+    let (rate_card_address, bump_seed) = Pubkey::find_program_address(&rate_card_seeds, program_id);
+    if &rate_card_address != rate_card_info.key {
+        msg!("Rate card program derived address does not match the rate card address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if rate_card_info.try_data_is_empty()? {
+        let bump_seed = &[bump_seed];
+        rate_card_seeds.push(bump_seed);
+
+        create_program_account(
+            program_id,
+            funder_info,
+            rate_card_info,
+            &rate_card_seeds,
+            RateCard::LEN,
+            &Rent::from_account_info(rent_info)?,
+            system_program_info,
+        )?;
+
+        let rate_card = RateCard::new(InitRateCardParams {
+            job: *job_info.key,
+            price_per_frame,
+            total_frames,
+            bump_seed: bump_seed[0],
+            rent_payer: *funder_info.key,
+        });
+        RateCard::pack(rate_card, &mut rate_card_info.try_borrow_mut_data()?)?;
+    } else {
+        if rate_card_info.owner != program_id {
+            msg!("Rate card provided is not owned by the RNDR program");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        let mut rate_card = RateCard::unpack(&rate_card_info.try_borrow_data()?)?;
+        if rate_card.frames_paid > 0 {
+            msg!("Rate card can't be changed after frames have been paid");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+
+        rate_card.price_per_frame = price_per_frame;
+        rate_card.total_frames = total_frames;
+        RateCard::pack(rate_card, &mut rate_card_info.try_borrow_mut_data()?)?;
+    }
+
+    job.priced_per_frame = 1;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_claim_frames(
+    program_id: &Pubkey,
+    count: u32,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if count == 0 {
+        msg!("Number of frames to claim can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_owner_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let rate_card_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+    // Optional render node
+    let node_info = account_info_iter.next();
+
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let namespace = Pubkey::from(escrow.namespace);
+    let bump_seed = &[escrow.bump_seed];
+    let mut escrow_seeds = escrow_seeds(token_mint_info.key, token_program_info.key, &namespace);
+    escrow_seeds.push(bump_seed);
+
+    let escrow_address = Pubkey::create_program_address(&escrow_seeds, program_id)?;
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if escrow.owner != *escrow_owner_info.key {
+        msg!("Escrow owner does not match the escrow owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !escrow_owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let escrow_associated_token_address =
+        get_associated_token_address(&escrow_address, token_mint_info.key);
+    if &escrow_associated_token_address != escrow_associated_token_info.key {
+        msg!(
+            "Escrow associated token address does not match the associated token address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_pubkey = Pubkey::create_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.authority.as_ref(),
+            &[job.bump_seed],
+        ],
+        program_id,
+    )?;
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if job.submissions_required != 0 {
+        msg!("Job only pays out through accepted render submissions");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut rate_card = RateCard::unpack(&rate_card_info.try_borrow_data()?)?;
+    if rate_card_info.owner != program_id {
+        msg!("Rate card provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &rate_card.job != job_info.key {
+        msg!("Rate card job does not match the job provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let rate_card_address = Pubkey::create_program_address(
+        &[
+            b"rate_card",
+            job_info.key.as_ref(),
+            job.generation.as_ref(),
+            &[rate_card.bump_seed],
+        ],
+        program_id,
+    )?;
+    if &rate_card_address != rate_card_info.key {
+        msg!("Rate card program derived address does not match the rate card address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    rate_card.frames_paid = rate_card
+        .frames_paid
+        .checked_add(count)
+        .ok_or(RNDRError::MathError)?;
+    if rate_card.frames_paid > rate_card.total_frames {
+        msg!("Number of frames to claim exceeds the total frames of the rate card");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let amount = rate_card
+        .price_per_frame
+        .checked_mul(count.into())
+        .ok_or(RNDRError::MathError)?;
+    let amount = job.apply_tier_multiplier(amount)?;
+
+    job.amount = u64::from(job.amount)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    job.disbursed = u64::from(job.disbursed)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    if job.exceeds_approved_amount()? {
+        msg!("Amount of tokens to disburse exceeds the approved milestones of the job");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    RateCard::pack(rate_card, &mut rate_card_info.try_borrow_mut_data()?)?;
+
+    credit_node(
+        program_id,
+        escrow,
+        node_info,
+        None,
+        destination_token_info,
+        amount,
+    )?;
+
+    // The escrow signs the transfer, so its data can't be borrowed during it
+    drop(escrow_data);
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            escrow_associated_token_info.key,
+            destination_token_info.key,
+            escrow_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            escrow_associated_token_info.clone(),
+            destination_token_info.clone(),
+            escrow_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[&escrow_seeds],
+    )?;
+
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
+    escrow.amount = u64::from(escrow.amount)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_tier_multipliers(
+    program_id: &Pubkey,
+    multipliers: [u32; TIER_COUNT],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if multipliers
+        .iter()
+        .any(|multiplier| !(TIER_MULTIPLIER_BASE..=MAX_TIER_MULTIPLIER).contains(multiplier))
+    {
+        msg!("Tier price multipliers must be between 1x and 10x");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_owner_info = next_account_info(account_info_iter)?;
+
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if escrow.owner != *escrow_owner_info.key {
+        msg!("Escrow owner does not match the escrow owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !escrow_owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    for (tier_multiplier, multiplier) in escrow.tier_multipliers.iter_mut().zip(multipliers) {
+        *tier_multiplier = multiplier.into();
+    }
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_job_tier(program_id: &Pubkey, tier: u8, accounts: &[AccountInfo]) -> ProgramResult {
+    if tier as usize > TIER_COUNT {
+        msg!("Job tier is invalid");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Optional accounts
+    let escrow_owner_info = account_info_iter.next();
+
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_pubkey = Pubkey::create_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.authority.as_ref(),
+            &[job.bump_seed],
+        ],
+        program_id,
+    )?;
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if job.authority != *authority_info.key {
+        msg!("Job authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !authority_info.is_signer {
+        msg!("Job authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    // Reserved funds were priced with the current multiplier of the job, and are paid out with it
+    if u64::from(job.reserved) > 0 {
+        msg!("Job tier can't be changed while payouts from the job are pending");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if u64::from(job.disbursed) > 0 {
+        let escrow_owner_info = escrow_owner_info.ok_or_else(|| {
+            msg!("Escrow owner must consent to changing the tier of a job in progress");
+            ProgramError::NotEnoughAccountKeys
+        })?;
+        if escrow.owner != *escrow_owner_info.key {
+            msg!("Escrow owner does not match the escrow owner provided");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        if !escrow_owner_info.is_signer {
+            msg!("Escrow owner provided must be a signer");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    }
+
+    job.tier = tier;
+    job.tier_multiplier = escrow.tier_multiplier(tier)?.into();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_create_assignment(
+    program_id: &Pubkey,
+    amount: u64,
+    deadline: UnixTimestamp,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to reserve can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_owner_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let node_info = next_account_info(account_info_iter)?;
+    let assignment_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let clock_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    // Optional stake of the render node
+    let stake_info = account_info_iter.next();
+
+    let clock = &Clock::from_account_info(clock_info)?;
+    if deadline <= clock.unix_timestamp {
+        msg!("Assignment deadline must be in the future");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if escrow.owner != *escrow_owner_info.key {
+        msg!("Escrow owner does not match the escrow owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !escrow_owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let min_stake = u64::from(escrow.min_stake);
+    if min_stake > 0 {
+        let stake_info = stake_info.ok_or_else(|| {
+            msg!("Escrow requires render nodes to stake before they are assigned work");
+            RNDRError::UnspecifiedError
+        })?;
+        let stake = Stake::unpack(&stake_info.try_borrow_data()?)?;
+        if stake_info.owner != program_id {
+            msg!("Stake provided is not owned by the RNDR program");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        if &stake.escrow != escrow_info.key || &stake.operator != node_info.key {
+            msg!("Stake does not belong to the render node and escrow provided");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        if stake.amount < min_stake {
+            msg!("Render node stake is below the minimum stake of the escrow");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    }
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_pubkey = Pubkey::create_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.authority.as_ref(),
+            &[job.bump_seed],
+        ],
+        program_id,
+    )?;
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if job.submissions_required != 0 {
+        msg!("Job only pays out through accepted render submissions");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.priced_per_frame != 0 {
+        msg!("Job with a rate card only pays out through claimed frames");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let amount = job.apply_tier_multiplier(amount)?;
+    job.amount = u64::from(job.amount)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    job.reserved = u64::from(job.reserved)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    if job.exceeds_approved_amount()? {
+        msg!("Amount of tokens to reserve exceeds the approved milestones of the job");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let generation = job.generation;
+    let mut assignment_seeds: Vec<&[_]> = vec![
+        b"assignment",
+        job_info.key.as_ref(),
+        generation.as_ref(),
+        node_info.key.as_ref(),
+    ];
+
+    let (assignment_address, bump_seed) =
+        Pubkey::find_program_address(&assignment_seeds, program_id);
+    if &assignment_address != assignment_info.key {
+        msg!("Assignment program derived address does not match the assignment address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let bump_seed = &[bump_seed];
+    assignment_seeds.push(bump_seed);
+
+    create_program_account(
+        program_id,
+        funder_info,
+        assignment_info,
+        &assignment_seeds,
+        Assignment::LEN,
+        &Rent::from_account_info(rent_info)?,
+        system_program_info,
+    )?;
+
+    let assignment = Assignment::new(InitAssignmentParams {
+        escrow: *escrow_info.key,
+        job: *job_info.key,
+        node: *node_info.key,
+        amount,
+        deadline,
+        bump_seed: bump_seed[0],
+        rent_payer: *funder_info.key,
+    });
+    Assignment::pack(assignment, &mut assignment_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_pay_assignment(
+    program_id: &Pubkey,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to pay can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_owner_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let assignment_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let node_token_info = next_account_info(account_info_iter)?;
+    let rent_payer_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+    // Optional render node
+    let node_info = account_info_iter.next();
+
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let namespace = Pubkey::from(escrow.namespace);
+    let bump_seed = &[escrow.bump_seed];
+    let mut escrow_seeds = escrow_seeds(token_mint_info.key, token_program_info.key, &namespace);
+    escrow_seeds.push(bump_seed);
+
+    let escrow_address = Pubkey::create_program_address(&escrow_seeds, program_id)?;
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if escrow.owner != *escrow_owner_info.key {
+        msg!("Escrow owner does not match the escrow owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !escrow_owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let escrow_associated_token_address =
+        get_associated_token_address(&escrow_address, token_mint_info.key);
+    if &escrow_associated_token_address != escrow_associated_token_info.key {
+        msg!(
+            "Escrow associated token address does not match the associated token address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut assignment = Assignment::unpack(&assignment_info.try_borrow_data()?)?;
+    if assignment_info.owner != program_id {
+        msg!("Assignment provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &assignment.escrow != escrow_info.key {
+        msg!("Assignment escrow does not match the escrow provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &assignment.job != job_info.key {
+        msg!("Assignment job does not match the job provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &assignment.rent_payer != rent_payer_info.key {
+        msg!("Assignment rent payer does not match the rent payer provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let node_token = spl_token::state::Account::unpack(&node_token_info.try_borrow_data()?)?;
+    if node_token.owner != assignment.node {
+        msg!("Render node token account is not owned by the render node of the assignment");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    credit_node(
+        program_id,
+        escrow,
+        node_info,
+        Some(&assignment.node),
+        node_token_info,
+        amount,
+    )?;
+
+    assignment.amount = assignment.amount.checked_sub(amount).ok_or_else(|| {
+        msg!("Amount of tokens to pay exceeds the amount reserved by the assignment");
+        RNDRError::UnspecifiedError
+    })?;
+    job.reserved = u64::from(job.reserved)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    job.disbursed = u64::from(job.disbursed)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    // The escrow signs the transfer, so its data can't be borrowed during it
+    drop(escrow_data);
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            escrow_associated_token_info.key,
+            node_token_info.key,
+            escrow_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            escrow_associated_token_info.clone(),
+            node_token_info.clone(),
+            escrow_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[&escrow_seeds],
+    )?;
+
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
+    escrow.amount = u64::from(escrow.amount)
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    if assignment.amount == 0 {
+        close_program_account(assignment_info, rent_payer_info)?;
+    } else {
+        Assignment::pack(assignment, &mut assignment_info.try_borrow_mut_data()?)?;
+    }
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_release_assignment(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let job_info = next_account_info(account_info_iter)?;
+    let assignment_info = next_account_info(account_info_iter)?;
+    let rent_payer_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let clock_info = next_account_info(account_info_iter)?;
+
+    let mut job_data = job_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let assignment = Assignment::unpack(&assignment_info.try_borrow_data()?)?;
+    if assignment_info.owner != program_id {
+        msg!("Assignment provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &assignment.job != job_info.key {
+        msg!("Assignment job does not match the job provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &assignment.rent_payer != rent_payer_info.key {
+        msg!("Assignment rent payer does not match the rent payer provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let clock = &Clock::from_account_info(clock_info)?;
+    if clock.unix_timestamp <= assignment.deadline {
+        msg!("Assignment deadline has not passed yet");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    job.reserved = u64::from(job.reserved)
+        .checked_sub(assignment.amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    job.amount = u64::from(job.amount)
+        .checked_add(assignment.amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    close_program_account(assignment_info, rent_payer_info)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_register_node(program_id: &Pubkey, tier: u8, accounts: &[AccountInfo]) -> ProgramResult {
+    if tier as usize > TIER_COUNT {
+        msg!("Node tier is invalid");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let operator_info = next_account_info(account_info_iter)?;
+    let payout_token_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let node_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let rent_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !operator_info.is_signer {
+        msg!("Node operator provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    spl_token::state::Account::unpack(&payout_token_info.try_borrow_data()?)?;
+
+    let mut node_seeds: Vec<&[_]> = vec![b"node", operator_info.key.as_ref()];
+
+    let (node_address, bump_seed) = Pubkey::find_program_address(&node_seeds, program_id);
+    if &node_address != node_info.key {
+        msg!("Node program derived address does not match the node address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !node_info.try_data_is_empty()? {
+        msg!("Node is already registered");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = &[bump_seed];
+    node_seeds.push(bump_seed);
+
+    create_program_account(
+        program_id,
+        operator_info,
+        node_info,
+        &node_seeds,
+        Node::LEN,
+        &Rent::from_account_info(rent_info)?,
+        system_program_info,
+    )?;
+
+    let node = Node::new(InitNodeParams {
+        operator: *operator_info.key,
+        payout_token: *payout_token_info.key,
+        tier,
+        bump_seed: bump_seed[0],
+        rent_payer: *operator_info.key,
+    });
+    Node::pack(node, &mut node_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_update_node(program_id: &Pubkey, tier: u8, accounts: &[AccountInfo]) -> ProgramResult {
+    if tier as usize > TIER_COUNT {
+        msg!("Node tier is invalid");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let operator_info = next_account_info(account_info_iter)?;
+    let payout_token_info = next_account_info(account_info_iter)?;
+    let node_info = next_account_info(account_info_iter)?;
+
+    let mut node = Node::unpack(&node_info.try_borrow_data()?)?;
+    if node_info.owner != program_id {
+        msg!("Node provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &node.operator != operator_info.key {
+        msg!("Node operator does not match the operator provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !operator_info.is_signer {
+        msg!("Node operator provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    spl_token::state::Account::unpack(&payout_token_info.try_borrow_data()?)?;
+
+    node.payout_token = *payout_token_info.key;
+    node.tier = tier;
+    Node::pack(node, &mut node_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_suspend_node(
+    program_id: &Pubkey,
+    suspended: bool,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let program_config_info = next_account_info(account_info_iter)?;
+    let admin_info = next_account_info(account_info_iter)?;
+    let node_info = next_account_info(account_info_iter)?;
+
+    let program_config = get_program_config(program_id, program_config_info)?.ok_or_else(|| {
+        msg!("Program config is not initialized");
+        RNDRError::UnspecifiedError
+    })?;
+    if program_config.admin != *admin_info.key {
+        msg!("Program config admin does not match the admin provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !admin_info.is_signer {
+        msg!("Program config admin provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut node = Node::unpack(&node_info.try_borrow_data()?)?;
+    if node_info.owner != program_id {
+        msg!("Node provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    node.status = if suspended {
+        NodeStatus::Suspended
+    } else {
+        NodeStatus::Active
+    };
+    Node::pack(node, &mut node_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_node_registry_required(
+    program_id: &Pubkey,
+    required: bool,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_owner_info = next_account_info(account_info_iter)?;
+
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if escrow.owner != *escrow_owner_info.key {
+        msg!("Escrow owner does not match the escrow owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !escrow_owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    escrow.node_registry_required = required.into();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_min_stake(
+    program_id: &Pubkey,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_owner_info = next_account_info(account_info_iter)?;
+
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if escrow.owner != *escrow_owner_info.key {
+        msg!("Escrow owner does not match the escrow owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !escrow_owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    escrow.min_stake = amount.into();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_stake(program_id: &Pubkey, amount: u64, accounts: &[AccountInfo]) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to stake can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let operator_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let stake_vault_info = next_account_info(account_info_iter)?;
+    let stake_vault_associated_token_info = next_account_info(account_info_iter)?;
+    let stake_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let rent_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let associated_token_program_info = next_account_info(account_info_iter)?;
+
+    let escrow_data = escrow_info.try_borrow_data()?;
+    let escrow = PodEscrow::load(&escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let namespace = Pubkey::from(escrow.namespace);
+    let bump_seed = &[escrow.bump_seed];
+    let mut escrow_seeds = escrow_seeds(token_mint_info.key, token_program_info.key, &namespace);
+    escrow_seeds.push(bump_seed);
+
+    let escrow_address = Pubkey::create_program_address(&escrow_seeds, program_id)?;
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !operator_info.is_signer {
+        msg!("Node operator provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (stake_vault_address, _bump_seed) =
+        Pubkey::find_program_address(&[b"stake_vault", escrow_info.key.as_ref()], program_id);
+    if &stake_vault_address != stake_vault_info.key {
+        msg!("Stake vault program derived address does not match the address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let stake_vault_associated_token_address =
+        get_associated_token_address(&stake_vault_address, token_mint_info.key);
+    if &stake_vault_associated_token_address != stake_vault_associated_token_info.key {
+        msg!("Stake vault associated token address does not match the address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if stake_vault_associated_token_info.try_data_is_empty()? {
+        invoke(
+            &Instruction {
+                program_id: *associated_token_program_info.key,
+                accounts: vec![
+                    AccountMeta::new(*operator_info.key, true),
+                    AccountMeta::new(*stake_vault_associated_token_info.key, false),
+                    AccountMeta::new_readonly(stake_vault_address, false),
+                    AccountMeta::new_readonly(*token_mint_info.key, false),
+                    AccountMeta::new_readonly(*system_program_info.key, false),
+                    AccountMeta::new_readonly(*token_program_info.key, false),
+                    AccountMeta::new_readonly(*rent_info.key, false),
+                ],
+                data: vec![],
+            },
+            &[
+                operator_info.clone(),
+                stake_vault_associated_token_info.clone(),
+                stake_vault_info.clone(),
+                token_mint_info.clone(),
+                system_program_info.clone(),
+                token_program_info.clone(),
+                rent_info.clone(),
+            ],
+        )?;
+    }
+
+    let mut stake_seeds: Vec<&[_]> = vec![
+        b"stake",
+        escrow_info.key.as_ref(),
+        operator_info.key.as_ref(),
+    ];
+
+    let (stake_address, bump_seed) = Pubkey::find_program_address(&stake_seeds, program_id);
+    if &stake_address != stake_info.key {
+        msg!("Stake program derived address does not match the stake address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut stake = if stake_info.try_data_is_empty()? {
+        let bump_seed = &[bump_seed];
+        stake_seeds.push(bump_seed);
+
+        create_program_account(
+            program_id,
+            operator_info,
+            stake_info,
+            &stake_seeds,
+            Stake::LEN,
+            &Rent::from_account_info(rent_info)?,
+            system_program_info,
+        )?;
+
+        Stake::new(InitStakeParams {
+            escrow: *escrow_info.key,
+            operator: *operator_info.key,
+            bump_seed: bump_seed[0],
+            rent_payer: *operator_info.key,
+        })
+    } else {
+        if stake_info.owner != program_id {
+            msg!("Stake provided is not owned by the RNDR program");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        Stake::unpack(&stake_info.try_borrow_data()?)?
+    };
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_token_info.key,
+            stake_vault_associated_token_info.key,
+            authority_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            source_token_info.clone(),
+            stake_vault_associated_token_info.clone(),
+            authority_info.clone(),
+            token_program_info.clone(),
+        ],
+    )?;
+
+    stake.amount = stake
+        .amount
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?;
+    Stake::pack(stake, &mut stake_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_request_unstake(
+    program_id: &Pubkey,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to unstake can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let operator_info = next_account_info(account_info_iter)?;
+    let stake_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let clock_info = next_account_info(account_info_iter)?;
+
+    let mut stake = Stake::unpack(&stake_info.try_borrow_data()?)?;
+    if stake_info.owner != program_id {
+        msg!("Stake provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &stake.operator != operator_info.key {
+        msg!("Stake operator does not match the operator provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !operator_info.is_signer {
+        msg!("Node operator provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    stake.amount = stake.amount.checked_sub(amount).ok_or_else(|| {
+        msg!("Amount of tokens to unstake exceeds the stake");
+        RNDRError::UnspecifiedError
+    })?;
+    stake.unstaking_amount = stake
+        .unstaking_amount
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?;
+
+    // Every request restarts the cooldown of all the unstaking tokens
+    let clock = &Clock::from_account_info(clock_info)?;
+    stake.unstake_available_at = clock
+        .unix_timestamp
+        .checked_add(UNSTAKE_COOLDOWN)
+        .ok_or(RNDRError::MathError)?;
+    Stake::pack(stake, &mut stake_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_unstake(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let operator_info = next_account_info(account_info_iter)?;
+    let stake_info = next_account_info(account_info_iter)?;
+    let stake_vault_info = next_account_info(account_info_iter)?;
+    let stake_vault_associated_token_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let clock_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    let mut stake = Stake::unpack(&stake_info.try_borrow_data()?)?;
+    if stake_info.owner != program_id {
+        msg!("Stake provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &stake.escrow != escrow_info.key {
+        msg!("Stake escrow does not match the escrow provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &stake.operator != operator_info.key {
+        msg!("Stake operator does not match the operator provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !operator_info.is_signer {
+        msg!("Node operator provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if stake.unstaking_amount == 0 {
+        msg!("Stake has no tokens to unstake");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let clock = &Clock::from_account_info(clock_info)?;
+    if clock.unix_timestamp < stake.unstake_available_at {
+        msg!("Unstake cooldown has not ended yet");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let amount = stake.unstaking_amount;
+    stake.unstaking_amount = 0;
+    Stake::pack(stake, &mut stake_info.try_borrow_mut_data()?)?;
+
+    transfer_from_stake_vault(
+        program_id,
+        escrow_info,
+        token_mint_info,
+        stake_vault_info,
+        stake_vault_associated_token_info,
+        destination_token_info,
+        token_program_info,
+        amount,
+    )
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_slash_stake(
+    program_id: &Pubkey,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to slash can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let program_config_info = next_account_info(account_info_iter)?;
+    let stake_info = next_account_info(account_info_iter)?;
+    let stake_vault_info = next_account_info(account_info_iter)?;
+    let stake_vault_associated_token_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    let mut escrow_data = escrow_info.try_borrow_mut_data()?;
+    let escrow = PodEscrow::load_mut(&mut escrow_data)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let namespace = Pubkey::from(escrow.namespace);
+    let bump_seed = &[escrow.bump_seed];
+    let mut escrow_seeds = escrow_seeds(token_mint_info.key, token_program_info.key, &namespace);
+    escrow_seeds.push(bump_seed);
+
+    let escrow_address = Pubkey::create_program_address(&escrow_seeds, program_id)?;
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // The program config admin arbitrates disputes between escrow owners and render nodes
+    if escrow.owner != *authority_info.key {
+        let program_config = get_program_config(program_id, program_config_info)?;
+        if program_config.map(|program_config| program_config.admin) != Some(*authority_info.key) {
+            msg!("Authority provided is neither the escrow owner nor the program config admin");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    }
+    if !authority_info.is_signer {
+        msg!("Authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut stake = Stake::unpack(&stake_info.try_borrow_data()?)?;
+    if stake_info.owner != program_id {
+        msg!("Stake provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &stake.escrow != escrow_info.key {
+        msg!("Stake escrow does not match the escrow provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    // Unstaking tokens are slashed last, the cooldown doesn't shield them
+    let staked = amount.min(stake.amount);
+    stake.amount -= staked;
+    stake.unstaking_amount = stake
+        .unstaking_amount
+        .checked_sub(amount - staked)
+        .ok_or_else(|| {
+            msg!("Amount of tokens to slash exceeds the stake");
+            RNDRError::UnspecifiedError
+        })?;
+    Stake::pack(stake, &mut stake_info.try_borrow_mut_data()?)?;
+
+    if destination_info.owner != program_id {
+        // Slashed tokens go to a token account of the treasury set in the program config
+        let treasury = get_program_config(program_id, program_config_info)?
+            .map(|program_config| program_config.treasury)
+            .unwrap_or_default();
+        if treasury == Pubkey::default() {
+            msg!("Program config doesn't set a treasury to send slashed tokens to");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        let destination_token =
+            spl_token::state::Account::unpack(&destination_info.try_borrow_data()?)?;
+        if destination_token.owner != treasury {
+            msg!("Destination provided is neither a job nor a token account of the treasury");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+
+        return transfer_from_stake_vault(
+            program_id,
+            escrow_info,
+            token_mint_info,
+            stake_vault_info,
+            stake_vault_associated_token_info,
+            destination_info,
+            token_program_info,
+            amount,
+        );
+    }
+
+    // Slashed tokens are credited to the affected job
+    if escrow.successor != Pubkey::default() {
+        msg!("Escrow is migrating to a new token mint and can't be funded");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let escrow_associated_token_address =
+        get_associated_token_address(&escrow_address, token_mint_info.key);
+    if &escrow_associated_token_address != escrow_associated_token_info.key {
+        msg!(
+            "Escrow associated token address does not match the associated token address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut job_data = destination_info.try_borrow_mut_data()?;
+    let job = PodJob::load_mut(&mut job_data)?;
+
+    let job_pubkey = Pubkey::create_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.authority.as_ref(),
+            &[job.bump_seed],
+        ],
+        program_id,
+    )?;
+    if &job_pubkey != destination_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    job.amount = u64::from(job.amount)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+    escrow.amount = u64::from(escrow.amount)
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?
+        .into();
+
+    transfer_from_stake_vault(
+        program_id,
+        escrow_info,
+        token_mint_info,
+        stake_vault_info,
+        stake_vault_associated_token_info,
+        escrow_associated_token_info,
+        token_program_info,
+        amount,
+    )
+}
+
+/// Transfers tokens out of the stake vault of an escrow, signed by the stake vault
+#[allow(clippy::too_many_arguments)]
+fn transfer_from_stake_vault<'a>(
+    program_id: &Pubkey,
+    escrow_info: &AccountInfo<'a>,
+    token_mint_info: &AccountInfo<'a>,
+    stake_vault_info: &AccountInfo<'a>,
+    stake_vault_associated_token_info: &AccountInfo<'a>,
+    destination_token_info: &AccountInfo<'a>,
+    token_program_info: &AccountInfo<'a>,
+    amount: u64,
+) -> ProgramResult {
+    let (stake_vault_address, bump_seed) =
+        Pubkey::find_program_address(&[b"stake_vault", escrow_info.key.as_ref()], program_id);
+    if &stake_vault_address != stake_vault_info.key {
+        msg!("Stake vault program derived address does not match the address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let stake_vault_associated_token_address =
+        get_associated_token_address(&stake_vault_address, token_mint_info.key);
+    if &stake_vault_associated_token_address != stake_vault_associated_token_info.key {
+        msg!("Stake vault associated token address does not match the address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            stake_vault_associated_token_info.key,
+            destination_token_info.key,
+            stake_vault_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            stake_vault_associated_token_info.clone(),
+            destination_token_info.clone(),
+            stake_vault_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[&[b"stake_vault", escrow_info.key.as_ref(), &[bump_seed]]],
+    )
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_close_idempotency_marker(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let idempotency_marker_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let rent_payer_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let clock_info = next_account_info(account_info_iter)?;
+
+    let idempotency_marker =
+        IdempotencyMarker::unpack(&idempotency_marker_info.try_borrow_data()?)?;
+    if idempotency_marker_info.owner != program_id {
+        msg!("Idempotency marker provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if idempotency_marker.rent_payer != *rent_payer_info.key {
+        msg!("Idempotency marker rent payer does not match the rent payer provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !rent_payer_info.is_signer {
+        msg!("Idempotency marker rent payer provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let clock = &Clock::from_account_info(clock_info)?;
+    let closable_at = idempotency_marker
+        .created_at
+        .checked_add(IDEMPOTENCY_MARKER_LIFETIME)
+        .ok_or(RNDRError::MathError)?;
+    if clock.unix_timestamp < closable_at {
+        msg!("Idempotency marker can't be closed before its lifetime has passed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    close_program_account(idempotency_marker_info, rent_payer_info)?;
+
+    Ok(())
+}
+
+/// Creates the idempotency marker of a disbursal from a job, failing if the key was already used
+#[allow(clippy::too_many_arguments)]
+fn create_idempotency_marker<'a>(
+    program_id: &Pubkey,
+    funder_info: &AccountInfo<'a>,
+    idempotency_marker_info: &AccountInfo<'a>,
+    job: &Pubkey,
+    generation: u64,
+    idempotency_key: Vec<u8>,
+    clock_info: &AccountInfo<'a>,
+    rent_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    let generation_bytes = generation.to_le_bytes();
+    let mut idempotency_marker_seeds: Vec<&[_]> = vec![
+        b"idempotency",
+        job.as_ref(),
+        &generation_bytes,
+        &idempotency_key,
+    ];
+
+    let (idempotency_marker_address, bump_seed) =
+        Pubkey::find_program_address(&idempotency_marker_seeds, program_id);
+    if &idempotency_marker_address != idempotency_marker_info.key {
+        msg!("Idempotency marker program derived address does not match the address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !idempotency_marker_info.try_data_is_empty()? {
+        msg!("Disbursal with the idempotency key was already made from the job");
+        return Err(RNDRError::DuplicateDisbursal.into());
+    }
+
+    let bump_seed = &[bump_seed];
+    idempotency_marker_seeds.push(bump_seed);
+
+    create_program_account(
+        program_id,
+        funder_info,
+        idempotency_marker_info,
+        &idempotency_marker_seeds,
+        IdempotencyMarker::LEN,
+        &Rent::from_account_info(rent_info)?,
+        system_program_info,
+    )?;
+
+    let clock = &Clock::from_account_info(clock_info)?;
+    let idempotency_marker = IdempotencyMarker::new(InitIdempotencyMarkerParams {
+        job: *job,
+        key: idempotency_key,
+        created_at: clock.unix_timestamp,
+        bump_seed: bump_seed[0],
+        rent_payer: *funder_info.key,
+    });
+    IdempotencyMarker::pack(
+        idempotency_marker,
+        &mut idempotency_marker_info.try_borrow_mut_data()?,
+    )
+}
+
+/// Unpacks a payout root, checking its owner and that the account holds the claimed bitmap of all
+/// its leaves before the data is read
+fn unpack_payout_root(
+    program_id: &Pubkey,
+    payout_root_info: &AccountInfo,
+) -> Result<PayoutRoot, ProgramError> {
+    if payout_root_info.owner != program_id {
+        msg!("Payout root provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let payout_root_data = payout_root_info.try_borrow_data()?;
+    if payout_root_data.len() < PayoutRoot::LEN {
+        msg!("Payout root account data is too short");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let payout_root = PayoutRoot::unpack(&payout_root_data[..PayoutRoot::LEN])?;
+    if payout_root_data.len() < PayoutRoot::account_len(payout_root.leaf_count) {
+        msg!("Payout root account data is too short for its leaves");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(payout_root)
+}
+
+/// Records a payout in the earnings ledger of the owner of the destination token account,
+/// creating the ledger if it doesn't exist yet
+#[allow(clippy::too_many_arguments)]
+fn record_node_earnings<'a>(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    funder_info: &AccountInfo<'a>,
+    destination_token_info: &AccountInfo<'a>,
+    node_earnings_info: &AccountInfo<'a>,
+    clock_info: &AccountInfo<'a>,
+    rent_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    amount: u64,
+) -> ProgramResult {
+    let destination_token =
+        spl_token::state::Account::unpack(&destination_token_info.try_borrow_data()?)?;
+
+    let mut node_earnings_seeds: Vec<&[_]> = vec![
+        b"node_earnings",
+        escrow.as_ref(),
+        destination_token.owner.as_ref(),
+    ];
+
+    let (node_earnings_address, bump_seed) =
+        Pubkey::find_program_address(&node_earnings_seeds, program_id);
+    if &node_earnings_address != node_earnings_info.key {
+        msg!("Node earnings program derived address does not match the address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut node_earnings = if node_earnings_info.try_data_is_empty()? {
+        let bump_seed = &[bump_seed];
+        node_earnings_seeds.push(bump_seed);
+
+        create_program_account(
+            program_id,
+            funder_info,
+            node_earnings_info,
+            &node_earnings_seeds,
+            NodeEarnings::LEN,
+            &Rent::from_account_info(rent_info)?,
+            system_program_info,
+        )?;
+
+        NodeEarnings::new(InitNodeEarningsParams {
+            escrow: *escrow,
+            owner: destination_token.owner,
+            bump_seed: bump_seed[0],
+            rent_payer: *funder_info.key,
+        })
+    } else {
+        if node_earnings_info.owner != program_id {
+            msg!("Node earnings provided is not owned by the RNDR program");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        NodeEarnings::unpack(&node_earnings_info.try_borrow_data()?)?
+    };
+
+    let clock = &Clock::from_account_info(clock_info)?;
+    node_earnings.record_payout(amount, clock.slot)?;
+    NodeEarnings::pack(
+        node_earnings,
+        &mut node_earnings_info.try_borrow_mut_data()?,
+    )
+}
+
+/// Writes the receipt of a payout from a job. Receipts are never closed, so they are seeded by the
+/// job generation to leave the receipts of a closed job apart from those of a job created again.
+#[allow(clippy::too_many_arguments)]
+fn write_receipt<'a>(
+    program_id: &Pubkey,
+    funder_info: &AccountInfo<'a>,
+    receipt_info: &AccountInfo<'a>,
+    job: &Pubkey,
+    generation: u64,
+    payout_index: u64,
+    disburser: &Pubkey,
+    destination: &Pubkey,
+    amount: u64,
+    reference: [u8; 32],
+    clock_info: &AccountInfo<'a>,
+    rent_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    let generation_bytes = generation.to_le_bytes();
+    let payout_index_bytes = payout_index.to_le_bytes();
+    let mut receipt_seeds: Vec<&[_]> = vec![
+        b"receipt",
+        job.as_ref(),
+        &generation_bytes,
+        &payout_index_bytes,
+    ];
+
+    let (receipt_address, bump_seed) = Pubkey::find_program_address(&receipt_seeds, program_id);
+    if &receipt_address != receipt_info.key {
+        msg!("Receipt program derived address does not match the receipt address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !receipt_info.try_data_is_empty()? {
+        msg!("Receipt of the payout already exists");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = &[bump_seed];
+    receipt_seeds.push(bump_seed);
+
+    create_program_account(
+        program_id,
+        funder_info,
+        receipt_info,
+        &receipt_seeds,
+        Receipt::LEN,
+        &Rent::from_account_info(rent_info)?,
+        system_program_info,
+    )?;
+
+    let clock = &Clock::from_account_info(clock_info)?;
+    let receipt = Receipt::new(InitReceiptParams {
+        job: *job,
+        payout_index,
+        destination: *destination,
+        amount,
+        slot: clock.slot,
+        disburser: *disburser,
+        reference,
+        bump_seed: bump_seed[0],
+        rent_payer: *funder_info.key,
+    });
+    Receipt::pack(receipt, &mut receipt_info.try_borrow_mut_data()?)
+}
+
+/// Checks the render node paid by a disbursal if one is provided, and credits it with the amount
+/// paid. Escrows that require registered nodes only pay the payout token account of an active node.
+fn credit_node(
+    program_id: &Pubkey,
+    escrow: &PodEscrow,
+    node_info: Option<&AccountInfo>,
+    operator: Option<&Pubkey>,
+    destination_token_info: &AccountInfo,
+    amount: u64,
+) -> ProgramResult {
+    let node_info = match node_info {
+        Some(node_info) => node_info,
+        None if escrow.node_registry_required != 0 => {
+            msg!("Escrow only disburses to registered render nodes");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        None => return Ok(()),
+    };
+
+    let mut node = check_node(program_id, node_info, operator, destination_token_info)?;
+    node.payout_count = node
+        .payout_count
+        .checked_add(1)
+        .ok_or(RNDRError::MathError)?;
+    node.earned = node
+        .earned
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?;
+    Node::pack(node, &mut node_info.try_borrow_mut_data()?)
+}
+
+/// Checks that a render node is active and paid to the destination provided
+fn check_node(
+    program_id: &Pubkey,
+    node_info: &AccountInfo,
+    operator: Option<&Pubkey>,
+    destination_token_info: &AccountInfo,
+) -> Result<Node, ProgramError> {
+    let node = Node::unpack(&node_info.try_borrow_data()?)?;
+    if node_info.owner != program_id {
+        msg!("Node provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if matches!(operator, Some(operator) if operator != &node.operator) {
+        msg!("Node operator does not match the render node of the payout");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if node.status != NodeStatus::Active {
+        msg!("Node is suspended");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &node.payout_token != destination_token_info.key {
+        msg!("Node payout token account does not match the destination provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    Ok(node)
+}
+
+/// Creates the job program derived address of an authority if it doesn't exist yet, or checks
+/// the job provided otherwise. Returns whether the job was created.
+#[allow(clippy::too_many_arguments)]
+fn init_or_check_job<'a>(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    authority: &Pubkey,
+    generation: u64,
+    tier: u8,
+    tier_multiplier: u32,
+    job_info: &AccountInfo<'a>,
+    funder_info: &AccountInfo<'a>,
+    rent_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+) -> Result<bool, ProgramError> {
+    let mut job_seeds: Vec<&[_]> = vec![b"job", escrow.as_ref(), authority.as_ref()];
+
+    if job_info.try_data_is_empty()? {
+        let (job_pubkey, bump_seed) = Pubkey::find_program_address(&job_seeds, program_id);
+        if &job_pubkey != job_info.key {
+            msg!("Job program derived address does not match the job address provided");
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let bump_seed = &[bump_seed];
+        job_seeds.push(bump_seed);
+
+        create_program_account(
+            program_id,
+            funder_info,
+            job_info,
+            &job_seeds,
+            Job::LEN,
+            &Rent::from_account_info(rent_info)?,
+            system_program_info,
+        )?;
+
+        let job = Job::new(InitJobParams {
+            authority: *authority,
+            generation,
+            tier,
+            tier_multiplier,
+            bump_seed: bump_seed[0],
+            rent_payer: *funder_info.key,
+        });
+        Job::pack(job, &mut job_info.try_borrow_mut_data()?)?;
+        Ok(true)
+    } else if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        Err(RNDRError::UnspecifiedError.into())
+    } else {
+        let bump_seed = &[PodJob::load(&job_info.try_borrow_data()?)?.bump_seed];
+        job_seeds.push(bump_seed);
+
+        let job_pubkey = Pubkey::create_program_address(&job_seeds, program_id)?;
+        if &job_pubkey != job_info.key {
+            msg!("Job program derived address does not match the job address provided");
+            return Err(ProgramError::InvalidSeeds);
+        }
+        Ok(false)
+    }
+}
+
+fn check_allowed_by_program_config(
+    program_config: &ProgramConfig,
+    token_mint_info: &AccountInfo,
+    token_program_info: &AccountInfo,
+) -> ProgramResult {
+    if !program_config.is_mint_allowed(token_mint_info.key) {
+        msg!("Token mint provided is not allowed by the program config");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !program_config.is_token_program_allowed(token_program_info.key) {
+        msg!("Token program provided is not allowed by the program config");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    Ok(())
+}
+
+/// Loads the program config, or none if it hasn't been initialized
+fn get_program_config(
+    program_id: &Pubkey,
+    program_config_info: &AccountInfo,
+) -> Result<Option<ProgramConfig>, ProgramError> {
+    let (program_config_address, _bump_seed) =
+        Pubkey::find_program_address(&[b"config"], program_id);
+    if &program_config_address != program_config_info.key {
+        msg!("Program config program derived address does not match the address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if program_config_info.owner != program_id {
+        return Ok(None);
+    }
+
+    ProgramConfig::unpack(&program_config_info.try_borrow_data()?).map(Some)
+}
+
+/// Checks that the upgrade authority recorded in the program's ProgramData account signed
+fn check_upgrade_authority(
+    program_id: &Pubkey,
+    program_data_info: &AccountInfo,
+    upgrade_authority_info: &AccountInfo,
+) -> ProgramResult {
+    let (program_data_address, _bump_seed) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+    if &program_data_address != program_data_info.key {
+        msg!("Program data address does not match the program data address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if program_data_info.owner != &bpf_loader_upgradeable::id() {
+        msg!("Program data provided is not owned by the upgradeable loader");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let upgrade_authority_address =
+        match bincode::deserialize(&program_data_info.try_borrow_data()?) {
+            Ok(UpgradeableLoaderState::ProgramData {
+                upgrade_authority_address,
+                ..
+            }) => upgrade_authority_address,
+            _ => {
+                msg!("Program data provided is invalid");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        };
+    if upgrade_authority_address != Some(*upgrade_authority_info.key) {
+        msg!("Program upgrade authority does not match the upgrade authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !upgrade_authority_info.is_signer {
+        msg!("Program upgrade authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(())
+}
+
+/// Creates a program derived account owned by the program, with rent paid by the funder
+fn create_program_account<'a>(
+    program_id: &Pubkey,
+    funder_info: &AccountInfo<'a>,
+    account_info: &AccountInfo<'a>,
+    account_seeds: &[&[u8]],
+    space: usize,
+    rent: &Rent,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    let required_lamports = rent
+        .minimum_balance(space)
+        .max(1)
+        .saturating_sub(account_info.lamports());
+    if required_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(funder_info.key, account_info.key, required_lamports),
+            &[
+                funder_info.clone(),
+                account_info.clone(),
+                system_program_info.clone(),
+            ],
+        )?;
+    }
+
+    invoke_signed(
+        &system_instruction::allocate(account_info.key, space as u64),
+        &[account_info.clone(), system_program_info.clone()],
+        &[account_seeds],
+    )?;
+
+    invoke_signed(
+        &system_instruction::assign(account_info.key, program_id),
+        &[account_info.clone(), system_program_info.clone()],
+        &[account_seeds],
+    )?;
+
+    Ok(())
+}
+
+/// Closes a program account, returning its lamports to the destination
+fn close_program_account(
+    account_info: &AccountInfo,
+    destination_info: &AccountInfo,
+) -> ProgramResult {
+    let lamports = account_info.lamports();
+    **destination_info.try_borrow_mut_lamports()? = destination_info
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(RNDRError::MathError)?;
+    **account_info.try_borrow_mut_lamports()? = 0;
+    account_info.try_borrow_mut_data()?.fill(0);
+
     Ok(())
 }
@@ -11,6 +11,36 @@ pub enum AccountType {
     EscrowV1,
     /// Job
     JobV1,
+    /// Job metadata
+    JobMetadataV1,
+    /// Milestone
+    MilestoneV1,
+    /// Payout root
+    PayoutRootV1,
+    /// Redeemed voucher
+    RedeemedVoucherV1,
+    /// Program config
+    ProgramConfigV1,
+    /// Funding schedule
+    FundingScheduleV1,
+    /// Pending payout
+    PendingPayoutV1,
+    /// Render submission
+    RenderSubmissionV1,
+    /// Rate card
+    RateCardV1,
+    /// Assignment
+    AssignmentV1,
+    /// Render node
+    NodeV1,
+    /// Node operator stake
+    StakeV1,
+    /// Earnings ledger of a destination owner
+    NodeEarningsV1,
+    /// Payout receipt
+    ReceiptV1,
+    /// Idempotency marker of a disbursal
+    IdempotencyMarkerV1,
 }
 
 impl Default for AccountType {
@@ -0,0 +1,144 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::UnixTimestamp,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Assignment state
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Assignment {
+    /// Account type, must be AssignmentV1 currently
+    pub account_type: AccountType,
+    /// Escrow the job belongs to
+    pub escrow: Pubkey,
+    /// Job the funds are reserved on
+    pub job: Pubkey,
+    /// Render node the funds are reserved for
+    pub node: Pubkey,
+    /// Amount of tokens reserved for the render node
+    pub amount: u64,
+    /// Time after which the reservation can be released back to the job
+    pub deadline: UnixTimestamp,
+    /// Bump seed of the assignment program derived address
+    pub bump_seed: u8,
+    /// Account that paid the rent of the assignment
+    pub rent_payer: Pubkey,
+}
+
+impl Assignment {
+    /// Create an assignment
+    pub fn new(params: InitAssignmentParams) -> Self {
+        let mut assignment = Self::default();
+        Self::init(&mut assignment, params);
+        assignment
+    }
+
+    /// Initialize an assignment
+    pub fn init(&mut self, params: InitAssignmentParams) {
+        self.account_type = AccountType::AssignmentV1;
+        self.escrow = params.escrow;
+        self.job = params.job;
+        self.node = params.node;
+        self.amount = params.amount;
+        self.deadline = params.deadline;
+        self.bump_seed = params.bump_seed;
+        self.rent_payer = params.rent_payer;
+    }
+}
+
+/// Initialize an assignment
+pub struct InitAssignmentParams {
+    /// Escrow the job belongs to
+    pub escrow: Pubkey,
+    /// Job the funds are reserved on
+    pub job: Pubkey,
+    /// Render node the funds are reserved for
+    pub node: Pubkey,
+    /// Amount of tokens reserved for the render node
+    pub amount: u64,
+    /// Time after which the reservation can be released back to the job
+    pub deadline: UnixTimestamp,
+    /// Bump seed of the assignment program derived address
+    pub bump_seed: u8,
+    /// Account that paid the rent of the assignment
+    pub rent_payer: Pubkey,
+}
+
+impl Sealed for Assignment {}
+
+impl IsInitialized for Assignment {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const ASSIGNMENT_LEN: usize = 146; // 1 + 32 + 32 + 32 + 8 + 8 + 1 + 32
+impl Pack for Assignment {
+    const LEN: usize = ASSIGNMENT_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, ASSIGNMENT_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, job, node, amount, deadline, bump_seed, rent_payer) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            8,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        job.copy_from_slice(&self.job.to_bytes());
+        node.copy_from_slice(&self.node.to_bytes());
+        *amount = self.amount.to_le_bytes();
+        *deadline = self.deadline.to_le_bytes();
+        *bump_seed = self.bump_seed.to_le_bytes();
+        rent_payer.copy_from_slice(&self.rent_payer.to_bytes());
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, ASSIGNMENT_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, job, node, amount, deadline, bump_seed, rent_payer) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            8,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if account_type != AccountType::AssignmentV1 {
+            msg!("Assignment account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            job: Pubkey::new_from_array(*job),
+            node: Pubkey::new_from_array(*node),
+            amount: u64::from_le_bytes(*amount),
+            deadline: i64::from_le_bytes(*deadline),
+            bump_seed: u8::from_le_bytes(*bump_seed),
+            rent_payer: Pubkey::new_from_array(*rent_payer),
+        })
+    }
+}
@@ -1,15 +1,26 @@
 use {
     super::*,
+    crate::error::RNDRError,
     arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    bytemuck::{Pod, Zeroable},
     solana_program::{
         msg,
         program_error::ProgramError,
         program_pack::{IsInitialized, Pack, Sealed},
         pubkey::{Pubkey, PUBKEY_BYTES},
     },
-    std::convert::TryFrom,
+    std::{convert::TryFrom, mem::size_of},
 };
 
+/// Number of priority tiers jobs can select
+pub const TIER_COUNT: usize = 3;
+
+/// Tier price multiplier that leaves prices unchanged, multipliers are in basis points
+pub const TIER_MULTIPLIER_BASE: u32 = 10_000;
+
+/// Highest tier price multiplier, pricing a tier at 10x
+pub const MAX_TIER_MULTIPLIER: u32 = 10 * TIER_MULTIPLIER_BASE;
+
 /// Escrow state
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct Escrow {
@@ -19,6 +30,22 @@ pub struct Escrow {
     pub amount: u64,
     /// Owner authority that can disburse funds
     pub owner: Pubkey,
+    /// Bump seed of the escrow program derived address
+    pub bump_seed: u8,
+    /// Namespace of the escrow program derived address, the default pubkey for the legacy escrow
+    pub namespace: Pubkey,
+    /// Number of open jobs in the escrow
+    pub job_count: u64,
+    /// Escrow for the new token mint job balances are migrating to, the default pubkey if none
+    pub successor: Pubkey,
+    /// Price multiplier of each priority tier, in basis points
+    pub tier_multipliers: [u32; TIER_COUNT],
+    /// Whether disbursal destinations must belong to an active registered render node
+    pub node_registry_required: bool,
+    /// Amount of tokens node operators must stake to be assigned work, 0 if no stake is required
+    pub min_stake: u64,
+    /// Number of jobs ever created in the escrow, the generation of the next job
+    pub jobs_created: u64,
 }
 
 impl Escrow {
@@ -34,13 +61,71 @@ impl Escrow {
         self.account_type = AccountType::EscrowV1;
         self.amount = 0;
         self.owner = params.owner;
+        self.bump_seed = params.bump_seed;
+        self.namespace = params.namespace;
+        self.job_count = 0;
+        self.successor = Pubkey::default();
+        self.tier_multipliers = [TIER_MULTIPLIER_BASE; TIER_COUNT];
+        self.node_registry_required = false;
+        self.min_stake = 0;
+        self.jobs_created = 0;
+    }
+}
+
+/// Escrow state accessed in place in the account data, with the same layout as `Escrow`
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+#[repr(C)]
+pub struct PodEscrow {
+    /// Account type, must be EscrowV1 currently
+    pub account_type: u8,
+    /// Amount of tokens in escrow
+    pub amount: PodU64,
+    /// Owner authority that can disburse funds
+    pub owner: PodPubkey,
+    /// Bump seed of the escrow program derived address
+    pub bump_seed: u8,
+    /// Namespace of the escrow program derived address, the default pubkey for the legacy escrow
+    pub namespace: PodPubkey,
+    /// Number of open jobs in the escrow
+    pub job_count: PodU64,
+    /// Escrow for the new token mint job balances are migrating to, the default pubkey if none
+    pub successor: PodPubkey,
+    /// Price multiplier of each priority tier, in basis points
+    pub tier_multipliers: [PodU32; TIER_COUNT],
+    /// Whether disbursal destinations must belong to an active registered render node
+    pub node_registry_required: u8,
+    /// Amount of tokens node operators must stake to be assigned work, 0 if no stake is required
+    pub min_stake: PodU64,
+    /// Number of jobs ever created in the escrow, the generation of the next job
+    pub jobs_created: PodU64,
+}
+
+impl PodEscrow {
+    /// Get the price multiplier of a priority tier, tier 0 is untiered
+    pub fn tier_multiplier(&self, tier: u8) -> Result<u32, ProgramError> {
+        if tier == 0 {
+            return Ok(TIER_MULTIPLIER_BASE);
+        }
+        self.tier_multipliers
+            .get(tier as usize - 1)
+            .copied()
+            .map(u32::from)
+            .ok_or_else(|| RNDRError::UnspecifiedError.into())
     }
 }
 
+impl PodState for PodEscrow {
+    const ACCOUNT_TYPE: AccountType = AccountType::EscrowV1;
+}
+
 /// Initialize a escrow
 pub struct InitEscrowParams {
     /// Owner authority that can disburse funds
     pub owner: Pubkey,
+    /// Bump seed of the escrow program derived address
+    pub bump_seed: u8,
+    /// Namespace of the escrow program derived address, the default pubkey for the legacy escrow
+    pub namespace: Pubkey,
 }
 
 impl Sealed for Escrow {}
@@ -51,24 +136,88 @@ impl IsInitialized for Escrow {
     }
 }
 
-const ESCROW_LEN: usize = 41; // 1 + 8 + 32
+const ESCROW_LEN: usize = 143; // 1 + 8 + 32 + 1 + 32 + 8 + 32 + 4 * 3 + 1 + 8 + 8
+const _: () = assert!(size_of::<PodEscrow>() == ESCROW_LEN);
 impl Pack for Escrow {
     const LEN: usize = ESCROW_LEN;
 
     fn pack_into_slice(&self, output: &mut [u8]) {
         let output = array_mut_ref![output, 0, ESCROW_LEN];
         #[allow(clippy::ptr_offset_with_cast)]
-        let (account_type, amount, owner) = mut_array_refs![output, 1, 8, PUBKEY_BYTES];
+        let (
+            account_type,
+            amount,
+            owner,
+            bump_seed,
+            namespace,
+            job_count,
+            successor,
+            tier_multipliers,
+            node_registry_required,
+            min_stake,
+            jobs_created,
+        ) = mut_array_refs![
+            output,
+            1,
+            8,
+            PUBKEY_BYTES,
+            1,
+            PUBKEY_BYTES,
+            8,
+            PUBKEY_BYTES,
+            4 * TIER_COUNT,
+            1,
+            8,
+            8
+        ];
 
         *account_type = u8::from(self.account_type).to_le_bytes();
         *amount = self.amount.to_le_bytes();
         owner.copy_from_slice(&self.owner.to_bytes());
+        *bump_seed = self.bump_seed.to_le_bytes();
+        namespace.copy_from_slice(&self.namespace.to_bytes());
+        *job_count = self.job_count.to_le_bytes();
+        successor.copy_from_slice(&self.successor.to_bytes());
+        for (output, multiplier) in tier_multipliers
+            .chunks_exact_mut(4)
+            .zip(self.tier_multipliers.iter())
+        {
+            output.copy_from_slice(&multiplier.to_le_bytes());
+        }
+        *node_registry_required = (self.node_registry_required as u8).to_le_bytes();
+        *min_stake = self.min_stake.to_le_bytes();
+        *jobs_created = self.jobs_created.to_le_bytes();
     }
 
     fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
         let input = array_ref![input, 0, ESCROW_LEN];
         #[allow(clippy::ptr_offset_with_cast)]
-        let (account_type, amount, owner) = array_refs![input, 1, 8, PUBKEY_BYTES];
+        let (
+            account_type,
+            amount,
+            owner,
+            bump_seed,
+            namespace,
+            job_count,
+            successor,
+            tier_multipliers,
+            node_registry_required,
+            min_stake,
+            jobs_created,
+        ) = array_refs![
+            input,
+            1,
+            8,
+            PUBKEY_BYTES,
+            1,
+            PUBKEY_BYTES,
+            8,
+            PUBKEY_BYTES,
+            4 * TIER_COUNT,
+            1,
+            8,
+            8
+        ];
 
         let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
             .map_err(|_| ProgramError::InvalidAccountData)?;
@@ -77,10 +226,32 @@ impl Pack for Escrow {
             return Err(ProgramError::InvalidAccountData);
         }
 
+        let mut multipliers = [0; TIER_COUNT];
+        for (multiplier, input) in multipliers.iter_mut().zip(tier_multipliers.chunks_exact(4)) {
+            *multiplier = u32::from_le_bytes(*array_ref![input, 0, 4]);
+        }
+
+        let node_registry_required = match u8::from_le_bytes(*node_registry_required) {
+            0 => false,
+            1 => true,
+            _ => {
+                msg!("Escrow node registry required flag is invalid");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        };
+
         Ok(Self {
             account_type,
             amount: u64::from_le_bytes(*amount),
             owner: Pubkey::new_from_array(*owner),
+            bump_seed: u8::from_le_bytes(*bump_seed),
+            namespace: Pubkey::new_from_array(*namespace),
+            job_count: u64::from_le_bytes(*job_count),
+            successor: Pubkey::new_from_array(*successor),
+            tier_multipliers: multipliers,
+            node_registry_required,
+            min_stake: u64::from_le_bytes(*min_stake),
+            jobs_created: u64::from_le_bytes(*jobs_created),
         })
     }
 }
@@ -0,0 +1,202 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::UnixTimestamp,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Funding schedule state
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FundingSchedule {
+    /// Account type, must be FundingScheduleV1 currently
+    pub account_type: AccountType,
+    /// Escrow the job belongs to
+    pub escrow: Pubkey,
+    /// Job funded by the schedule
+    pub job: Pubkey,
+    /// Job authority that created the schedule
+    pub authority: Pubkey,
+    /// Token account funds are pulled from, with the escrow as delegate
+    pub source_token: Pubkey,
+    /// Amount of tokens funded each period
+    pub amount: u64,
+    /// Length of a period in seconds
+    pub period: i64,
+    /// Time the next funding is due at
+    pub next_due: UnixTimestamp,
+    /// Whether funding stopped because the allowance of the escrow ran out
+    pub paused: bool,
+    /// Bump seed of the funding schedule program derived address
+    pub bump_seed: u8,
+    /// Account that paid the rent of the funding schedule
+    pub rent_payer: Pubkey,
+}
+
+impl FundingSchedule {
+    /// Create a funding schedule
+    pub fn new(params: InitFundingScheduleParams) -> Self {
+        let mut funding_schedule = Self::default();
+        Self::init(&mut funding_schedule, params);
+        funding_schedule
+    }
+
+    /// Initialize a funding schedule
+    pub fn init(&mut self, params: InitFundingScheduleParams) {
+        self.account_type = AccountType::FundingScheduleV1;
+        self.escrow = params.escrow;
+        self.job = params.job;
+        self.authority = params.authority;
+        self.source_token = params.source_token;
+        self.amount = params.amount;
+        self.period = params.period;
+        self.next_due = params.next_due;
+        self.paused = false;
+        self.bump_seed = params.bump_seed;
+        self.rent_payer = params.rent_payer;
+    }
+}
+
+/// Initialize a funding schedule
+pub struct InitFundingScheduleParams {
+    /// Escrow the job belongs to
+    pub escrow: Pubkey,
+    /// Job funded by the schedule
+    pub job: Pubkey,
+    /// Job authority that created the schedule
+    pub authority: Pubkey,
+    /// Token account funds are pulled from, with the escrow as delegate
+    pub source_token: Pubkey,
+    /// Amount of tokens funded each period
+    pub amount: u64,
+    /// Length of a period in seconds
+    pub period: i64,
+    /// Time the first funding is due at
+    pub next_due: UnixTimestamp,
+    /// Bump seed of the funding schedule program derived address
+    pub bump_seed: u8,
+    /// Account that paid the rent of the funding schedule
+    pub rent_payer: Pubkey,
+}
+
+impl Sealed for FundingSchedule {}
+
+impl IsInitialized for FundingSchedule {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const FUNDING_SCHEDULE_LEN: usize = 187; // 1 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 1 + 1 + 32
+impl Pack for FundingSchedule {
+    const LEN: usize = FUNDING_SCHEDULE_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, FUNDING_SCHEDULE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            job,
+            authority,
+            source_token,
+            amount,
+            period,
+            next_due,
+            paused,
+            bump_seed,
+            rent_payer,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            8,
+            8,
+            1,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        job.copy_from_slice(&self.job.to_bytes());
+        authority.copy_from_slice(&self.authority.to_bytes());
+        source_token.copy_from_slice(&self.source_token.to_bytes());
+        *amount = self.amount.to_le_bytes();
+        *period = self.period.to_le_bytes();
+        *next_due = self.next_due.to_le_bytes();
+        *paused = (self.paused as u8).to_le_bytes();
+        *bump_seed = self.bump_seed.to_le_bytes();
+        rent_payer.copy_from_slice(&self.rent_payer.to_bytes());
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, FUNDING_SCHEDULE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            job,
+            authority,
+            source_token,
+            amount,
+            period,
+            next_due,
+            paused,
+            bump_seed,
+            rent_payer,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            8,
+            8,
+            1,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if account_type != AccountType::FundingScheduleV1 {
+            msg!("Funding schedule account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let paused = match u8::from_le_bytes(*paused) {
+            0 => false,
+            1 => true,
+            _ => {
+                msg!("Funding schedule paused flag is invalid");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        };
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            job: Pubkey::new_from_array(*job),
+            authority: Pubkey::new_from_array(*authority),
+            source_token: Pubkey::new_from_array(*source_token),
+            amount: u64::from_le_bytes(*amount),
+            period: i64::from_le_bytes(*period),
+            next_due: i64::from_le_bytes(*next_due),
+            paused,
+            bump_seed: u8::from_le_bytes(*bump_seed),
+            rent_payer: Pubkey::new_from_array(*rent_payer),
+        })
+    }
+}
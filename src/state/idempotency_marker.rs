@@ -0,0 +1,147 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::UnixTimestamp,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Maximum length of an idempotency key
+pub const MAX_IDEMPOTENCY_KEY_LEN: usize = 32;
+
+/// Seconds an idempotency marker is kept before it can be closed, long enough to outlast retries
+pub const IDEMPOTENCY_MARKER_LIFETIME: i64 = 24 * 60 * 60;
+
+/// Check if an idempotency key has a supported length of 16 or 32 bytes
+pub fn is_valid_idempotency_key(key: &[u8]) -> bool {
+    key.len() == 16 || key.len() == MAX_IDEMPOTENCY_KEY_LEN
+}
+
+/// Idempotency marker state, recording that a disbursal with a key was made from a job
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IdempotencyMarker {
+    /// Account type, must be IdempotencyMarkerV1 currently
+    pub account_type: AccountType,
+    /// Job the disbursal was made from
+    pub job: Pubkey,
+    /// Idempotency key of the disbursal
+    pub key: Vec<u8>,
+    /// Time of the disbursal
+    pub created_at: UnixTimestamp,
+    /// Bump seed of the idempotency marker program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the idempotency marker
+    pub rent_payer: Pubkey,
+}
+
+impl IdempotencyMarker {
+    /// Create an idempotency marker
+    pub fn new(params: InitIdempotencyMarkerParams) -> Self {
+        let mut idempotency_marker = Self::default();
+        Self::init(&mut idempotency_marker, params);
+        idempotency_marker
+    }
+
+    /// Initialize an idempotency marker
+    pub fn init(&mut self, params: InitIdempotencyMarkerParams) {
+        self.account_type = AccountType::IdempotencyMarkerV1;
+        self.job = params.job;
+        self.key = params.key;
+        self.created_at = params.created_at;
+        self.bump_seed = params.bump_seed;
+        self.rent_payer = params.rent_payer;
+    }
+}
+
+/// Initialize an idempotency marker
+pub struct InitIdempotencyMarkerParams {
+    /// Job the disbursal was made from
+    pub job: Pubkey,
+    /// Idempotency key of the disbursal
+    pub key: Vec<u8>,
+    /// Time of the disbursal
+    pub created_at: UnixTimestamp,
+    /// Bump seed of the idempotency marker program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the idempotency marker
+    pub rent_payer: Pubkey,
+}
+
+impl Sealed for IdempotencyMarker {}
+
+impl IsInitialized for IdempotencyMarker {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const IDEMPOTENCY_MARKER_LEN: usize = 107; // 1 + 32 + 1 + 32 + 8 + 1 + 32
+impl Pack for IdempotencyMarker {
+    const LEN: usize = IDEMPOTENCY_MARKER_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, IDEMPOTENCY_MARKER_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, job, key_len, key, created_at, bump_seed, rent_payer) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            1,
+            MAX_IDEMPOTENCY_KEY_LEN,
+            8,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        job.copy_from_slice(&self.job.to_bytes());
+        *key_len = (self.key.len() as u8).to_le_bytes();
+        key.fill(0);
+        key[..self.key.len()].copy_from_slice(&self.key);
+        *created_at = self.created_at.to_le_bytes();
+        *bump_seed = self.bump_seed.to_le_bytes();
+        rent_payer.copy_from_slice(&self.rent_payer.to_bytes());
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, IDEMPOTENCY_MARKER_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, job, key_len, key, created_at, bump_seed, rent_payer) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            1,
+            MAX_IDEMPOTENCY_KEY_LEN,
+            8,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if account_type != AccountType::IdempotencyMarkerV1 {
+            msg!("Idempotency marker account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let key_len = u8::from_le_bytes(*key_len) as usize;
+        if key_len > MAX_IDEMPOTENCY_KEY_LEN {
+            msg!("Idempotency marker key length is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            job: Pubkey::new_from_array(*job),
+            key: key[..key_len].to_vec(),
+            created_at: i64::from_le_bytes(*created_at),
+            bump_seed: u8::from_le_bytes(*bump_seed),
+            rent_payer: Pubkey::new_from_array(*rent_payer),
+        })
+    }
+}
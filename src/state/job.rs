@@ -1,13 +1,15 @@
 use {
     super::*,
+    crate::error::RNDRError,
     arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    bytemuck::{Pod, Zeroable},
     solana_program::{
         msg,
         program_error::ProgramError,
         program_pack::{IsInitialized, Pack, Sealed},
         pubkey::{Pubkey, PUBKEY_BYTES},
     },
-    std::convert::TryFrom,
+    std::{convert::TryFrom, mem::size_of},
 };
 
 /// Job state
@@ -19,6 +21,32 @@ pub struct Job {
     pub amount: u64,
     /// User authority that initialized the job
     pub authority: Pubkey,
+    /// Total amount of tokens disbursed from the job
+    pub disbursed: u64,
+    /// Number of milestones defined for the job
+    pub milestone_count: u8,
+    /// Total amount of tokens the job authority has approved through milestones
+    pub approved_amount: u64,
+    /// Amount of tokens in escrow reserved for pending payouts from the job
+    pub reserved: u64,
+    /// Whether payouts from the job require an accepted render submission
+    pub submissions_required: bool,
+    /// Whether the job has a rate card, paying out only through claimed frames
+    pub priced_per_frame: bool,
+    /// Priority tier of the job, 0 if the job is untiered
+    pub tier: u8,
+    /// Price multiplier of the tier of the job in basis points, kept from when the tier was
+    /// selected
+    pub tier_multiplier: u32,
+    /// Number of payouts disbursed with DisburseFunds, the index of the next payout receipt
+    pub payout_count: u64,
+    /// Generation of the job in the escrow, seeding the accounts of the job so that they aren't
+    /// shared with a closed job of the same address
+    pub generation: u64,
+    /// Bump seed of the job program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the job
+    pub rent_payer: Pubkey,
 }
 
 impl Job {
@@ -34,13 +62,104 @@ impl Job {
         self.account_type = AccountType::JobV1;
         self.amount = 0;
         self.authority = params.authority;
+        self.disbursed = 0;
+        self.milestone_count = 0;
+        self.approved_amount = 0;
+        self.reserved = 0;
+        self.submissions_required = false;
+        self.priced_per_frame = false;
+        self.tier = params.tier;
+        self.tier_multiplier = params.tier_multiplier;
+        self.payout_count = 0;
+        self.generation = params.generation;
+        self.bump_seed = params.bump_seed;
+        self.rent_payer = params.rent_payer;
+    }
+
+    /// Check if disbursal from the job is limited by milestones
+    pub fn has_milestones(&self) -> bool {
+        self.milestone_count > 0
+    }
+}
+
+/// Job state accessed in place in the account data, with the same layout as `Job`
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+#[repr(C)]
+pub struct PodJob {
+    /// Account type, must be JobV1 currently
+    pub account_type: u8,
+    /// Amount of tokens in escrow for the job
+    pub amount: PodU64,
+    /// User authority that initialized the job
+    pub authority: PodPubkey,
+    /// Total amount of tokens disbursed from the job
+    pub disbursed: PodU64,
+    /// Number of milestones defined for the job
+    pub milestone_count: u8,
+    /// Total amount of tokens the job authority has approved through milestones
+    pub approved_amount: PodU64,
+    /// Amount of tokens in escrow reserved for pending payouts from the job
+    pub reserved: PodU64,
+    /// Whether payouts from the job require an accepted render submission
+    pub submissions_required: u8,
+    /// Whether the job has a rate card, paying out only through claimed frames
+    pub priced_per_frame: u8,
+    /// Priority tier of the job, 0 if the job is untiered
+    pub tier: u8,
+    /// Price multiplier of the tier of the job in basis points, kept from when the tier was
+    /// selected
+    pub tier_multiplier: PodU32,
+    /// Number of payouts disbursed with DisburseFunds, the index of the next payout receipt
+    pub payout_count: PodU64,
+    /// Generation of the job in the escrow, seeding the accounts of the job so that they aren't
+    /// shared with a closed job of the same address
+    pub generation: PodU64,
+    /// Bump seed of the job program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the job
+    pub rent_payer: PodPubkey,
+}
+
+impl PodJob {
+    /// Apply the price multiplier of the tier of the job to an amount of tokens
+    pub fn apply_tier_multiplier(&self, amount: u64) -> Result<u64, ProgramError> {
+        let amount = u128::from(amount)
+            .checked_mul(u32::from(self.tier_multiplier).into())
+            .ok_or(RNDRError::MathError)?
+            / u128::from(TIER_MULTIPLIER_BASE);
+        u64::try_from(amount).map_err(|_| RNDRError::MathError.into())
+    }
+
+    /// Check if the tokens disbursed and reserved from the job exceed its approved milestones
+    pub fn exceeds_approved_amount(&self) -> Result<bool, ProgramError> {
+        if self.milestone_count == 0 {
+            return Ok(false);
+        }
+        let committed = u64::from(self.disbursed)
+            .checked_add(self.reserved.into())
+            .ok_or(RNDRError::MathError)?;
+        Ok(committed > self.approved_amount.into())
     }
 }
 
+impl PodState for PodJob {
+    const ACCOUNT_TYPE: AccountType = AccountType::JobV1;
+}
+
 /// Initialize a job
 pub struct InitJobParams {
     /// User authority that initialized the job
     pub authority: Pubkey,
+    /// Generation of the job in the escrow
+    pub generation: u64,
+    /// Priority tier of the job, 0 if the job is untiered
+    pub tier: u8,
+    /// Price multiplier of the tier of the job in basis points
+    pub tier_multiplier: u32,
+    /// Bump seed of the job program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the job
+    pub rent_payer: Pubkey,
 }
 
 impl Sealed for Job {}
@@ -51,24 +170,103 @@ impl IsInitialized for Job {
     }
 }
 
-const JOB_LEN: usize = 41; // 1 + 8 + 32
+const JOB_LEN: usize = 122; // 1 + 8 + 32 + 8 + 1 + 8 + 8 + 1 + 1 + 1 + 4 + 8 + 8 + 1 + 32
+const _: () = assert!(size_of::<PodJob>() == JOB_LEN);
 impl Pack for Job {
     const LEN: usize = JOB_LEN;
 
     fn pack_into_slice(&self, output: &mut [u8]) {
         let output = array_mut_ref![output, 0, JOB_LEN];
         #[allow(clippy::ptr_offset_with_cast)]
-        let (account_type, amount, authority) = mut_array_refs![output, 1, 8, PUBKEY_BYTES];
+        let (
+            account_type,
+            amount,
+            authority,
+            disbursed,
+            milestone_count,
+            approved_amount,
+            reserved,
+            submissions_required,
+            priced_per_frame,
+            tier,
+            tier_multiplier,
+            payout_count,
+            generation,
+            bump_seed,
+            rent_payer,
+        ) = mut_array_refs![
+            output,
+            1,
+            8,
+            PUBKEY_BYTES,
+            8,
+            1,
+            8,
+            8,
+            1,
+            1,
+            1,
+            4,
+            8,
+            8,
+            1,
+            PUBKEY_BYTES
+        ];
 
         *account_type = u8::from(self.account_type).to_le_bytes();
         *amount = self.amount.to_le_bytes();
         authority.copy_from_slice(&self.authority.to_bytes());
+        *disbursed = self.disbursed.to_le_bytes();
+        *milestone_count = self.milestone_count.to_le_bytes();
+        *approved_amount = self.approved_amount.to_le_bytes();
+        *reserved = self.reserved.to_le_bytes();
+        *submissions_required = (self.submissions_required as u8).to_le_bytes();
+        *priced_per_frame = (self.priced_per_frame as u8).to_le_bytes();
+        *tier = self.tier.to_le_bytes();
+        *tier_multiplier = self.tier_multiplier.to_le_bytes();
+        *payout_count = self.payout_count.to_le_bytes();
+        *generation = self.generation.to_le_bytes();
+        *bump_seed = self.bump_seed.to_le_bytes();
+        rent_payer.copy_from_slice(&self.rent_payer.to_bytes());
     }
 
     fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
         let input = array_ref![input, 0, JOB_LEN];
         #[allow(clippy::ptr_offset_with_cast)]
-        let (account_type, amount, authority) = array_refs![input, 1, 8, PUBKEY_BYTES];
+        let (
+            account_type,
+            amount,
+            authority,
+            disbursed,
+            milestone_count,
+            approved_amount,
+            reserved,
+            submissions_required,
+            priced_per_frame,
+            tier,
+            tier_multiplier,
+            payout_count,
+            generation,
+            bump_seed,
+            rent_payer,
+        ) = array_refs![
+            input,
+            1,
+            8,
+            PUBKEY_BYTES,
+            8,
+            1,
+            8,
+            8,
+            1,
+            1,
+            1,
+            4,
+            8,
+            8,
+            1,
+            PUBKEY_BYTES
+        ];
 
         let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
             .map_err(|_| ProgramError::InvalidAccountData)?;
@@ -77,10 +275,39 @@ impl Pack for Job {
             return Err(ProgramError::InvalidAccountData);
         }
 
+        let submissions_required = match u8::from_le_bytes(*submissions_required) {
+            0 => false,
+            1 => true,
+            _ => {
+                msg!("Job submissions required flag is invalid");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        };
+        let priced_per_frame = match u8::from_le_bytes(*priced_per_frame) {
+            0 => false,
+            1 => true,
+            _ => {
+                msg!("Job priced per frame flag is invalid");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        };
+
         Ok(Self {
             account_type,
             amount: u64::from_le_bytes(*amount),
             authority: Pubkey::new_from_array(*authority),
+            disbursed: u64::from_le_bytes(*disbursed),
+            milestone_count: u8::from_le_bytes(*milestone_count),
+            approved_amount: u64::from_le_bytes(*approved_amount),
+            reserved: u64::from_le_bytes(*reserved),
+            submissions_required,
+            priced_per_frame,
+            tier: u8::from_le_bytes(*tier),
+            tier_multiplier: u32::from_le_bytes(*tier_multiplier),
+            payout_count: u64::from_le_bytes(*payout_count),
+            generation: u64::from_le_bytes(*generation),
+            bump_seed: u8::from_le_bytes(*bump_seed),
+            rent_payer: Pubkey::new_from_array(*rent_payer),
         })
     }
 }
@@ -0,0 +1,122 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Maximum length of a job content identifier, enough for a base32 CIDv1
+pub const MAX_CONTENT_ID_LEN: usize = 64;
+
+/// Job metadata state
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JobMetadata {
+    /// Account type, must be JobMetadataV1 currently
+    pub account_type: AccountType,
+    /// Job the metadata describes
+    pub job: Pubkey,
+    /// Hash of the scene or package being rendered
+    pub scene_hash: [u8; 32],
+    /// Optional content identifier of the scene, such as an IPFS CID
+    pub content_id: Vec<u8>,
+    /// First frame to render
+    pub frame_start: u32,
+    /// Last frame to render, inclusive
+    pub frame_end: u32,
+}
+
+impl JobMetadata {
+    /// Create job metadata
+    pub fn new(params: InitJobMetadataParams) -> Self {
+        let mut job_metadata = Self::default();
+        Self::init(&mut job_metadata, params);
+        job_metadata
+    }
+
+    /// Initialize job metadata
+    pub fn init(&mut self, params: InitJobMetadataParams) {
+        self.account_type = AccountType::JobMetadataV1;
+        self.job = params.job;
+        self.scene_hash = params.scene_hash;
+        self.content_id = params.content_id;
+        self.frame_start = params.frame_start;
+        self.frame_end = params.frame_end;
+    }
+}
+
+/// Initialize job metadata
+pub struct InitJobMetadataParams {
+    /// Job the metadata describes
+    pub job: Pubkey,
+    /// Hash of the scene or package being rendered
+    pub scene_hash: [u8; 32],
+    /// Optional content identifier of the scene, such as an IPFS CID
+    pub content_id: Vec<u8>,
+    /// First frame to render
+    pub frame_start: u32,
+    /// Last frame to render, inclusive
+    pub frame_end: u32,
+}
+
+impl Sealed for JobMetadata {}
+
+impl IsInitialized for JobMetadata {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const JOB_METADATA_LEN: usize = 138; // 1 + 32 + 32 + 1 + 64 + 4 + 4
+impl Pack for JobMetadata {
+    const LEN: usize = JOB_METADATA_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, JOB_METADATA_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, job, scene_hash, content_id_len, content_id, frame_start, frame_end) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, 32, 1, MAX_CONTENT_ID_LEN, 4, 4];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        job.copy_from_slice(&self.job.to_bytes());
+        scene_hash.copy_from_slice(&self.scene_hash);
+        *content_id_len = (self.content_id.len() as u8).to_le_bytes();
+        content_id.fill(0);
+        content_id[..self.content_id.len()].copy_from_slice(&self.content_id);
+        *frame_start = self.frame_start.to_le_bytes();
+        *frame_end = self.frame_end.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, JOB_METADATA_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, job, scene_hash, content_id_len, content_id, frame_start, frame_end) =
+            array_refs![input, 1, PUBKEY_BYTES, 32, 1, MAX_CONTENT_ID_LEN, 4, 4];
+
+        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if account_type != AccountType::JobMetadataV1 {
+            msg!("Job metadata account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let content_id_len = u8::from_le_bytes(*content_id_len) as usize;
+        if content_id_len > MAX_CONTENT_ID_LEN {
+            msg!("Job metadata content identifier length is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            job: Pubkey::new_from_array(*job),
+            scene_hash: *scene_hash,
+            content_id: content_id[..content_id_len].to_vec(),
+            frame_start: u32::from_le_bytes(*frame_start),
+            frame_end: u32::from_le_bytes(*frame_end),
+        })
+    }
+}
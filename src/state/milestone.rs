@@ -0,0 +1,118 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Milestone state
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Milestone {
+    /// Account type, must be MilestoneV1 currently
+    pub account_type: AccountType,
+    /// Job the milestone belongs to
+    pub job: Pubkey,
+    /// Index of the milestone within the job
+    pub index: u8,
+    /// Maximum amount of tokens that can be disbursed for the milestone
+    pub amount: u64,
+    /// Hash of the milestone description
+    pub description_hash: [u8; 32],
+    /// Whether the job authority has approved the milestone
+    pub approved: bool,
+}
+
+impl Milestone {
+    /// Create a milestone
+    pub fn new(params: InitMilestoneParams) -> Self {
+        let mut milestone = Self::default();
+        Self::init(&mut milestone, params);
+        milestone
+    }
+
+    /// Initialize a milestone
+    pub fn init(&mut self, params: InitMilestoneParams) {
+        self.account_type = AccountType::MilestoneV1;
+        self.job = params.job;
+        self.index = params.index;
+        self.amount = params.amount;
+        self.description_hash = params.description_hash;
+        self.approved = false;
+    }
+}
+
+/// Initialize a milestone
+pub struct InitMilestoneParams {
+    /// Job the milestone belongs to
+    pub job: Pubkey,
+    /// Index of the milestone within the job
+    pub index: u8,
+    /// Maximum amount of tokens that can be disbursed for the milestone
+    pub amount: u64,
+    /// Hash of the milestone description
+    pub description_hash: [u8; 32],
+}
+
+impl Sealed for Milestone {}
+
+impl IsInitialized for Milestone {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const MILESTONE_LEN: usize = 75; // 1 + 32 + 1 + 8 + 32 + 1
+impl Pack for Milestone {
+    const LEN: usize = MILESTONE_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, MILESTONE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, job, index, amount, description_hash, approved) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, 1, 8, 32, 1];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        job.copy_from_slice(&self.job.to_bytes());
+        *index = self.index.to_le_bytes();
+        *amount = self.amount.to_le_bytes();
+        description_hash.copy_from_slice(&self.description_hash);
+        *approved = (self.approved as u8).to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, MILESTONE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, job, index, amount, description_hash, approved) =
+            array_refs![input, 1, PUBKEY_BYTES, 1, 8, 32, 1];
+
+        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if account_type != AccountType::MilestoneV1 {
+            msg!("Milestone account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let approved = match u8::from_le_bytes(*approved) {
+            0 => false,
+            1 => true,
+            _ => {
+                msg!("Milestone approved flag is invalid");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        };
+
+        Ok(Self {
+            account_type,
+            job: Pubkey::new_from_array(*job),
+            index: u8::from_le_bytes(*index),
+            amount: u64::from_le_bytes(*amount),
+            description_hash: *description_hash,
+            approved,
+        })
+    }
+}
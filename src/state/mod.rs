@@ -1,9 +1,41 @@
 //! State types
 
 pub use account_type::*;
+pub use assignment::*;
 pub use escrow::*;
+pub use funding_schedule::*;
+pub use idempotency_marker::*;
 pub use job::*;
+pub use job_metadata::*;
+pub use milestone::*;
+pub use node::*;
+pub use node_earnings::*;
+pub use payout_root::*;
+pub use pending_payout::*;
+pub use pod::*;
+pub use program_config::*;
+pub use rate_card::*;
+pub use receipt::*;
+pub use redeemed_voucher::*;
+pub use render_submission::*;
+pub use stake::*;
 
 mod account_type;
+mod assignment;
 mod escrow;
+mod funding_schedule;
+mod idempotency_marker;
 mod job;
+mod job_metadata;
+mod milestone;
+mod node;
+mod node_earnings;
+mod payout_root;
+mod pending_payout;
+mod pod;
+mod program_config;
+mod rate_card;
+mod receipt;
+mod redeemed_voucher;
+mod render_submission;
+mod stake;
@@ -0,0 +1,189 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    num_enum::{IntoPrimitive, TryFromPrimitive},
+    solana_program::{
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Status of a render node
+#[derive(Clone, Copy, Debug, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum NodeStatus {
+    /// The node can be paid
+    Active,
+    /// The node has been suspended by the program config admin and can't be paid
+    Suspended,
+}
+
+impl Default for NodeStatus {
+    fn default() -> Self {
+        NodeStatus::Active
+    }
+}
+
+/// Render node state
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Node {
+    /// Account type, must be NodeV1 currently
+    pub account_type: AccountType,
+    /// Wallet of the node operator
+    pub operator: Pubkey,
+    /// RNDR token account the node is paid to
+    pub payout_token: Pubkey,
+    /// Priority tier of the node, 0 if the node is untiered
+    pub tier: u8,
+    /// Status of the node
+    pub status: NodeStatus,
+    /// Number of payouts credited to the node. Payouts are credited when the node is provided
+    /// with them, which escrows requiring registered nodes enforce
+    pub payout_count: u64,
+    /// Total amount of tokens credited to the node
+    pub earned: u64,
+    /// Bump seed of the node program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the node
+    pub rent_payer: Pubkey,
+}
+
+impl Node {
+    /// Create a node
+    pub fn new(params: InitNodeParams) -> Self {
+        let mut node = Self::default();
+        Self::init(&mut node, params);
+        node
+    }
+
+    /// Initialize a node
+    pub fn init(&mut self, params: InitNodeParams) {
+        self.account_type = AccountType::NodeV1;
+        self.operator = params.operator;
+        self.payout_token = params.payout_token;
+        self.tier = params.tier;
+        self.status = NodeStatus::Active;
+        self.payout_count = 0;
+        self.earned = 0;
+        self.bump_seed = params.bump_seed;
+        self.rent_payer = params.rent_payer;
+    }
+}
+
+/// Initialize a node
+pub struct InitNodeParams {
+    /// Wallet of the node operator
+    pub operator: Pubkey,
+    /// RNDR token account the node is paid to
+    pub payout_token: Pubkey,
+    /// Priority tier of the node, 0 if the node is untiered
+    pub tier: u8,
+    /// Bump seed of the node program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the node
+    pub rent_payer: Pubkey,
+}
+
+impl Sealed for Node {}
+
+impl IsInitialized for Node {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const NODE_LEN: usize = 116; // 1 + 32 + 32 + 1 + 1 + 8 + 8 + 1 + 32
+impl Pack for Node {
+    const LEN: usize = NODE_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, NODE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            operator,
+            payout_token,
+            tier,
+            status,
+            payout_count,
+            earned,
+            bump_seed,
+            rent_payer,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            1,
+            1,
+            8,
+            8,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        operator.copy_from_slice(&self.operator.to_bytes());
+        payout_token.copy_from_slice(&self.payout_token.to_bytes());
+        *tier = self.tier.to_le_bytes();
+        *status = u8::from(self.status).to_le_bytes();
+        *payout_count = self.payout_count.to_le_bytes();
+        *earned = self.earned.to_le_bytes();
+        *bump_seed = self.bump_seed.to_le_bytes();
+        rent_payer.copy_from_slice(&self.rent_payer.to_bytes());
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, NODE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            operator,
+            payout_token,
+            tier,
+            status,
+            payout_count,
+            earned,
+            bump_seed,
+            rent_payer,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            1,
+            1,
+            8,
+            8,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if account_type != AccountType::NodeV1 {
+            msg!("Node account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let status = NodeStatus::try_from(u8::from_le_bytes(*status)).map_err(|_| {
+            msg!("Node status is invalid");
+            ProgramError::InvalidAccountData
+        })?;
+
+        Ok(Self {
+            account_type,
+            operator: Pubkey::new_from_array(*operator),
+            payout_token: Pubkey::new_from_array(*payout_token),
+            tier: u8::from_le_bytes(*tier),
+            status,
+            payout_count: u64::from_le_bytes(*payout_count),
+            earned: u64::from_le_bytes(*earned),
+            bump_seed: u8::from_le_bytes(*bump_seed),
+            rent_payer: Pubkey::new_from_array(*rent_payer),
+        })
+    }
+}
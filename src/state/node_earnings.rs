@@ -0,0 +1,184 @@
+use {
+    super::*,
+    crate::error::RNDRError,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::Slot,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Earnings ledger of a destination owner in an escrow. Only payouts made with `DisburseFunds`
+/// are recorded, while a registered render node counts the payouts of every path it is provided to
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeEarnings {
+    /// Account type, must be NodeEarningsV1 currently
+    pub account_type: AccountType,
+    /// Escrow the earnings were disbursed from
+    pub escrow: Pubkey,
+    /// Owner of the destination token accounts the earnings were disbursed to
+    pub owner: Pubkey,
+    /// Total amount of tokens earned
+    pub earned: u64,
+    /// Number of payouts received
+    pub payout_count: u64,
+    /// Slot of the first payout
+    pub first_payout_slot: Slot,
+    /// Slot of the last payout
+    pub last_payout_slot: Slot,
+    /// Bump seed of the node earnings program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the node earnings
+    pub rent_payer: Pubkey,
+}
+
+impl NodeEarnings {
+    /// Create a node earnings ledger
+    pub fn new(params: InitNodeEarningsParams) -> Self {
+        let mut node_earnings = Self::default();
+        Self::init(&mut node_earnings, params);
+        node_earnings
+    }
+
+    /// Initialize a node earnings ledger
+    pub fn init(&mut self, params: InitNodeEarningsParams) {
+        self.account_type = AccountType::NodeEarningsV1;
+        self.escrow = params.escrow;
+        self.owner = params.owner;
+        self.earned = 0;
+        self.payout_count = 0;
+        self.first_payout_slot = 0;
+        self.last_payout_slot = 0;
+        self.bump_seed = params.bump_seed;
+        self.rent_payer = params.rent_payer;
+    }
+
+    /// Record a payout
+    pub fn record_payout(&mut self, amount: u64, slot: Slot) -> Result<(), ProgramError> {
+        if self.payout_count == 0 {
+            self.first_payout_slot = slot;
+        }
+        self.last_payout_slot = slot;
+        self.earned = self
+            .earned
+            .checked_add(amount)
+            .ok_or(RNDRError::MathError)?;
+        self.payout_count = self
+            .payout_count
+            .checked_add(1)
+            .ok_or(RNDRError::MathError)?;
+        Ok(())
+    }
+}
+
+/// Initialize a node earnings ledger
+pub struct InitNodeEarningsParams {
+    /// Escrow the earnings are disbursed from
+    pub escrow: Pubkey,
+    /// Owner of the destination token accounts the earnings are disbursed to
+    pub owner: Pubkey,
+    /// Bump seed of the node earnings program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the node earnings
+    pub rent_payer: Pubkey,
+}
+
+impl Sealed for NodeEarnings {}
+
+impl IsInitialized for NodeEarnings {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const NODE_EARNINGS_LEN: usize = 130; // 1 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + 32
+impl Pack for NodeEarnings {
+    const LEN: usize = NODE_EARNINGS_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, NODE_EARNINGS_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            owner,
+            earned,
+            payout_count,
+            first_payout_slot,
+            last_payout_slot,
+            bump_seed,
+            rent_payer,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            8,
+            8,
+            8,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        owner.copy_from_slice(&self.owner.to_bytes());
+        *earned = self.earned.to_le_bytes();
+        *payout_count = self.payout_count.to_le_bytes();
+        *first_payout_slot = self.first_payout_slot.to_le_bytes();
+        *last_payout_slot = self.last_payout_slot.to_le_bytes();
+        *bump_seed = self.bump_seed.to_le_bytes();
+        rent_payer.copy_from_slice(&self.rent_payer.to_bytes());
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, NODE_EARNINGS_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            owner,
+            earned,
+            payout_count,
+            first_payout_slot,
+            last_payout_slot,
+            bump_seed,
+            rent_payer,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            8,
+            8,
+            8,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if account_type != AccountType::NodeEarningsV1 {
+            msg!("Node earnings account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            owner: Pubkey::new_from_array(*owner),
+            earned: u64::from_le_bytes(*earned),
+            payout_count: u64::from_le_bytes(*payout_count),
+            first_payout_slot: u64::from_le_bytes(*first_payout_slot),
+            last_payout_slot: u64::from_le_bytes(*last_payout_slot),
+            bump_seed: u8::from_le_bytes(*bump_seed),
+            rent_payer: Pubkey::new_from_array(*rent_payer),
+        })
+    }
+}
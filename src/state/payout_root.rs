@@ -0,0 +1,196 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::UnixTimestamp,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Maximum number of leaves in a payout root, bounded by the size of its claimed bitmap
+pub const MAX_PAYOUT_LEAVES: u32 = 65_536;
+
+/// Payout root state, followed in the account by a bitmap of claimed leaves
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PayoutRoot {
+    /// Account type, must be PayoutRootV1 currently
+    pub account_type: AccountType,
+    /// Escrow the payouts are made from
+    pub escrow: Pubkey,
+    /// Job the payouts are debited from
+    pub job: Pubkey,
+    /// Merkle root of the payout leaves
+    pub root: [u8; 32],
+    /// Total amount of tokens reserved for the payouts
+    pub amount: u64,
+    /// Amount of tokens claimed so far
+    pub claimed_amount: u64,
+    /// Number of leaves in the merkle tree
+    pub leaf_count: u32,
+    /// Time after which unclaimed tokens can be returned to the job
+    pub expiry: UnixTimestamp,
+    /// Account that paid rent for the payout root
+    pub rent_payer: Pubkey,
+}
+
+impl PayoutRoot {
+    /// Create a payout root
+    pub fn new(params: InitPayoutRootParams) -> Self {
+        let mut payout_root = Self::default();
+        Self::init(&mut payout_root, params);
+        payout_root
+    }
+
+    /// Initialize a payout root
+    pub fn init(&mut self, params: InitPayoutRootParams) {
+        self.account_type = AccountType::PayoutRootV1;
+        self.escrow = params.escrow;
+        self.job = params.job;
+        self.root = params.root;
+        self.amount = params.amount;
+        self.claimed_amount = 0;
+        self.leaf_count = params.leaf_count;
+        self.expiry = params.expiry;
+        self.rent_payer = params.rent_payer;
+    }
+
+    /// Length of the claimed bitmap for a number of leaves
+    // usize::div_ceil isn't available on the toolchain the program is built with
+    #[allow(unknown_lints, clippy::manual_div_ceil)]
+    pub fn bitmap_len(leaf_count: u32) -> usize {
+        (leaf_count as usize + 7) / 8
+    }
+
+    /// Length of a payout root account for a number of leaves
+    pub fn account_len(leaf_count: u32) -> usize {
+        Self::LEN + Self::bitmap_len(leaf_count)
+    }
+
+    /// Check if a leaf has been claimed in the bitmap
+    pub fn is_claimed(bitmap: &[u8], index: u32) -> bool {
+        bitmap[index as usize / 8] & (1 << (index % 8)) != 0
+    }
+
+    /// Mark a leaf as claimed in the bitmap
+    pub fn set_claimed(bitmap: &mut [u8], index: u32) {
+        bitmap[index as usize / 8] |= 1 << (index % 8);
+    }
+}
+
+/// Initialize a payout root
+pub struct InitPayoutRootParams {
+    /// Escrow the payouts are made from
+    pub escrow: Pubkey,
+    /// Job the payouts are debited from
+    pub job: Pubkey,
+    /// Merkle root of the payout leaves
+    pub root: [u8; 32],
+    /// Total amount of tokens reserved for the payouts
+    pub amount: u64,
+    /// Number of leaves in the merkle tree
+    pub leaf_count: u32,
+    /// Time after which unclaimed tokens can be returned to the job
+    pub expiry: UnixTimestamp,
+    /// Account that paid rent for the payout root
+    pub rent_payer: Pubkey,
+}
+
+impl Sealed for PayoutRoot {}
+
+impl IsInitialized for PayoutRoot {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const PAYOUT_ROOT_LEN: usize = 157; // 1 + 32 + 32 + 32 + 8 + 8 + 4 + 8 + 32
+impl Pack for PayoutRoot {
+    const LEN: usize = PAYOUT_ROOT_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, PAYOUT_ROOT_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            job,
+            root,
+            amount,
+            claimed_amount,
+            leaf_count,
+            expiry,
+            rent_payer,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            32,
+            8,
+            8,
+            4,
+            8,
+            PUBKEY_BYTES
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        job.copy_from_slice(&self.job.to_bytes());
+        root.copy_from_slice(&self.root);
+        *amount = self.amount.to_le_bytes();
+        *claimed_amount = self.claimed_amount.to_le_bytes();
+        *leaf_count = self.leaf_count.to_le_bytes();
+        *expiry = self.expiry.to_le_bytes();
+        rent_payer.copy_from_slice(&self.rent_payer.to_bytes());
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, PAYOUT_ROOT_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            job,
+            root,
+            amount,
+            claimed_amount,
+            leaf_count,
+            expiry,
+            rent_payer,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            32,
+            8,
+            8,
+            4,
+            8,
+            PUBKEY_BYTES
+        ];
+
+        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if account_type != AccountType::PayoutRootV1 {
+            msg!("Payout root account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            job: Pubkey::new_from_array(*job),
+            root: *root,
+            amount: u64::from_le_bytes(*amount),
+            claimed_amount: u64::from_le_bytes(*claimed_amount),
+            leaf_count: u32::from_le_bytes(*leaf_count),
+            expiry: i64::from_le_bytes(*expiry),
+            rent_payer: Pubkey::new_from_array(*rent_payer),
+        })
+    }
+}
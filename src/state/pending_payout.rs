@@ -0,0 +1,176 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::UnixTimestamp,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Seconds the job authority has to challenge a pending payout
+pub const PAYOUT_CHALLENGE_PERIOD: i64 = 24 * 60 * 60;
+
+/// Pending payout state
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PendingPayout {
+    /// Account type, must be PendingPayoutV1 currently
+    pub account_type: AccountType,
+    /// Escrow the job belongs to
+    pub escrow: Pubkey,
+    /// Job the payout is reserved on
+    pub job: Pubkey,
+    /// Destination RNDR token account
+    pub destination: Pubkey,
+    /// Amount of tokens to pay out
+    pub amount: u64,
+    /// Time after which the payout can be finalized
+    pub finalize_after: UnixTimestamp,
+    /// Nonce distinguishing pending payouts of the same job
+    pub nonce: u64,
+    /// Bump seed of the pending payout program derived address
+    pub bump_seed: u8,
+    /// Account that paid the rent of the pending payout
+    pub rent_payer: Pubkey,
+}
+
+impl PendingPayout {
+    /// Create a pending payout
+    pub fn new(params: InitPendingPayoutParams) -> Self {
+        let mut pending_payout = Self::default();
+        Self::init(&mut pending_payout, params);
+        pending_payout
+    }
+
+    /// Initialize a pending payout
+    pub fn init(&mut self, params: InitPendingPayoutParams) {
+        self.account_type = AccountType::PendingPayoutV1;
+        self.escrow = params.escrow;
+        self.job = params.job;
+        self.destination = params.destination;
+        self.amount = params.amount;
+        self.finalize_after = params.finalize_after;
+        self.nonce = params.nonce;
+        self.bump_seed = params.bump_seed;
+        self.rent_payer = params.rent_payer;
+    }
+}
+
+/// Initialize a pending payout
+pub struct InitPendingPayoutParams {
+    /// Escrow the job belongs to
+    pub escrow: Pubkey,
+    /// Job the payout is reserved on
+    pub job: Pubkey,
+    /// Destination RNDR token account
+    pub destination: Pubkey,
+    /// Amount of tokens to pay out
+    pub amount: u64,
+    /// Time after which the payout can be finalized
+    pub finalize_after: UnixTimestamp,
+    /// Nonce distinguishing pending payouts of the same job
+    pub nonce: u64,
+    /// Bump seed of the pending payout program derived address
+    pub bump_seed: u8,
+    /// Account that paid the rent of the pending payout
+    pub rent_payer: Pubkey,
+}
+
+impl Sealed for PendingPayout {}
+
+impl IsInitialized for PendingPayout {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const PENDING_PAYOUT_LEN: usize = 154; // 1 + 32 + 32 + 32 + 8 + 8 + 8 + 1 + 32
+impl Pack for PendingPayout {
+    const LEN: usize = PENDING_PAYOUT_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, PENDING_PAYOUT_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            job,
+            destination,
+            amount,
+            finalize_after,
+            nonce,
+            bump_seed,
+            rent_payer,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            8,
+            8,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        job.copy_from_slice(&self.job.to_bytes());
+        destination.copy_from_slice(&self.destination.to_bytes());
+        *amount = self.amount.to_le_bytes();
+        *finalize_after = self.finalize_after.to_le_bytes();
+        *nonce = self.nonce.to_le_bytes();
+        *bump_seed = self.bump_seed.to_le_bytes();
+        rent_payer.copy_from_slice(&self.rent_payer.to_bytes());
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, PENDING_PAYOUT_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            job,
+            destination,
+            amount,
+            finalize_after,
+            nonce,
+            bump_seed,
+            rent_payer,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            8,
+            8,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if account_type != AccountType::PendingPayoutV1 {
+            msg!("Pending payout account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            job: Pubkey::new_from_array(*job),
+            destination: Pubkey::new_from_array(*destination),
+            amount: u64::from_le_bytes(*amount),
+            finalize_after: i64::from_le_bytes(*finalize_after),
+            nonce: u64::from_le_bytes(*nonce),
+            bump_seed: u8::from_le_bytes(*bump_seed),
+            rent_payer: Pubkey::new_from_array(*rent_payer),
+        })
+    }
+}
@@ -0,0 +1,108 @@
+use {
+    super::*,
+    bytemuck::{Pod, Zeroable},
+    solana_program::{msg, program_error::ProgramError, pubkey::Pubkey},
+    std::mem::size_of,
+};
+
+/// State read and written in place in account data, without copying it
+pub trait PodState: Pod {
+    /// Account type the state must have
+    const ACCOUNT_TYPE: AccountType;
+
+    /// Borrow the state in account data
+    fn load(data: &[u8]) -> Result<&Self, ProgramError> {
+        Self::check_account_type(data)?;
+        bytemuck::try_from_bytes(&data[..size_of::<Self>()])
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// Mutably borrow the state in account data
+    fn load_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        Self::check_account_type(data)?;
+        bytemuck::try_from_bytes_mut(&mut data[..size_of::<Self>()])
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// Check the account data is large enough for the state and has its account type
+    fn check_account_type(data: &[u8]) -> Result<(), ProgramError> {
+        if data.len() < size_of::<Self>() {
+            msg!("Account data is too small");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if data[0] != u8::from(Self::ACCOUNT_TYPE) {
+            msg!("Account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
+
+/// Little-endian u32 with an alignment of 1
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+#[repr(transparent)]
+pub struct PodU32([u8; 4]);
+
+impl From<u32> for PodU32 {
+    fn from(value: u32) -> Self {
+        Self(value.to_le_bytes())
+    }
+}
+
+impl From<PodU32> for u32 {
+    fn from(value: PodU32) -> Self {
+        Self::from_le_bytes(value.0)
+    }
+}
+
+/// Little-endian u64 with an alignment of 1
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+#[repr(transparent)]
+pub struct PodU64([u8; 8]);
+
+impl From<u64> for PodU64 {
+    fn from(value: u64) -> Self {
+        Self(value.to_le_bytes())
+    }
+}
+
+impl From<PodU64> for u64 {
+    fn from(value: PodU64) -> Self {
+        Self::from_le_bytes(value.0)
+    }
+}
+
+impl AsRef<[u8]> for PodU64 {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Pubkey with an alignment of 1
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+#[repr(transparent)]
+pub struct PodPubkey([u8; 32]);
+
+impl From<Pubkey> for PodPubkey {
+    fn from(value: Pubkey) -> Self {
+        Self(value.to_bytes())
+    }
+}
+
+impl From<PodPubkey> for Pubkey {
+    fn from(value: PodPubkey) -> Self {
+        Self::new_from_array(value.0)
+    }
+}
+
+impl PartialEq<Pubkey> for PodPubkey {
+    fn eq(&self, other: &Pubkey) -> bool {
+        self.0 == other.to_bytes()
+    }
+}
+
+impl AsRef<[u8]> for PodPubkey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
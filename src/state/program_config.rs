@@ -0,0 +1,202 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Maximum number of token mints escrows can be created for
+pub const MAX_ALLOWED_MINTS: usize = 8;
+/// Maximum number of token programs escrows can be created for
+pub const MAX_ALLOWED_TOKEN_PROGRAMS: usize = 2;
+
+/// Program-wide configuration state
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProgramConfig {
+    /// Account type, must be ProgramConfigV1 currently
+    pub account_type: AccountType,
+    /// Admin authority that can update the config and initialize escrows
+    pub admin: Pubkey,
+    /// Token mints escrows can be created for
+    pub allowed_mints: Vec<Pubkey>,
+    /// Token programs escrows can be created for
+    pub allowed_token_programs: Vec<Pubkey>,
+    /// Owner of new escrows initialized without an owner
+    pub default_escrow_owner: Pubkey,
+    /// Owner of the token accounts slashed stakes can be sent to, none if default
+    pub treasury: Pubkey,
+    /// Bump seed of the program config program derived address
+    pub bump_seed: u8,
+}
+
+impl ProgramConfig {
+    /// Create a program config
+    pub fn new(params: InitProgramConfigParams) -> Self {
+        let mut program_config = Self::default();
+        Self::init(&mut program_config, params);
+        program_config
+    }
+
+    /// Initialize a program config
+    pub fn init(&mut self, params: InitProgramConfigParams) {
+        self.account_type = AccountType::ProgramConfigV1;
+        self.admin = params.admin;
+        self.allowed_mints = params.allowed_mints;
+        self.allowed_token_programs = params.allowed_token_programs;
+        self.default_escrow_owner = params.default_escrow_owner;
+        self.treasury = params.treasury;
+        self.bump_seed = params.bump_seed;
+    }
+
+    /// Check if escrows can be created for a token mint
+    pub fn is_mint_allowed(&self, token_mint: &Pubkey) -> bool {
+        self.allowed_mints.contains(token_mint)
+    }
+
+    /// Check if escrows can be created for a token program
+    pub fn is_token_program_allowed(&self, token_program: &Pubkey) -> bool {
+        self.allowed_token_programs.contains(token_program)
+    }
+}
+
+/// Initialize a program config
+pub struct InitProgramConfigParams {
+    /// Admin authority that can update the config and initialize escrows
+    pub admin: Pubkey,
+    /// Token mints escrows can be created for
+    pub allowed_mints: Vec<Pubkey>,
+    /// Token programs escrows can be created for
+    pub allowed_token_programs: Vec<Pubkey>,
+    /// Owner of new escrows initialized without an owner
+    pub default_escrow_owner: Pubkey,
+    /// Owner of the token accounts slashed stakes can be sent to, none if default
+    pub treasury: Pubkey,
+    /// Bump seed of the program config program derived address
+    pub bump_seed: u8,
+}
+
+impl Sealed for ProgramConfig {}
+
+impl IsInitialized for ProgramConfig {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const PROGRAM_CONFIG_LEN: usize = 420; // 1 + 32 + 1 + 32 * 8 + 1 + 32 * 2 + 32 + 32 + 1
+impl Pack for ProgramConfig {
+    const LEN: usize = PROGRAM_CONFIG_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, PROGRAM_CONFIG_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            admin,
+            allowed_mints_len,
+            allowed_mints,
+            allowed_token_programs_len,
+            allowed_token_programs,
+            default_escrow_owner,
+            treasury,
+            bump_seed,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            1,
+            PUBKEY_BYTES * MAX_ALLOWED_MINTS,
+            1,
+            PUBKEY_BYTES * MAX_ALLOWED_TOKEN_PROGRAMS,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            1
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        admin.copy_from_slice(&self.admin.to_bytes());
+        *allowed_mints_len = (self.allowed_mints.len() as u8).to_le_bytes();
+        pack_pubkeys(&self.allowed_mints, allowed_mints);
+        *allowed_token_programs_len = (self.allowed_token_programs.len() as u8).to_le_bytes();
+        pack_pubkeys(&self.allowed_token_programs, allowed_token_programs);
+        default_escrow_owner.copy_from_slice(&self.default_escrow_owner.to_bytes());
+        treasury.copy_from_slice(&self.treasury.to_bytes());
+        *bump_seed = self.bump_seed.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, PROGRAM_CONFIG_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            admin,
+            allowed_mints_len,
+            allowed_mints,
+            allowed_token_programs_len,
+            allowed_token_programs,
+            default_escrow_owner,
+            treasury,
+            bump_seed,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            1,
+            PUBKEY_BYTES * MAX_ALLOWED_MINTS,
+            1,
+            PUBKEY_BYTES * MAX_ALLOWED_TOKEN_PROGRAMS,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            1
+        ];
+
+        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if account_type != AccountType::ProgramConfigV1 {
+            msg!("Program config account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let allowed_mints_len = u8::from_le_bytes(*allowed_mints_len) as usize;
+        let allowed_token_programs_len = u8::from_le_bytes(*allowed_token_programs_len) as usize;
+        if allowed_mints_len > MAX_ALLOWED_MINTS
+            || allowed_token_programs_len > MAX_ALLOWED_TOKEN_PROGRAMS
+        {
+            msg!("Program config allowlist length is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            admin: Pubkey::new_from_array(*admin),
+            allowed_mints: unpack_pubkeys(allowed_mints, allowed_mints_len),
+            allowed_token_programs: unpack_pubkeys(
+                allowed_token_programs,
+                allowed_token_programs_len,
+            ),
+            default_escrow_owner: Pubkey::new_from_array(*default_escrow_owner),
+            treasury: Pubkey::new_from_array(*treasury),
+            bump_seed: u8::from_le_bytes(*bump_seed),
+        })
+    }
+}
+
+fn pack_pubkeys(pubkeys: &[Pubkey], output: &mut [u8]) {
+    output.fill(0);
+    for (pubkey, chunk) in pubkeys.iter().zip(output.chunks_exact_mut(PUBKEY_BYTES)) {
+        chunk.copy_from_slice(pubkey.as_ref());
+    }
+}
+
+fn unpack_pubkeys(input: &[u8], len: usize) -> Vec<Pubkey> {
+    input
+        .chunks_exact(PUBKEY_BYTES)
+        .take(len)
+        .map(Pubkey::new)
+        .collect()
+}
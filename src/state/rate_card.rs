@@ -0,0 +1,116 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Rate card state
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RateCard {
+    /// Account type, must be RateCardV1 currently
+    pub account_type: AccountType,
+    /// Job the rate card prices
+    pub job: Pubkey,
+    /// Amount of tokens paid per frame
+    pub price_per_frame: u64,
+    /// Total number of frames of the job
+    pub total_frames: u32,
+    /// Number of frames already paid
+    pub frames_paid: u32,
+    /// Bump seed of the rate card program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the rate card
+    pub rent_payer: Pubkey,
+}
+
+impl RateCard {
+    /// Create a rate card
+    pub fn new(params: InitRateCardParams) -> Self {
+        let mut rate_card = Self::default();
+        Self::init(&mut rate_card, params);
+        rate_card
+    }
+
+    /// Initialize a rate card
+    pub fn init(&mut self, params: InitRateCardParams) {
+        self.account_type = AccountType::RateCardV1;
+        self.job = params.job;
+        self.price_per_frame = params.price_per_frame;
+        self.total_frames = params.total_frames;
+        self.frames_paid = 0;
+        self.bump_seed = params.bump_seed;
+        self.rent_payer = params.rent_payer;
+    }
+}
+
+/// Initialize a rate card
+pub struct InitRateCardParams {
+    /// Job the rate card prices
+    pub job: Pubkey,
+    /// Amount of tokens paid per frame
+    pub price_per_frame: u64,
+    /// Total number of frames of the job
+    pub total_frames: u32,
+    /// Bump seed of the rate card program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the rate card
+    pub rent_payer: Pubkey,
+}
+
+impl Sealed for RateCard {}
+
+impl IsInitialized for RateCard {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const RATE_CARD_LEN: usize = 82; // 1 + 32 + 8 + 4 + 4 + 1 + 32
+impl Pack for RateCard {
+    const LEN: usize = RATE_CARD_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, RATE_CARD_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, job, price_per_frame, total_frames, frames_paid, bump_seed, rent_payer) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, 8, 4, 4, 1, PUBKEY_BYTES];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        job.copy_from_slice(&self.job.to_bytes());
+        *price_per_frame = self.price_per_frame.to_le_bytes();
+        *total_frames = self.total_frames.to_le_bytes();
+        *frames_paid = self.frames_paid.to_le_bytes();
+        *bump_seed = self.bump_seed.to_le_bytes();
+        rent_payer.copy_from_slice(&self.rent_payer.to_bytes());
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, RATE_CARD_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, job, price_per_frame, total_frames, frames_paid, bump_seed, rent_payer) =
+            array_refs![input, 1, PUBKEY_BYTES, 8, 4, 4, 1, PUBKEY_BYTES];
+
+        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if account_type != AccountType::RateCardV1 {
+            msg!("Rate card account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            job: Pubkey::new_from_array(*job),
+            price_per_frame: u64::from_le_bytes(*price_per_frame),
+            total_frames: u32::from_le_bytes(*total_frames),
+            frames_paid: u32::from_le_bytes(*frames_paid),
+            bump_seed: u8::from_le_bytes(*bump_seed),
+            rent_payer: Pubkey::new_from_array(*rent_payer),
+        })
+    }
+}
@@ -0,0 +1,184 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::Slot,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Receipt state, an immutable record of a payout disbursed from a job
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Receipt {
+    /// Account type, must be ReceiptV1 currently
+    pub account_type: AccountType,
+    /// Job the payout was disbursed from
+    pub job: Pubkey,
+    /// Index of the payout in the payouts of the job
+    pub payout_index: u64,
+    /// Token account the payout was disbursed to
+    pub destination: Pubkey,
+    /// Amount of tokens disbursed
+    pub amount: u64,
+    /// Slot of the payout
+    pub slot: Slot,
+    /// Escrow owner that disbursed the payout
+    pub disburser: Pubkey,
+    /// External reference of the payout, such as an invoice hash, zeroed if none
+    pub reference: [u8; 32],
+    /// Bump seed of the receipt program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the receipt
+    pub rent_payer: Pubkey,
+}
+
+impl Receipt {
+    /// Create a receipt
+    pub fn new(params: InitReceiptParams) -> Self {
+        let mut receipt = Self::default();
+        Self::init(&mut receipt, params);
+        receipt
+    }
+
+    /// Initialize a receipt
+    pub fn init(&mut self, params: InitReceiptParams) {
+        self.account_type = AccountType::ReceiptV1;
+        self.job = params.job;
+        self.payout_index = params.payout_index;
+        self.destination = params.destination;
+        self.amount = params.amount;
+        self.slot = params.slot;
+        self.disburser = params.disburser;
+        self.reference = params.reference;
+        self.bump_seed = params.bump_seed;
+        self.rent_payer = params.rent_payer;
+    }
+}
+
+/// Initialize a receipt
+pub struct InitReceiptParams {
+    /// Job the payout was disbursed from
+    pub job: Pubkey,
+    /// Index of the payout in the payouts of the job
+    pub payout_index: u64,
+    /// Token account the payout was disbursed to
+    pub destination: Pubkey,
+    /// Amount of tokens disbursed
+    pub amount: u64,
+    /// Slot of the payout
+    pub slot: Slot,
+    /// Escrow owner that disbursed the payout
+    pub disburser: Pubkey,
+    /// External reference of the payout, zeroed if none
+    pub reference: [u8; 32],
+    /// Bump seed of the receipt program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the receipt
+    pub rent_payer: Pubkey,
+}
+
+impl Sealed for Receipt {}
+
+impl IsInitialized for Receipt {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const RECEIPT_LEN: usize = 186; // 1 + 32 + 8 + 32 + 8 + 8 + 32 + 32 + 1 + 32
+impl Pack for Receipt {
+    const LEN: usize = RECEIPT_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, RECEIPT_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            job,
+            payout_index,
+            destination,
+            amount,
+            slot,
+            disburser,
+            reference,
+            bump_seed,
+            rent_payer,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            8,
+            PUBKEY_BYTES,
+            8,
+            8,
+            PUBKEY_BYTES,
+            32,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        job.copy_from_slice(&self.job.to_bytes());
+        *payout_index = self.payout_index.to_le_bytes();
+        destination.copy_from_slice(&self.destination.to_bytes());
+        *amount = self.amount.to_le_bytes();
+        *slot = self.slot.to_le_bytes();
+        disburser.copy_from_slice(&self.disburser.to_bytes());
+        reference.copy_from_slice(&self.reference);
+        *bump_seed = self.bump_seed.to_le_bytes();
+        rent_payer.copy_from_slice(&self.rent_payer.to_bytes());
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, RECEIPT_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            job,
+            payout_index,
+            destination,
+            amount,
+            slot,
+            disburser,
+            reference,
+            bump_seed,
+            rent_payer,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            8,
+            PUBKEY_BYTES,
+            8,
+            8,
+            PUBKEY_BYTES,
+            32,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if account_type != AccountType::ReceiptV1 {
+            msg!("Receipt account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            job: Pubkey::new_from_array(*job),
+            payout_index: u64::from_le_bytes(*payout_index),
+            destination: Pubkey::new_from_array(*destination),
+            amount: u64::from_le_bytes(*amount),
+            slot: u64::from_le_bytes(*slot),
+            disburser: Pubkey::new_from_array(*disburser),
+            reference: *reference,
+            bump_seed: u8::from_le_bytes(*bump_seed),
+            rent_payer: Pubkey::new_from_array(*rent_payer),
+        })
+    }
+}
@@ -0,0 +1,88 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Redeemed voucher state, preventing a voucher nonce from being replayed
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RedeemedVoucher {
+    /// Account type, must be RedeemedVoucherV1 currently
+    pub account_type: AccountType,
+    /// Escrow the voucher was redeemed from
+    pub escrow: Pubkey,
+    /// Nonce of the voucher
+    pub nonce: u64,
+}
+
+impl RedeemedVoucher {
+    /// Create a redeemed voucher
+    pub fn new(params: InitRedeemedVoucherParams) -> Self {
+        let mut redeemed_voucher = Self::default();
+        Self::init(&mut redeemed_voucher, params);
+        redeemed_voucher
+    }
+
+    /// Initialize a redeemed voucher
+    pub fn init(&mut self, params: InitRedeemedVoucherParams) {
+        self.account_type = AccountType::RedeemedVoucherV1;
+        self.escrow = params.escrow;
+        self.nonce = params.nonce;
+    }
+}
+
+/// Initialize a redeemed voucher
+pub struct InitRedeemedVoucherParams {
+    /// Escrow the voucher was redeemed from
+    pub escrow: Pubkey,
+    /// Nonce of the voucher
+    pub nonce: u64,
+}
+
+impl Sealed for RedeemedVoucher {}
+
+impl IsInitialized for RedeemedVoucher {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const REDEEMED_VOUCHER_LEN: usize = 41; // 1 + 32 + 8
+impl Pack for RedeemedVoucher {
+    const LEN: usize = REDEEMED_VOUCHER_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, REDEEMED_VOUCHER_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, nonce) = mut_array_refs![output, 1, PUBKEY_BYTES, 8];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        *nonce = self.nonce.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, REDEEMED_VOUCHER_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, nonce) = array_refs![input, 1, PUBKEY_BYTES, 8];
+
+        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if account_type != AccountType::RedeemedVoucherV1 {
+            msg!("Redeemed voucher account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            nonce: u64::from_le_bytes(*nonce),
+        })
+    }
+}
@@ -0,0 +1,224 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    num_enum::{IntoPrimitive, TryFromPrimitive},
+    solana_program::{
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Review status of a render submission
+#[derive(Clone, Copy, Debug, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum SubmissionStatus {
+    /// Waiting for the job authority to review it
+    Pending,
+    /// Accepted by the job authority, the node can be paid up to its price
+    Accepted,
+    /// Rejected by the job authority, the node can resubmit
+    Rejected,
+}
+
+impl Default for SubmissionStatus {
+    fn default() -> Self {
+        SubmissionStatus::Pending
+    }
+}
+
+/// Render submission state
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RenderSubmission {
+    /// Account type, must be RenderSubmissionV1 currently
+    pub account_type: AccountType,
+    /// Job the frames were rendered for
+    pub job: Pubkey,
+    /// Render node that submitted the frames
+    pub node: Pubkey,
+    /// Hash of the rendered frames
+    pub frames_hash: [u8; 32],
+    /// First frame rendered
+    pub frame_start: u32,
+    /// Last frame rendered
+    pub frame_end: u32,
+    /// Price quoted by the node for the frames
+    pub price: u64,
+    /// Review status of the submission
+    pub status: SubmissionStatus,
+    /// Amount of tokens paid against the submission
+    pub paid: u64,
+    /// Bump seed of the render submission program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the render submission
+    pub rent_payer: Pubkey,
+}
+
+impl RenderSubmission {
+    /// Create a render submission
+    pub fn new(params: InitRenderSubmissionParams) -> Self {
+        let mut render_submission = Self::default();
+        Self::init(&mut render_submission, params);
+        render_submission
+    }
+
+    /// Initialize a render submission
+    pub fn init(&mut self, params: InitRenderSubmissionParams) {
+        self.account_type = AccountType::RenderSubmissionV1;
+        self.job = params.job;
+        self.node = params.node;
+        self.bump_seed = params.bump_seed;
+        self.rent_payer = params.rent_payer;
+        self.submit(
+            params.frames_hash,
+            params.frame_start,
+            params.frame_end,
+            params.price,
+        );
+    }
+
+    /// Replace the submitted frames and put the submission back up for review
+    pub fn submit(&mut self, frames_hash: [u8; 32], frame_start: u32, frame_end: u32, price: u64) {
+        self.frames_hash = frames_hash;
+        self.frame_start = frame_start;
+        self.frame_end = frame_end;
+        self.price = price;
+        self.status = SubmissionStatus::Pending;
+        self.paid = 0;
+    }
+}
+
+/// Initialize a render submission
+pub struct InitRenderSubmissionParams {
+    /// Job the frames were rendered for
+    pub job: Pubkey,
+    /// Render node that submitted the frames
+    pub node: Pubkey,
+    /// Hash of the rendered frames
+    pub frames_hash: [u8; 32],
+    /// First frame rendered
+    pub frame_start: u32,
+    /// Last frame rendered
+    pub frame_end: u32,
+    /// Price quoted by the node for the frames
+    pub price: u64,
+    /// Bump seed of the render submission program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the render submission
+    pub rent_payer: Pubkey,
+}
+
+impl Sealed for RenderSubmission {}
+
+impl IsInitialized for RenderSubmission {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const RENDER_SUBMISSION_LEN: usize = 155; // 1 + 32 + 32 + 32 + 4 + 4 + 8 + 1 + 8 + 1 + 32
+impl Pack for RenderSubmission {
+    const LEN: usize = RENDER_SUBMISSION_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, RENDER_SUBMISSION_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            job,
+            node,
+            frames_hash,
+            frame_start,
+            frame_end,
+            price,
+            status,
+            paid,
+            bump_seed,
+            rent_payer,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            32,
+            4,
+            4,
+            8,
+            1,
+            8,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        job.copy_from_slice(&self.job.to_bytes());
+        node.copy_from_slice(&self.node.to_bytes());
+        frames_hash.copy_from_slice(&self.frames_hash);
+        *frame_start = self.frame_start.to_le_bytes();
+        *frame_end = self.frame_end.to_le_bytes();
+        *price = self.price.to_le_bytes();
+        *status = u8::from(self.status).to_le_bytes();
+        *paid = self.paid.to_le_bytes();
+        *bump_seed = self.bump_seed.to_le_bytes();
+        rent_payer.copy_from_slice(&self.rent_payer.to_bytes());
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, RENDER_SUBMISSION_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            job,
+            node,
+            frames_hash,
+            frame_start,
+            frame_end,
+            price,
+            status,
+            paid,
+            bump_seed,
+            rent_payer,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            32,
+            4,
+            4,
+            8,
+            1,
+            8,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if account_type != AccountType::RenderSubmissionV1 {
+            msg!("Render submission account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let status = SubmissionStatus::try_from(u8::from_le_bytes(*status)).map_err(|_| {
+            msg!("Render submission status is invalid");
+            ProgramError::InvalidAccountData
+        })?;
+
+        Ok(Self {
+            account_type,
+            job: Pubkey::new_from_array(*job),
+            node: Pubkey::new_from_array(*node),
+            frames_hash: *frames_hash,
+            frame_start: u32::from_le_bytes(*frame_start),
+            frame_end: u32::from_le_bytes(*frame_end),
+            price: u64::from_le_bytes(*price),
+            status,
+            paid: u64::from_le_bytes(*paid),
+            bump_seed: u8::from_le_bytes(*bump_seed),
+            rent_payer: Pubkey::new_from_array(*rent_payer),
+        })
+    }
+}
@@ -0,0 +1,159 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::UnixTimestamp,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Seconds a node operator waits between requesting to unstake tokens and withdrawing them
+pub const UNSTAKE_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+
+/// Stake state
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stake {
+    /// Account type, must be StakeV1 currently
+    pub account_type: AccountType,
+    /// Escrow the stake makes the node eligible for
+    pub escrow: Pubkey,
+    /// Wallet of the node operator
+    pub operator: Pubkey,
+    /// Amount of tokens staked
+    pub amount: u64,
+    /// Amount of tokens waiting for the cooldown to be withdrawn, they can still be slashed
+    pub unstaking_amount: u64,
+    /// Time after which the unstaking tokens can be withdrawn
+    pub unstake_available_at: UnixTimestamp,
+    /// Bump seed of the stake program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the stake
+    pub rent_payer: Pubkey,
+}
+
+impl Stake {
+    /// Create a stake
+    pub fn new(params: InitStakeParams) -> Self {
+        let mut stake = Self::default();
+        Self::init(&mut stake, params);
+        stake
+    }
+
+    /// Initialize a stake
+    pub fn init(&mut self, params: InitStakeParams) {
+        self.account_type = AccountType::StakeV1;
+        self.escrow = params.escrow;
+        self.operator = params.operator;
+        self.amount = 0;
+        self.unstaking_amount = 0;
+        self.unstake_available_at = 0;
+        self.bump_seed = params.bump_seed;
+        self.rent_payer = params.rent_payer;
+    }
+}
+
+/// Initialize a stake
+pub struct InitStakeParams {
+    /// Escrow the stake makes the node eligible for
+    pub escrow: Pubkey,
+    /// Wallet of the node operator
+    pub operator: Pubkey,
+    /// Bump seed of the stake program derived address
+    pub bump_seed: u8,
+    /// Account that paid rent for the stake
+    pub rent_payer: Pubkey,
+}
+
+impl Sealed for Stake {}
+
+impl IsInitialized for Stake {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const STAKE_LEN: usize = 122; // 1 + 32 + 32 + 8 + 8 + 8 + 1 + 32
+impl Pack for Stake {
+    const LEN: usize = STAKE_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, STAKE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            operator,
+            amount,
+            unstaking_amount,
+            unstake_available_at,
+            bump_seed,
+            rent_payer,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            8,
+            8,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        operator.copy_from_slice(&self.operator.to_bytes());
+        *amount = self.amount.to_le_bytes();
+        *unstaking_amount = self.unstaking_amount.to_le_bytes();
+        *unstake_available_at = self.unstake_available_at.to_le_bytes();
+        *bump_seed = self.bump_seed.to_le_bytes();
+        rent_payer.copy_from_slice(&self.rent_payer.to_bytes());
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, STAKE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            operator,
+            amount,
+            unstaking_amount,
+            unstake_available_at,
+            bump_seed,
+            rent_payer,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            8,
+            8,
+            1,
+            PUBKEY_BYTES
+        ];
+
+        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if account_type != AccountType::StakeV1 {
+            msg!("Stake account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            operator: Pubkey::new_from_array(*operator),
+            amount: u64::from_le_bytes(*amount),
+            unstaking_amount: u64::from_le_bytes(*unstaking_amount),
+            unstake_available_at: i64::from_le_bytes(*unstake_available_at),
+            bump_seed: u8::from_le_bytes(*bump_seed),
+            rent_payer: Pubkey::new_from_array(*rent_payer),
+        })
+    }
+}
@@ -0,0 +1,171 @@
+//! Signed payout vouchers
+
+use {
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::UnixTimestamp,
+        instruction::Instruction,
+        msg,
+        program_error::ProgramError,
+        program_pack::{Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryInto,
+};
+
+/// Ed25519 signature verification program
+pub mod ed25519_program {
+    solana_program::declare_id!("Ed25519SigVerify111111111111111111111111111");
+}
+
+/// Payout voucher signed off-chain by the escrow owner
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Voucher {
+    /// Escrow the payout is made from
+    pub escrow: Pubkey,
+    /// Job the payout is debited from
+    pub job: Pubkey,
+    /// Destination RNDR token account
+    pub destination: Pubkey,
+    /// Amount of tokens to pay out, after the price multiplier of the job tier
+    pub amount: u64,
+    /// Unique nonce of the voucher for the escrow
+    pub nonce: u64,
+    /// Time after which the voucher can't be redeemed
+    pub expiry: UnixTimestamp,
+}
+
+impl Sealed for Voucher {}
+
+const VOUCHER_LEN: usize = 120; // 32 + 32 + 32 + 8 + 8 + 8
+impl Pack for Voucher {
+    const LEN: usize = VOUCHER_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, VOUCHER_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (escrow, job, destination, amount, nonce, expiry) =
+            mut_array_refs![output, PUBKEY_BYTES, PUBKEY_BYTES, PUBKEY_BYTES, 8, 8, 8];
+
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        job.copy_from_slice(&self.job.to_bytes());
+        destination.copy_from_slice(&self.destination.to_bytes());
+        *amount = self.amount.to_le_bytes();
+        *nonce = self.nonce.to_le_bytes();
+        *expiry = self.expiry.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, VOUCHER_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (escrow, job, destination, amount, nonce, expiry) =
+            array_refs![input, PUBKEY_BYTES, PUBKEY_BYTES, PUBKEY_BYTES, 8, 8, 8];
+
+        Ok(Self {
+            escrow: Pubkey::new_from_array(*escrow),
+            job: Pubkey::new_from_array(*job),
+            destination: Pubkey::new_from_array(*destination),
+            amount: u64::from_le_bytes(*amount),
+            nonce: u64::from_le_bytes(*nonce),
+            expiry: i64::from_le_bytes(*expiry),
+        })
+    }
+}
+
+const SIGNATURE_BYTES: usize = 64;
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+const DATA_START: usize = SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN;
+/// Instruction index used by the ed25519 program to refer to its own instruction data
+const CURRENT_INSTRUCTION_INDEX: u16 = u16::MAX;
+
+/// Creates an ed25519 program instruction verifying a single signature, with the public key,
+/// signature and message all stored in the instruction data
+pub fn new_ed25519_instruction(
+    pubkey: &Pubkey,
+    signature: &[u8; SIGNATURE_BYTES],
+    message: &[u8],
+) -> Instruction {
+    let public_key_offset = DATA_START;
+    let signature_offset = public_key_offset + PUBKEY_BYTES;
+    let message_data_offset = signature_offset + SIGNATURE_BYTES;
+
+    let mut data = Vec::with_capacity(message_data_offset + message.len());
+    data.push(1);
+    data.push(0);
+    data.extend_from_slice(&(signature_offset as u16).to_le_bytes());
+    data.extend_from_slice(&CURRENT_INSTRUCTION_INDEX.to_le_bytes());
+    data.extend_from_slice(&(public_key_offset as u16).to_le_bytes());
+    data.extend_from_slice(&CURRENT_INSTRUCTION_INDEX.to_le_bytes());
+    data.extend_from_slice(&(message_data_offset as u16).to_le_bytes());
+    data.extend_from_slice(&(message.len() as u16).to_le_bytes());
+    data.extend_from_slice(&CURRENT_INSTRUCTION_INDEX.to_le_bytes());
+    data.extend_from_slice(pubkey.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::id(),
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Check that an ed25519 program instruction verified a single signature of the message by the
+/// public key, with both stored in its own instruction data
+pub fn verify_ed25519_instruction(
+    instruction: &Instruction,
+    pubkey: &Pubkey,
+    message: &[u8],
+) -> Result<(), ProgramError> {
+    if instruction.program_id != ed25519_program::id() {
+        msg!("Instruction is not an ed25519 program instruction");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let data = &instruction.data;
+    if data.len() < DATA_START || data[0] != 1 {
+        msg!("Ed25519 instruction must verify exactly one signature");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let read_u16 = |offset: usize| {
+        u16::from_le_bytes(
+            data[SIGNATURE_OFFSETS_START + offset..SIGNATURE_OFFSETS_START + offset + 2]
+                .try_into()
+                .unwrap(),
+        )
+    };
+    let signature_instruction_index = read_u16(2);
+    let public_key_offset = read_u16(4) as usize;
+    let public_key_instruction_index = read_u16(6);
+    let message_data_offset = read_u16(8) as usize;
+    let message_data_size = read_u16(10) as usize;
+    let message_instruction_index = read_u16(12);
+
+    if signature_instruction_index != CURRENT_INSTRUCTION_INDEX
+        || public_key_instruction_index != CURRENT_INSTRUCTION_INDEX
+        || message_instruction_index != CURRENT_INSTRUCTION_INDEX
+    {
+        msg!("Ed25519 instruction must reference its own instruction data");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let signed_pubkey = data
+        .get(public_key_offset..public_key_offset + PUBKEY_BYTES)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    if signed_pubkey != pubkey.as_ref() {
+        msg!("Ed25519 instruction public key does not match the expected signer");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let signed_message = data
+        .get(message_data_offset..message_data_offset + message_data_size)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    if signed_message != message {
+        msg!("Ed25519 instruction message does not match the expected message");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok(())
+}
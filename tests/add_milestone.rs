@@ -0,0 +1,70 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::add_milestone, processor::process_instruction, state::AccountType},
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 10 * DECIMALS;
+    const MILESTONE_AMOUNT: u64 = 4 * DECIMALS;
+    const DESCRIPTION_HASH: [u8; 32] = [3; 32];
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[add_milestone(
+            rndr::id(),
+            MILESTONE_AMOUNT,
+            DESCRIPTION_HASH,
+            0,
+            payer.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+            authority.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (milestone_pubkey, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"milestone",
+            test_job.pubkey.as_ref(),
+            &test_job.generation.to_le_bytes(),
+            &[0],
+        ],
+        &rndr::id(),
+    );
+    let milestone = get_milestone(&mut banks_client, milestone_pubkey).await;
+
+    assert_eq!(milestone.account_type, AccountType::MilestoneV1);
+    assert_eq!(milestone.job, test_job.pubkey);
+    assert_eq!(milestone.index, 0);
+    assert_eq!(milestone.amount, MILESTONE_AMOUNT);
+    assert_eq!(milestone.description_hash, DESCRIPTION_HASH);
+    assert!(!milestone.approved);
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.milestone_count, 1);
+    assert_eq!(job.approved_amount, 0);
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::approve_milestone, processor::process_instruction},
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 10 * DECIMALS;
+    const MILESTONE_AMOUNT: u64 = 4 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let mut job = TestJob::new_job(test_escrow.pubkey, authority.pubkey());
+    job.amount = AMOUNT;
+    job.milestone_count = 1;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_milestone = TestMilestone::add(&mut test, test_job.pubkey, 0, MILESTONE_AMOUNT, false);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[approve_milestone(
+            rndr::id(),
+            test_milestone.index,
+            test_job.pubkey,
+            test_job.generation,
+            authority.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let milestone = test_milestone.get(&mut banks_client).await;
+    assert!(milestone.approved);
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.approved_amount, MILESTONE_AMOUNT);
+}
+
+#[tokio::test]
+async fn test_closed_job_milestone() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 10 * DECIMALS;
+    const MILESTONE_AMOUNT: u64 = 4 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    // The job was created again after a job of the same address was closed
+    let mut job = TestJob::new_job(test_escrow.pubkey, authority.pubkey());
+    job.amount = AMOUNT;
+    job.generation = 1;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_milestone = TestMilestone::add(&mut test, test_job.pubkey, 0, MILESTONE_AMOUNT, false);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[approve_milestone(
+            rndr::id(),
+            test_milestone.index,
+            test_job.pubkey,
+            0,
+            authority.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert_eq!(
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidSeeds)
+    );
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.approved_amount, 0);
+}
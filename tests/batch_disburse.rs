@@ -0,0 +1,358 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::{batch_disburse, Disbursement, MAX_BATCH_DISBURSEMENTS},
+        processor::process_instruction,
+        state::{NodeStatus, TIER_COUNT, TIER_MULTIPLIER_BASE},
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        packet::PACKET_DATA_SIZE, pubkey::Pubkey, signature::Signer, transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const JOB_AMOUNT: u64 = 5 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 2 * JOB_AMOUNT);
+    let test_destination_token_a = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_destination_token_b = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_job_a = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        Pubkey::new_unique(),
+        JOB_AMOUNT,
+    );
+    let test_job_b = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        Pubkey::new_unique(),
+        JOB_AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[batch_disburse(
+            rndr::id(),
+            vec![
+                Disbursement {
+                    job_index: 0,
+                    destination_index: 0,
+                    amount: DECIMALS,
+                },
+                Disbursement {
+                    job_index: 1,
+                    destination_index: 0,
+                    amount: 2 * DECIMALS,
+                },
+                Disbursement {
+                    job_index: 1,
+                    destination_index: 1,
+                    amount: 3 * DECIMALS,
+                },
+            ],
+            test_mint.pubkey,
+            &[test_job_a.pubkey, test_job_b.pubkey],
+            &[
+                test_destination_token_a.pubkey,
+                test_destination_token_b.pubkey,
+            ],
+            None,
+            test_escrow.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let destination_token_balance_a =
+        get_token_balance(&mut banks_client, test_destination_token_a.pubkey).await;
+    let destination_token_balance_b =
+        get_token_balance(&mut banks_client, test_destination_token_b.pubkey).await;
+    assert_eq!(destination_token_balance_a, 3 * DECIMALS);
+    assert_eq!(destination_token_balance_b, 3 * DECIMALS);
+
+    let escrow_balance = get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+    assert_eq!(escrow_balance, 2 * JOB_AMOUNT - 6 * DECIMALS);
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, 2 * JOB_AMOUNT - 6 * DECIMALS);
+
+    let job_a = test_job_a.get(&mut banks_client).await;
+    assert_eq!(job_a.amount, JOB_AMOUNT - DECIMALS);
+    assert_eq!(job_a.disbursed, DECIMALS);
+
+    let job_b = test_job_b.get(&mut banks_client).await;
+    assert_eq!(job_b.amount, ZERO);
+    assert_eq!(job_b.disbursed, JOB_AMOUNT);
+}
+
+#[tokio::test]
+async fn test_duplicate_job() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 5 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 2 * AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[batch_disburse(
+            rndr::id(),
+            vec![
+                Disbursement {
+                    job_index: 0,
+                    destination_index: 0,
+                    amount: AMOUNT,
+                },
+                Disbursement {
+                    job_index: 1,
+                    destination_index: 0,
+                    amount: AMOUNT,
+                },
+            ],
+            test_mint.pubkey,
+            &[test_job.pubkey, test_job.pubkey],
+            &[test_destination_token.pubkey],
+            None,
+            test_escrow.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+}
+
+/// Default compute budget of an instruction
+const MAX_COMPUTE_UNITS: u64 = 200_000;
+
+/// Largest batch allowed: 8 jobs paying out to 8 destinations, with every job paying two of them.
+/// Under `cargo test-bpf` the program runs as BPF, so the batch must also fit in the compute
+/// budget.
+#[tokio::test]
+async fn test_max_batch_size() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    test.set_bpf_compute_max_units(MAX_COMPUTE_UNITS);
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const JOB_AMOUNT: u64 = 2 * DECIMALS;
+    const BATCH_SIZE: u8 = (MAX_BATCH_DISBURSEMENTS / 2) as u8;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, BATCH_SIZE as u64 * JOB_AMOUNT);
+    let test_jobs: Vec<_> = (0..BATCH_SIZE)
+        .map(|_| {
+            TestJob::add(
+                &mut test,
+                test_escrow.pubkey,
+                Pubkey::new_unique(),
+                JOB_AMOUNT,
+            )
+        })
+        .collect();
+    let test_destination_tokens: Vec<_> = (0..BATCH_SIZE)
+        .map(|_| TestToken::add(&mut test, test_mint.pubkey, ZERO))
+        .collect();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let disbursements = (0..BATCH_SIZE)
+        .flat_map(|i| {
+            vec![
+                Disbursement {
+                    job_index: i,
+                    destination_index: i,
+                    amount: DECIMALS,
+                },
+                Disbursement {
+                    job_index: i,
+                    destination_index: (i + 1) % BATCH_SIZE,
+                    amount: DECIMALS,
+                },
+            ]
+        })
+        .collect();
+    let jobs: Vec<_> = test_jobs.iter().map(|test_job| test_job.pubkey).collect();
+    let destination_tokens: Vec<_> = test_destination_tokens
+        .iter()
+        .map(|test_destination_token| test_destination_token.pubkey)
+        .collect();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[batch_disburse(
+            rndr::id(),
+            disbursements,
+            test_mint.pubkey,
+            &jobs,
+            &destination_tokens,
+            None,
+            test_escrow.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(bincode::serialize(&transaction).unwrap().len() <= PACKET_DATA_SIZE);
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    for test_destination_token in &test_destination_tokens {
+        let destination_token_balance =
+            get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+        assert_eq!(destination_token_balance, 2 * DECIMALS);
+    }
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, ZERO);
+}
+
+#[tokio::test]
+async fn test_batch_too_large() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 100 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let disbursements = vec![
+        Disbursement {
+            job_index: 0,
+            destination_index: 0,
+            amount: DECIMALS,
+        };
+        MAX_BATCH_DISBURSEMENTS + 1
+    ];
+
+    let mut transaction = Transaction::new_with_payer(
+        &[batch_disburse(
+            rndr::id(),
+            disbursements,
+            test_mint.pubkey,
+            &[test_job.pubkey],
+            &[test_destination_token.pubkey],
+            None,
+            test_escrow.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_node_registry_required() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 2 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_with_node_registry_required(
+        &mut test,
+        test_mint.pubkey,
+        AMOUNT,
+        None,
+        1,
+        None,
+        [TIER_MULTIPLIER_BASE; TIER_COUNT],
+        true,
+    );
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_node = TestNode::add(&mut test, test_destination_token.pubkey, NodeStatus::Active);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let disbursements = vec![Disbursement {
+        job_index: 0,
+        destination_index: 0,
+        amount: DECIMALS,
+    }];
+
+    // The escrow doesn't pay destinations without their registered node
+    let mut transaction = Transaction::new_with_payer(
+        &[batch_disburse(
+            rndr::id(),
+            disbursements.clone(),
+            test_mint.pubkey,
+            &[test_job.pubkey],
+            &[test_destination_token.pubkey],
+            None,
+            test_escrow.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[batch_disburse(
+            rndr::id(),
+            disbursements,
+            test_mint.pubkey,
+            &[test_job.pubkey],
+            &[test_destination_token.pubkey],
+            Some(&[test_node.operator.pubkey()]),
+            test_escrow.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        DECIMALS
+    );
+    let node = test_node.get(&mut banks_client).await;
+    assert_eq!(node.payout_count, 1);
+    assert_eq!(node.earned, DECIMALS);
+}
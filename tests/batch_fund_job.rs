@@ -0,0 +1,212 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::{batch_fund_job, find_job_address, JobFunding},
+        processor::process_instruction,
+    },
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, 3 * AMOUNT);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, AMOUNT, None, 1);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let new_authority = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[batch_fund_job(
+            rndr::id(),
+            vec![
+                JobFunding {
+                    authority: test_job.authority,
+                    amount: AMOUNT,
+                },
+                JobFunding {
+                    authority: new_authority,
+                    amount: 2 * AMOUNT,
+                },
+            ],
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await,
+        0
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        4 * AMOUNT
+    );
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, 4 * AMOUNT);
+    assert_eq!(escrow.job_count, 2);
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, 2 * AMOUNT);
+
+    let (job_pubkey, _bump_seed) =
+        find_job_address(&rndr::id(), &test_escrow.pubkey, &new_authority);
+    let job = get_job(&mut banks_client, job_pubkey).await;
+    assert_eq!(job.authority, new_authority);
+    assert_eq!(job.amount, 2 * AMOUNT);
+    assert_eq!(job.rent_payer, payer.pubkey());
+}
+
+#[tokio::test]
+async fn test_existing_jobs() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, 3 * AMOUNT);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, 0, None, 2);
+    let test_job_1 = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), 0);
+    let test_job_2 = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[batch_fund_job(
+            rndr::id(),
+            vec![
+                JobFunding {
+                    authority: test_job_1.authority,
+                    amount: AMOUNT,
+                },
+                JobFunding {
+                    authority: test_job_2.authority,
+                    amount: 2 * AMOUNT,
+                },
+            ],
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        3 * AMOUNT
+    );
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, 3 * AMOUNT);
+    assert_eq!(escrow.job_count, 2);
+
+    let job = test_job_1.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+    let job = test_job_2.get(&mut banks_client).await;
+    assert_eq!(job.amount, 2 * AMOUNT);
+}
+
+#[tokio::test]
+async fn test_duplicate_job() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, 2 * AMOUNT);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, 0, None, 1);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let funding = JobFunding {
+        authority: test_job.authority,
+        amount: AMOUNT,
+    };
+    let mut transaction = Transaction::new_with_payer(
+        &[batch_fund_job(
+            rndr::id(),
+            vec![funding, funding],
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_zero_amount() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, 0, None, 2);
+    let test_job_1 = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), 0);
+    let test_job_2 = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[batch_fund_job(
+            rndr::id(),
+            vec![
+                JobFunding {
+                    authority: test_job_1.authority,
+                    amount: AMOUNT,
+                },
+                JobFunding {
+                    authority: test_job_2.authority,
+                    amount: 0,
+                },
+            ],
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,196 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::challenge_payout, processor::process_instruction},
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let mut job = TestJob::new_job(test_escrow.pubkey, authority.pubkey());
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_pending_payout = TestPendingPayout::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        Pubkey::new_unique(),
+        AMOUNT,
+        i64::MAX,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let pending_payout_lamports = get_account(&mut banks_client, test_pending_payout.pubkey)
+        .await
+        .lamports;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[challenge_payout(
+            rndr::id(),
+            test_escrow.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            test_pending_payout.pubkey,
+            test_pending_payout.rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.reserved, 0);
+
+    assert!(banks_client
+        .get_account(test_pending_payout.pubkey)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        get_account(&mut banks_client, test_pending_payout.rent_payer)
+            .await
+            .lamports,
+        pending_payout_lamports
+    );
+}
+
+#[tokio::test]
+async fn test_challenge_period_over() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let mut job = TestJob::new_job(test_escrow.pubkey, authority.pubkey());
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_pending_payout = TestPendingPayout::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        Pubkey::new_unique(),
+        AMOUNT,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[challenge_payout(
+            rndr::id(),
+            test_escrow.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            test_pending_payout.pubkey,
+            test_pending_payout.rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_escrow_owner_withdraw() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_pending_payout = TestPendingPayout::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        Pubkey::new_unique(),
+        AMOUNT,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[challenge_payout(
+            rndr::id(),
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_pending_payout.pubkey,
+            test_pending_payout.rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.reserved, 0);
+}
+
+#[tokio::test]
+async fn test_not_authority() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_pending_payout = TestPendingPayout::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        Pubkey::new_unique(),
+        AMOUNT,
+        i64::MAX,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[challenge_payout(
+            rndr::id(),
+            test_escrow.pubkey,
+            payer.pubkey(),
+            test_job.pubkey,
+            test_pending_payout.pubkey,
+            test_pending_payout.rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::claim_frames, processor::process_instruction},
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+    const PRICE_PER_FRAME: u64 = AMOUNT / 10;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let test_rate_card = TestRateCard::add(&mut test, test_job.pubkey, PRICE_PER_FRAME, 10, 2);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_frames(
+            rndr::id(),
+            4,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        4 * PRICE_PER_FRAME
+    );
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, AMOUNT - 4 * PRICE_PER_FRAME);
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT - 4 * PRICE_PER_FRAME);
+    assert_eq!(job.disbursed, 4 * PRICE_PER_FRAME);
+    let rate_card = test_rate_card.get(&mut banks_client).await;
+    assert_eq!(rate_card.frames_paid, 6);
+}
+
+#[tokio::test]
+async fn test_exceeds_total_frames() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+    const PRICE_PER_FRAME: u64 = AMOUNT / 10;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let test_rate_card = TestRateCard::add(&mut test, test_job.pubkey, PRICE_PER_FRAME, 10, 8);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_frames(
+            rndr::id(),
+            3,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let rate_card = test_rate_card.get(&mut banks_client).await;
+    assert_eq!(rate_card.frames_paid, 8);
+}
+
+#[tokio::test]
+async fn test_submissions_required() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+    const PRICE_PER_FRAME: u64 = AMOUNT / 10;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.amount = AMOUNT;
+    job.submissions_required = true;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    TestRateCard::add(&mut test, test_job.pubkey, PRICE_PER_FRAME, 10, 0);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_frames(
+            rndr::id(),
+            1,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,215 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::claim_payout, merkle, processor::process_instruction},
+    solana_program_test::*,
+    solana_sdk::{account::Account, pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 10 * DECIMALS;
+    const FIRST_AMOUNT: u64 = 2 * DECIMALS;
+    const SECOND_AMOUNT: u64 = 3 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_first_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_second_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.amount = AMOUNT - FIRST_AMOUNT - SECOND_AMOUNT;
+    job.reserved = FIRST_AMOUNT + SECOND_AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+
+    let first_leaf = merkle::leaf_hash(
+        0,
+        &test_first_token.owner.pubkey(),
+        FIRST_AMOUNT,
+        &test_job.pubkey,
+    );
+    let second_leaf = merkle::leaf_hash(
+        1,
+        &test_second_token.owner.pubkey(),
+        SECOND_AMOUNT,
+        &test_job.pubkey,
+    );
+    let root = merkle::node_hash(&first_leaf, &second_leaf);
+
+    let test_payout_root = TestPayoutRoot::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        root,
+        FIRST_AMOUNT + SECOND_AMOUNT,
+        2,
+        i64::MAX,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_payout(
+            rndr::id(),
+            0,
+            FIRST_AMOUNT,
+            vec![second_leaf],
+            test_mint.pubkey,
+            test_job.pubkey,
+            test_payout_root.pubkey,
+            test_first_token.pubkey,
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let first_token_balance = get_token_balance(&mut banks_client, test_first_token.pubkey).await;
+    assert_eq!(first_token_balance, FIRST_AMOUNT);
+
+    let payout_root = test_payout_root.get(&mut banks_client).await;
+    assert_eq!(payout_root.claimed_amount, FIRST_AMOUNT);
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.reserved, SECOND_AMOUNT);
+    assert_eq!(job.disbursed, FIRST_AMOUNT);
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, AMOUNT - FIRST_AMOUNT);
+
+    // The same leaf can't be claimed twice
+    let (recent_blockhash, _fee_calculator) = banks_client
+        .get_new_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_payout(
+            rndr::id(),
+            0,
+            FIRST_AMOUNT,
+            vec![second_leaf],
+            test_mint.pubkey,
+            test_job.pubkey,
+            test_payout_root.pubkey,
+            test_first_token.pubkey,
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_short_payout_root() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 10 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+
+    // Program owned account too short to hold a payout root
+    let payout_root = Pubkey::new_unique();
+    test.add_account(payout_root, Account::new(u32::MAX as u64, 8, &rndr::id()));
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_payout(
+            rndr::id(),
+            0,
+            AMOUNT,
+            vec![],
+            test_mint.pubkey,
+            test_job.pubkey,
+            payout_root,
+            test_token.pubkey,
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_tier_multiplier() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 3 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.tier = 1;
+    job.tier_multiplier = 15_000;
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+
+    let root = merkle::leaf_hash(0, &test_token.owner.pubkey(), AMOUNT, &test_job.pubkey);
+    let test_payout_root = TestPayoutRoot::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        root,
+        AMOUNT,
+        1,
+        i64::MAX,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_payout(
+            rndr::id(),
+            0,
+            AMOUNT,
+            vec![],
+            test_mint.pubkey,
+            test_job.pubkey,
+            test_payout_root.pubkey,
+            test_token.pubkey,
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // Leaf amounts are paid as committed, the tier multiplier doesn't apply to them again
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_token.pubkey).await,
+        AMOUNT
+    );
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.reserved, 0);
+    assert_eq!(job.disbursed, AMOUNT);
+}
@@ -0,0 +1,222 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::{close_escrow, with_leftover_token},
+        processor::process_instruction,
+    },
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let destination = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let escrow_lamports = get_account(&mut banks_client, test_escrow.pubkey)
+        .await
+        .lamports;
+    let escrow_associated_token_lamports =
+        get_account(&mut banks_client, test_escrow.associated_token)
+            .await
+            .lamports;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[close_escrow(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.owner.pubkey(),
+            destination,
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert!(banks_client
+        .get_account(test_escrow.pubkey)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(banks_client
+        .get_account(test_escrow.associated_token)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        get_account(&mut banks_client, destination).await.lamports,
+        escrow_lamports + escrow_associated_token_lamports
+    );
+}
+
+#[tokio::test]
+async fn test_open_jobs() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, 0, None, 1);
+    TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[close_escrow(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.owner.pubkey(),
+            payer.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_leftover_tokens() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let test_token = TestToken::add(&mut test, test_mint.pubkey, DECIMALS);
+    let leftover_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let destination = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[spl_token::instruction::transfer(
+            &spl_token::id(),
+            &test_token.pubkey,
+            &test_escrow.associated_token,
+            &test_token.owner.pubkey(),
+            &[],
+            1,
+        )
+        .unwrap()],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[close_escrow(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.owner.pubkey(),
+            destination,
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_leftover_token(
+            close_escrow(
+                rndr::id(),
+                test_mint.pubkey,
+                test_escrow.owner.pubkey(),
+                destination,
+                None,
+                None,
+            ),
+            leftover_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert!(banks_client
+        .get_account(test_escrow.pubkey)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(banks_client
+        .get_account(test_escrow.associated_token)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(leftover_token.get(&mut banks_client).await.amount, 1);
+}
+
+#[tokio::test]
+async fn test_funds_in_escrow() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, DECIMALS);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[close_escrow(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.owner.pubkey(),
+            payer.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_not_owner() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    TestEscrow::add(&mut test, test_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[close_escrow(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            payer.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::close_funding_schedule, processor::process_instruction},
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let authority = Keypair::new();
+    let job = Pubkey::new_unique();
+    let test_funding_schedule = TestFundingSchedule::add(
+        &mut test,
+        Pubkey::new_unique(),
+        job,
+        authority.pubkey(),
+        Pubkey::new_unique(),
+        1,
+        0,
+        false,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let funding_schedule_lamports = get_account(&mut banks_client, test_funding_schedule.pubkey)
+        .await
+        .lamports;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[close_funding_schedule(
+            rndr::id(),
+            authority.pubkey(),
+            job,
+            0,
+            test_funding_schedule.rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert!(banks_client
+        .get_account(test_funding_schedule.pubkey)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        get_account(&mut banks_client, test_funding_schedule.rent_payer)
+            .await
+            .lamports,
+        funding_schedule_lamports
+    );
+}
+
+#[tokio::test]
+async fn test_wrong_rent_payer() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let authority = Keypair::new();
+    let job = Pubkey::new_unique();
+    TestFundingSchedule::add(
+        &mut test,
+        Pubkey::new_unique(),
+        job,
+        authority.pubkey(),
+        Pubkey::new_unique(),
+        1,
+        0,
+        false,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[close_funding_schedule(
+            rndr::id(),
+            authority.pubkey(),
+            job,
+            0,
+            payer.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
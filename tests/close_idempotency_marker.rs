@@ -0,0 +1,143 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::close_idempotency_marker, processor::process_instruction,
+        state::IDEMPOTENCY_MARKER_LIFETIME,
+    },
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+const KEY: [u8; 16] = [3; 16];
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let job = Pubkey::new_unique();
+    let test_idempotency_marker = TestIdempotencyMarker::add(&mut test, job, &KEY, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let idempotency_marker_lamports =
+        get_account(&mut banks_client, test_idempotency_marker.pubkey)
+            .await
+            .lamports;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[close_idempotency_marker(
+            rndr::id(),
+            job,
+            0,
+            &KEY,
+            test_idempotency_marker.rent_payer.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(
+        &[&payer, &test_idempotency_marker.rent_payer],
+        recent_blockhash,
+    );
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert!(banks_client
+        .get_account(test_idempotency_marker.pubkey)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        get_account(
+            &mut banks_client,
+            test_idempotency_marker.rent_payer.pubkey()
+        )
+        .await
+        .lamports,
+        idempotency_marker_lamports
+    );
+}
+
+#[tokio::test]
+async fn test_lifetime_not_passed() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let job = Pubkey::new_unique();
+    let test_idempotency_marker =
+        TestIdempotencyMarker::add(&mut test, job, &KEY, i64::MAX - IDEMPOTENCY_MARKER_LIFETIME);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[close_idempotency_marker(
+            rndr::id(),
+            job,
+            0,
+            &KEY,
+            test_idempotency_marker.rent_payer.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(
+        &[&payer, &test_idempotency_marker.rent_payer],
+        recent_blockhash,
+    );
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_wrong_rent_payer() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let job = Pubkey::new_unique();
+    TestIdempotencyMarker::add(&mut test, job, &KEY, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[close_idempotency_marker(
+            rndr::id(),
+            job,
+            0,
+            &KEY,
+            payer.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_rent_payer_not_signer() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let job = Pubkey::new_unique();
+    let test_idempotency_marker = TestIdempotencyMarker::add(&mut test, job, &KEY, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut instruction = close_idempotency_marker(
+        rndr::id(),
+        job,
+        0,
+        &KEY,
+        test_idempotency_marker.rent_payer.pubkey(),
+    );
+    instruction.accounts[1].is_signer = false;
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    assert!(banks_client
+        .get_account(test_idempotency_marker.pubkey)
+        .await
+        .unwrap()
+        .is_some());
+}
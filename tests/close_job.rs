@@ -0,0 +1,229 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::{close_job, disburse_funds},
+        processor::process_instruction,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_instruction,
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, AMOUNT, None, 1);
+    let authority = Keypair::new();
+    let mut job = TestJob::new_job(test_escrow.pubkey, authority.pubkey());
+    job.disbursed = AMOUNT;
+    let rent_payer = job.rent_payer;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let job_lamports = get_account(&mut banks_client, test_job.pubkey)
+        .await
+        .lamports;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[close_job(
+            rndr::id(),
+            test_escrow.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert!(banks_client
+        .get_account(test_job.pubkey)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        get_account(&mut banks_client, rent_payer).await.lamports,
+        job_lamports
+    );
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.job_count, 0);
+}
+
+#[tokio::test]
+async fn test_escrow_owner() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, 0, None, 1);
+    let job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    let rent_payer = job.rent_payer;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[close_job(
+            rndr::id(),
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert!(banks_client
+        .get_account(test_job.pubkey)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_funds_in_escrow() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, AMOUNT, None, 1);
+    let authority = Keypair::new();
+    let mut job = TestJob::new_job(test_escrow.pubkey, authority.pubkey());
+    job.amount = AMOUNT;
+    let rent_payer = job.rent_payer;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[close_job(
+            rndr::id(),
+            test_escrow.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_wrong_rent_payer() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, 0, None, 1);
+    let authority = Keypair::new();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority.pubkey(), 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[close_job(
+            rndr::id(),
+            test_escrow.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            authority.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_revival() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, AMOUNT, None, 1);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    TestNodeEarnings::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        0,
+        0,
+    );
+    let authority = Keypair::new();
+    let mut job = TestJob::new_job(test_escrow.pubkey, authority.pubkey());
+    job.disbursed = AMOUNT;
+    let rent_payer = job.rent_payer;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Keep the closed job alive by sending it lamports again in the same transaction
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            close_job(
+                rndr::id(),
+                test_escrow.pubkey,
+                authority.pubkey(),
+                test_job.pubkey,
+                rent_payer,
+            ),
+            system_instruction::transfer(&payer.pubkey(), &test_job.pubkey, u32::MAX as u64),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The revived job has no state left to use
+    let job_account = get_account(&mut banks_client, test_job.pubkey).await;
+    assert!(job_account.data.iter().all(|byte| *byte == 0));
+
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_destination_token.owner.pubkey(),
+            test_job.pubkey,
+            test_escrow.owner.pubkey(),
+            test_escrow.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,280 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::crank_funding, processor::process_instruction},
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, AMOUNT, None, 1);
+    let test_source_token = TestToken::add_with_delegate(
+        &mut test,
+        test_mint.pubkey,
+        10 * AMOUNT,
+        Some(test_escrow.pubkey),
+        10 * AMOUNT,
+    );
+    let authority = test_source_token.owner.pubkey();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, AMOUNT);
+    let test_funding_schedule = TestFundingSchedule::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        authority,
+        test_source_token.pubkey,
+        AMOUNT,
+        0,
+        false,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[crank_funding(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_job.pubkey,
+            test_job.generation,
+            test_source_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await,
+        9 * AMOUNT
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        2 * AMOUNT
+    );
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, 2 * AMOUNT);
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, 2 * AMOUNT);
+
+    let funding_schedule = test_funding_schedule.get(&mut banks_client).await;
+    assert_eq!(funding_schedule.next_due, funding_schedule.period);
+    assert!(!funding_schedule.paused);
+}
+
+#[tokio::test]
+async fn test_not_due() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, 0, None, 1);
+    let test_source_token = TestToken::add_with_delegate(
+        &mut test,
+        test_mint.pubkey,
+        10 * AMOUNT,
+        Some(test_escrow.pubkey),
+        10 * AMOUNT,
+    );
+    let authority = test_source_token.owner.pubkey();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, 0);
+    TestFundingSchedule::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        authority,
+        test_source_token.pubkey,
+        AMOUNT,
+        i64::MAX,
+        false,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[crank_funding(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_job.pubkey,
+            test_job.generation,
+            test_source_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_allowance_ran_out() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, 0, None, 1);
+    let test_source_token = TestToken::add_with_delegate(
+        &mut test,
+        test_mint.pubkey,
+        10 * AMOUNT,
+        Some(test_escrow.pubkey),
+        AMOUNT / 2,
+    );
+    let authority = test_source_token.owner.pubkey();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, 0);
+    let test_funding_schedule = TestFundingSchedule::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        authority,
+        test_source_token.pubkey,
+        AMOUNT,
+        0,
+        false,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[crank_funding(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_job.pubkey,
+            test_job.generation,
+            test_source_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await,
+        10 * AMOUNT
+    );
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, 0);
+
+    let funding_schedule = test_funding_schedule.get(&mut banks_client).await;
+    assert!(funding_schedule.paused);
+    assert_eq!(funding_schedule.next_due, 0);
+}
+
+#[tokio::test]
+async fn test_paused() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, 0, None, 1);
+    let test_source_token = TestToken::add_with_delegate(
+        &mut test,
+        test_mint.pubkey,
+        10 * AMOUNT,
+        Some(test_escrow.pubkey),
+        10 * AMOUNT,
+    );
+    let authority = test_source_token.owner.pubkey();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, 0);
+    TestFundingSchedule::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        authority,
+        test_source_token.pubkey,
+        AMOUNT,
+        0,
+        true,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[crank_funding(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_job.pubkey,
+            test_job.generation,
+            test_source_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_wrong_source_token() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, 0, None, 1);
+    let test_source_token = TestToken::add_with_delegate(
+        &mut test,
+        test_mint.pubkey,
+        10 * AMOUNT,
+        Some(test_escrow.pubkey),
+        10 * AMOUNT,
+    );
+    let other_source_token = TestToken::add_with_delegate(
+        &mut test,
+        test_mint.pubkey,
+        10 * AMOUNT,
+        Some(test_escrow.pubkey),
+        10 * AMOUNT,
+    );
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), 0);
+    TestFundingSchedule::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        test_job.authority,
+        test_source_token.pubkey,
+        AMOUNT,
+        0,
+        false,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[crank_funding(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_job.pubkey,
+            test_job.generation,
+            other_source_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,197 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::{create_assignment, find_assignment_address, set_min_stake, with_stake},
+        processor::process_instruction,
+    },
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let node = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_assignment(
+            rndr::id(),
+            AMOUNT / 4,
+            i64::MAX,
+            payer.pubkey(),
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+            node,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT - AMOUNT / 4);
+    assert_eq!(job.reserved, AMOUNT / 4);
+
+    let (assignment_address, _bump_seed) =
+        find_assignment_address(&rndr::id(), &test_job.pubkey, test_job.generation, &node);
+    let assignment = get_assignment(&mut banks_client, assignment_address).await;
+    assert_eq!(assignment.escrow, test_escrow.pubkey);
+    assert_eq!(assignment.job, test_job.pubkey);
+    assert_eq!(assignment.node, node);
+    assert_eq!(assignment.amount, AMOUNT / 4);
+    assert_eq!(assignment.deadline, i64::MAX);
+    assert_eq!(assignment.rent_payer, payer.pubkey());
+}
+
+#[tokio::test]
+async fn test_past_deadline() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_assignment(
+            rndr::id(),
+            AMOUNT / 4,
+            0,
+            payer.pubkey(),
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+            Pubkey::new_unique(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_exceeds_job_amount() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_assignment(
+            rndr::id(),
+            AMOUNT + 1,
+            i64::MAX,
+            payer.pubkey(),
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+            Pubkey::new_unique(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_min_stake() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let test_stake = TestStake::add(
+        &mut test,
+        test_mint.pubkey,
+        test_escrow.pubkey,
+        AMOUNT,
+        0,
+        0,
+    );
+    let node = test_stake.operator.pubkey();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let instruction = create_assignment(
+        rndr::id(),
+        AMOUNT / 4,
+        i64::MAX,
+        payer.pubkey(),
+        test_escrow.pubkey,
+        test_escrow.owner.pubkey(),
+        test_job.pubkey,
+        test_job.generation,
+        node,
+    );
+
+    // Nodes without a stake can't be assigned work
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            set_min_stake(
+                rndr::id(),
+                AMOUNT,
+                test_escrow.pubkey,
+                test_escrow.owner.pubkey(),
+            ),
+            instruction.clone(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Nor can nodes with a stake below the minimum
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            set_min_stake(
+                rndr::id(),
+                AMOUNT + 1,
+                test_escrow.pubkey,
+                test_escrow.owner.pubkey(),
+            ),
+            with_stake(rndr::id(), instruction, test_escrow.pubkey, node),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.reserved, 0);
+}
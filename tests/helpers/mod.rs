@@ -1,10 +1,32 @@
 #![allow(dead_code)]
 
-use rndr::state::{InitJobParams, Job};
+use rndr::state::{
+    Assignment, FundingSchedule, IdempotencyMarker, InitAssignmentParams,
+    InitFundingScheduleParams, InitIdempotencyMarkerParams, InitJobParams, InitMilestoneParams,
+    InitNodeEarningsParams, InitNodeParams, InitPayoutRootParams, InitPendingPayoutParams,
+    InitProgramConfigParams, InitRateCardParams, InitReceiptParams, InitRenderSubmissionParams,
+    InitStakeParams, Job, JobMetadata, Milestone, Node, NodeEarnings, NodeStatus, PayoutRoot,
+    PendingPayout, ProgramConfig, RateCard, Receipt, RedeemedVoucher, RenderSubmission, Stake,
+    SubmissionStatus, TIER_COUNT, TIER_MULTIPLIER_BASE,
+};
 use spl_associated_token_account::get_associated_token_address;
 use {
-    rndr::state::{Escrow, InitEscrowParams},
-    solana_program::{program_option::COption, program_pack::Pack, pubkey::Pubkey},
+    rndr::{
+        instruction::{
+            find_assignment_address, find_escrow_address, find_funding_schedule_address,
+            find_idempotency_marker_address, find_job_address, find_node_address,
+            find_node_earnings_address, find_pending_payout_address, find_program_config_address,
+            find_program_data_address, find_rate_card_address, find_receipt_address,
+            find_render_submission_address, find_stake_address, find_stake_vault_address,
+        },
+        state::{Escrow, InitEscrowParams},
+    },
+    solana_program::{
+        bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+        program_option::COption,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    },
     solana_program_test::*,
     solana_sdk::{
         account::Account,
@@ -65,6 +87,163 @@ pub async fn get_job(banks_client: &mut BanksClient, pubkey: Pubkey) -> Job {
     Job::unpack(&account.data).unwrap()
 }
 
+pub async fn get_job_metadata(banks_client: &mut BanksClient, pubkey: Pubkey) -> JobMetadata {
+    let account = get_account(banks_client, pubkey).await;
+    JobMetadata::unpack(&account.data).unwrap()
+}
+
+pub async fn get_milestone(banks_client: &mut BanksClient, pubkey: Pubkey) -> Milestone {
+    let account = get_account(banks_client, pubkey).await;
+    Milestone::unpack(&account.data).unwrap()
+}
+
+pub async fn get_payout_root(banks_client: &mut BanksClient, pubkey: Pubkey) -> PayoutRoot {
+    let account = get_account(banks_client, pubkey).await;
+    PayoutRoot::unpack(&account.data[..PayoutRoot::LEN]).unwrap()
+}
+
+pub async fn get_redeemed_voucher(
+    banks_client: &mut BanksClient,
+    pubkey: Pubkey,
+) -> RedeemedVoucher {
+    let account = get_account(banks_client, pubkey).await;
+    RedeemedVoucher::unpack(&account.data).unwrap()
+}
+
+pub async fn get_funding_schedule(
+    banks_client: &mut BanksClient,
+    pubkey: Pubkey,
+) -> FundingSchedule {
+    let account = get_account(banks_client, pubkey).await;
+    FundingSchedule::unpack(&account.data).unwrap()
+}
+
+pub async fn get_pending_payout(banks_client: &mut BanksClient, pubkey: Pubkey) -> PendingPayout {
+    let account = get_account(banks_client, pubkey).await;
+    PendingPayout::unpack(&account.data).unwrap()
+}
+
+pub async fn get_render_submission(
+    banks_client: &mut BanksClient,
+    pubkey: Pubkey,
+) -> RenderSubmission {
+    let account = get_account(banks_client, pubkey).await;
+    RenderSubmission::unpack(&account.data).unwrap()
+}
+
+pub async fn get_rate_card(banks_client: &mut BanksClient, pubkey: Pubkey) -> RateCard {
+    let account = get_account(banks_client, pubkey).await;
+    RateCard::unpack(&account.data).unwrap()
+}
+
+pub async fn get_assignment(banks_client: &mut BanksClient, pubkey: Pubkey) -> Assignment {
+    let account = get_account(banks_client, pubkey).await;
+    Assignment::unpack(&account.data).unwrap()
+}
+
+pub async fn get_node(banks_client: &mut BanksClient, pubkey: Pubkey) -> Node {
+    let account = get_account(banks_client, pubkey).await;
+    Node::unpack(&account.data).unwrap()
+}
+
+pub async fn get_stake(banks_client: &mut BanksClient, pubkey: Pubkey) -> Stake {
+    let account = get_account(banks_client, pubkey).await;
+    Stake::unpack(&account.data).unwrap()
+}
+
+pub async fn get_node_earnings(banks_client: &mut BanksClient, pubkey: Pubkey) -> NodeEarnings {
+    let account = get_account(banks_client, pubkey).await;
+    NodeEarnings::unpack(&account.data).unwrap()
+}
+
+pub async fn get_idempotency_marker(
+    banks_client: &mut BanksClient,
+    pubkey: Pubkey,
+) -> IdempotencyMarker {
+    let account = get_account(banks_client, pubkey).await;
+    IdempotencyMarker::unpack(&account.data).unwrap()
+}
+
+pub async fn get_receipt(banks_client: &mut BanksClient, pubkey: Pubkey) -> Receipt {
+    let account = get_account(banks_client, pubkey).await;
+    Receipt::unpack(&account.data).unwrap()
+}
+
+pub async fn get_program_config(banks_client: &mut BanksClient, pubkey: Pubkey) -> ProgramConfig {
+    let account = get_account(banks_client, pubkey).await;
+    ProgramConfig::unpack(&account.data).unwrap()
+}
+
+/// Adds the ProgramData account of the RNDR program, as deployed by the upgradeable loader
+pub fn add_program_data(test: &mut ProgramTest, upgrade_authority: Option<Pubkey>) -> Pubkey {
+    let (pubkey, _bump_seed) = find_program_data_address(&rndr::id());
+
+    let data = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: upgrade_authority,
+    })
+    .unwrap();
+    let mut account = Account::new(u32::MAX as u64, data.len(), &bpf_loader_upgradeable::id());
+    account.data = data;
+    test.add_account(pubkey, account);
+
+    pubkey
+}
+
+pub struct TestProgramConfig {
+    pub pubkey: Pubkey,
+    pub admin: Keypair,
+}
+
+impl TestProgramConfig {
+    pub fn add(
+        test: &mut ProgramTest,
+        allowed_mints: Vec<Pubkey>,
+        allowed_token_programs: Vec<Pubkey>,
+        default_escrow_owner: Pubkey,
+    ) -> Self {
+        Self::add_with_treasury(
+            test,
+            allowed_mints,
+            allowed_token_programs,
+            default_escrow_owner,
+            Pubkey::default(),
+        )
+    }
+
+    pub fn add_with_treasury(
+        test: &mut ProgramTest,
+        allowed_mints: Vec<Pubkey>,
+        allowed_token_programs: Vec<Pubkey>,
+        default_escrow_owner: Pubkey,
+        treasury: Pubkey,
+    ) -> Self {
+        let admin = Keypair::new();
+
+        let (pubkey, bump_seed) = find_program_config_address(&rndr::id());
+
+        test.add_packable_account(
+            pubkey,
+            u32::MAX as u64,
+            &ProgramConfig::new(InitProgramConfigParams {
+                admin: admin.pubkey(),
+                allowed_mints,
+                allowed_token_programs,
+                default_escrow_owner,
+                treasury,
+                bump_seed,
+            }),
+            &rndr::id(),
+        );
+
+        Self { pubkey, admin }
+    }
+
+    pub async fn get(&self, banks_client: &mut BanksClient) -> ProgramConfig {
+        get_program_config(banks_client, self.pubkey).await
+    }
+}
+
 pub struct TestMint {
     pub pubkey: Pubkey,
     pub authority: Keypair,
@@ -109,6 +288,16 @@ pub struct TestToken {
 
 impl TestToken {
     pub fn add(test: &mut ProgramTest, mint: Pubkey, amount: u64) -> Self {
+        Self::add_with_delegate(test, mint, amount, None, 0)
+    }
+
+    pub fn add_with_delegate(
+        test: &mut ProgramTest,
+        mint: Pubkey,
+        amount: u64,
+        delegate: Option<Pubkey>,
+        delegated_amount: u64,
+    ) -> Self {
         let pubkey = Pubkey::new_unique();
         let owner = Keypair::new();
 
@@ -119,8 +308,10 @@ impl TestToken {
                 mint,
                 owner: owner.pubkey(),
                 amount,
+                delegate: delegate.into(),
                 state: AccountState::Initialized,
                 is_native: COption::None,
+                delegated_amount,
                 ..Token::default()
             },
             &spl_token::id(),
@@ -146,12 +337,82 @@ pub struct TestEscrow {
 
 impl TestEscrow {
     pub fn add(test: &mut ProgramTest, token_mint: Pubkey, amount: u64) -> Self {
+        Self::add_with_namespace(test, token_mint, amount, None)
+    }
+
+    pub fn add_with_namespace(
+        test: &mut ProgramTest,
+        token_mint: Pubkey,
+        amount: u64,
+        namespace: Option<Pubkey>,
+    ) -> Self {
+        Self::add_escrow(test, token_mint, amount, namespace, 0)
+    }
+
+    pub fn add_escrow(
+        test: &mut ProgramTest,
+        token_mint: Pubkey,
+        amount: u64,
+        namespace: Option<Pubkey>,
+        job_count: u64,
+    ) -> Self {
+        Self::add_with_successor(test, token_mint, amount, namespace, job_count, None)
+    }
+
+    pub fn add_with_successor(
+        test: &mut ProgramTest,
+        token_mint: Pubkey,
+        amount: u64,
+        namespace: Option<Pubkey>,
+        job_count: u64,
+        successor: Option<Pubkey>,
+    ) -> Self {
+        Self::add_with_tier_multipliers(
+            test,
+            token_mint,
+            amount,
+            namespace,
+            job_count,
+            successor,
+            [TIER_MULTIPLIER_BASE; TIER_COUNT],
+        )
+    }
+
+    pub fn add_with_tier_multipliers(
+        test: &mut ProgramTest,
+        token_mint: Pubkey,
+        amount: u64,
+        namespace: Option<Pubkey>,
+        job_count: u64,
+        successor: Option<Pubkey>,
+        tier_multipliers: [u32; TIER_COUNT],
+    ) -> Self {
+        Self::add_with_node_registry_required(
+            test,
+            token_mint,
+            amount,
+            namespace,
+            job_count,
+            successor,
+            tier_multipliers,
+            false,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_with_node_registry_required(
+        test: &mut ProgramTest,
+        token_mint: Pubkey,
+        amount: u64,
+        namespace: Option<Pubkey>,
+        job_count: u64,
+        successor: Option<Pubkey>,
+        tier_multipliers: [u32; TIER_COUNT],
+        node_registry_required: bool,
+    ) -> Self {
         let owner = Keypair::new();
 
-        let (pubkey, _bump_seed) = Pubkey::find_program_address(
-            &[b"escrow", token_mint.as_ref(), spl_token::id().as_ref()],
-            &rndr::id(),
-        );
+        let (pubkey, bump_seed) = find_escrow_address(&rndr::id(), &token_mint, namespace.as_ref());
 
         let associated_token = get_associated_token_address(&pubkey, &token_mint);
 
@@ -171,8 +432,14 @@ impl TestEscrow {
 
         let mut escrow = Escrow::new(InitEscrowParams {
             owner: owner.pubkey(),
+            bump_seed,
+            namespace: namespace.unwrap_or_default(),
         });
         escrow.amount = amount;
+        escrow.job_count = job_count;
+        escrow.successor = successor.unwrap_or_default();
+        escrow.tier_multipliers = tier_multipliers;
+        escrow.node_registry_required = node_registry_required;
         test.add_packable_account(pubkey, u32::MAX as u64, &escrow, &rndr::id());
 
         Self {
@@ -190,23 +457,503 @@ impl TestEscrow {
 pub struct TestJob {
     pub pubkey: Pubkey,
     pub authority: Pubkey,
+    pub generation: u64,
 }
 
 impl TestJob {
-    pub fn add(test: &mut ProgramTest, escrow: Pubkey, authority: Pubkey, amount: u64) -> Self {
-        let (pubkey, _bump_seed) = Pubkey::find_program_address(
-            &[b"job", &escrow.as_ref(), &authority.as_ref()],
-            &rndr::id(),
-        );
+    /// Test jobs are the first job of their escrow, so the accounts of test jobs are added with
+    /// generation 0
+    pub fn new_job(escrow: Pubkey, authority: Pubkey) -> Job {
+        let (_pubkey, bump_seed) = find_job_address(&rndr::id(), &escrow, &authority);
+        Job::new(InitJobParams {
+            authority,
+            generation: 0,
+            tier: 0,
+            tier_multiplier: TIER_MULTIPLIER_BASE,
+            bump_seed,
+            rent_payer: Pubkey::new_unique(),
+        })
+    }
 
-        let mut job = Job::new(InitJobParams { authority });
+    pub fn add(test: &mut ProgramTest, escrow: Pubkey, authority: Pubkey, amount: u64) -> Self {
+        let mut job = Self::new_job(escrow, authority);
         job.amount = amount;
+        Self::add_job(test, escrow, job)
+    }
+
+    pub fn add_job(test: &mut ProgramTest, escrow: Pubkey, job: Job) -> Self {
+        let authority = job.authority;
+        let generation = job.generation;
+        let (pubkey, _bump_seed) = find_job_address(&rndr::id(), &escrow, &authority);
+
         test.add_packable_account(pubkey, u32::MAX as u64, &job, &rndr::id());
 
-        Self { pubkey, authority }
+        Self {
+            pubkey,
+            authority,
+            generation,
+        }
     }
 
     pub async fn get(&self, banks_client: &mut BanksClient) -> Job {
         get_job(banks_client, self.pubkey).await
     }
 }
+
+pub struct TestMilestone {
+    pub pubkey: Pubkey,
+    pub job: Pubkey,
+    pub index: u8,
+}
+
+impl TestMilestone {
+    pub fn add(
+        test: &mut ProgramTest,
+        job: Pubkey,
+        index: u8,
+        amount: u64,
+        approved: bool,
+    ) -> Self {
+        let (pubkey, _bump_seed) = Pubkey::find_program_address(
+            &[b"milestone", job.as_ref(), &0u64.to_le_bytes(), &[index]],
+            &rndr::id(),
+        );
+
+        let mut milestone = Milestone::new(InitMilestoneParams {
+            job,
+            index,
+            amount,
+            description_hash: [index; 32],
+        });
+        milestone.approved = approved;
+        test.add_packable_account(pubkey, u32::MAX as u64, &milestone, &rndr::id());
+
+        Self { pubkey, job, index }
+    }
+
+    pub async fn get(&self, banks_client: &mut BanksClient) -> Milestone {
+        get_milestone(banks_client, self.pubkey).await
+    }
+}
+
+pub struct TestPayoutRoot {
+    pub pubkey: Pubkey,
+    pub job: Pubkey,
+    pub rent_payer: Pubkey,
+}
+
+impl TestPayoutRoot {
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        job: Pubkey,
+        root: [u8; 32],
+        amount: u64,
+        leaf_count: u32,
+        expiry: i64,
+    ) -> Self {
+        let (pubkey, _bump_seed) = Pubkey::find_program_address(
+            &[b"payout_root", job.as_ref(), &0u64.to_le_bytes(), &root],
+            &rndr::id(),
+        );
+        let rent_payer = Pubkey::new_unique();
+
+        let payout_root = PayoutRoot::new(InitPayoutRootParams {
+            escrow,
+            job,
+            root,
+            amount,
+            leaf_count,
+            expiry,
+            rent_payer,
+        });
+        let mut account = Account::new(
+            u32::MAX as u64,
+            PayoutRoot::account_len(leaf_count),
+            &rndr::id(),
+        );
+        payout_root.pack_into_slice(&mut account.data[..PayoutRoot::LEN]);
+        test.add_account(pubkey, account);
+
+        Self {
+            pubkey,
+            job,
+            rent_payer,
+        }
+    }
+
+    pub async fn get(&self, banks_client: &mut BanksClient) -> PayoutRoot {
+        get_payout_root(banks_client, self.pubkey).await
+    }
+}
+
+pub struct TestFundingSchedule {
+    pub pubkey: Pubkey,
+    pub job: Pubkey,
+    pub rent_payer: Pubkey,
+}
+
+impl TestFundingSchedule {
+    #[allow(clippy::too_many_arguments)]
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        job: Pubkey,
+        authority: Pubkey,
+        source_token: Pubkey,
+        amount: u64,
+        next_due: i64,
+        paused: bool,
+    ) -> Self {
+        let (pubkey, bump_seed) = find_funding_schedule_address(&rndr::id(), &job, 0);
+        let rent_payer = Pubkey::new_unique();
+
+        let mut funding_schedule = FundingSchedule::new(InitFundingScheduleParams {
+            escrow,
+            job,
+            authority,
+            source_token,
+            amount,
+            period: 60,
+            next_due,
+            bump_seed,
+            rent_payer,
+        });
+        funding_schedule.paused = paused;
+        test.add_packable_account(pubkey, u32::MAX as u64, &funding_schedule, &rndr::id());
+
+        Self {
+            pubkey,
+            job,
+            rent_payer,
+        }
+    }
+
+    pub async fn get(&self, banks_client: &mut BanksClient) -> FundingSchedule {
+        get_funding_schedule(banks_client, self.pubkey).await
+    }
+}
+
+pub struct TestPendingPayout {
+    pub pubkey: Pubkey,
+    pub job: Pubkey,
+    pub rent_payer: Pubkey,
+}
+
+impl TestPendingPayout {
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        job: Pubkey,
+        destination: Pubkey,
+        amount: u64,
+        finalize_after: i64,
+    ) -> Self {
+        let nonce = 0;
+        let (pubkey, bump_seed) = find_pending_payout_address(&rndr::id(), &job, 0, nonce);
+        let rent_payer = Pubkey::new_unique();
+
+        let pending_payout = PendingPayout::new(InitPendingPayoutParams {
+            escrow,
+            job,
+            destination,
+            amount,
+            finalize_after,
+            nonce,
+            bump_seed,
+            rent_payer,
+        });
+        test.add_packable_account(pubkey, u32::MAX as u64, &pending_payout, &rndr::id());
+
+        Self {
+            pubkey,
+            job,
+            rent_payer,
+        }
+    }
+
+    pub async fn get(&self, banks_client: &mut BanksClient) -> PendingPayout {
+        get_pending_payout(banks_client, self.pubkey).await
+    }
+}
+
+pub struct TestRenderSubmission {
+    pub pubkey: Pubkey,
+    pub job: Pubkey,
+    pub node: Pubkey,
+}
+
+impl TestRenderSubmission {
+    pub fn add(
+        test: &mut ProgramTest,
+        job: Pubkey,
+        node: Pubkey,
+        price: u64,
+        status: SubmissionStatus,
+    ) -> Self {
+        let (pubkey, bump_seed) = find_render_submission_address(&rndr::id(), &job, 0, &node);
+
+        let mut render_submission = RenderSubmission::new(InitRenderSubmissionParams {
+            job,
+            node,
+            frames_hash: [1; 32],
+            frame_start: 1,
+            frame_end: 10,
+            price,
+            bump_seed,
+            rent_payer: node,
+        });
+        render_submission.status = status;
+        test.add_packable_account(pubkey, u32::MAX as u64, &render_submission, &rndr::id());
+
+        Self { pubkey, job, node }
+    }
+
+    pub async fn get(&self, banks_client: &mut BanksClient) -> RenderSubmission {
+        get_render_submission(banks_client, self.pubkey).await
+    }
+}
+
+pub struct TestRateCard {
+    pub pubkey: Pubkey,
+    pub job: Pubkey,
+}
+
+impl TestRateCard {
+    pub fn add(
+        test: &mut ProgramTest,
+        job: Pubkey,
+        price_per_frame: u64,
+        total_frames: u32,
+        frames_paid: u32,
+    ) -> Self {
+        let (pubkey, bump_seed) = find_rate_card_address(&rndr::id(), &job, 0);
+
+        let mut rate_card = RateCard::new(InitRateCardParams {
+            job,
+            price_per_frame,
+            total_frames,
+            bump_seed,
+            rent_payer: Pubkey::new_unique(),
+        });
+        rate_card.frames_paid = frames_paid;
+        test.add_packable_account(pubkey, u32::MAX as u64, &rate_card, &rndr::id());
+
+        Self { pubkey, job }
+    }
+
+    pub async fn get(&self, banks_client: &mut BanksClient) -> RateCard {
+        get_rate_card(banks_client, self.pubkey).await
+    }
+}
+
+pub struct TestAssignment {
+    pub pubkey: Pubkey,
+    pub job: Pubkey,
+    pub rent_payer: Pubkey,
+}
+
+impl TestAssignment {
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        job: Pubkey,
+        node: Pubkey,
+        amount: u64,
+        deadline: i64,
+    ) -> Self {
+        let (pubkey, bump_seed) = find_assignment_address(&rndr::id(), &job, 0, &node);
+        let rent_payer = Pubkey::new_unique();
+
+        let assignment = Assignment::new(InitAssignmentParams {
+            escrow,
+            job,
+            node,
+            amount,
+            deadline,
+            bump_seed,
+            rent_payer,
+        });
+        test.add_packable_account(pubkey, u32::MAX as u64, &assignment, &rndr::id());
+
+        Self {
+            pubkey,
+            job,
+            rent_payer,
+        }
+    }
+
+    pub async fn get(&self, banks_client: &mut BanksClient) -> Assignment {
+        get_assignment(banks_client, self.pubkey).await
+    }
+}
+
+pub struct TestNode {
+    pub pubkey: Pubkey,
+    pub operator: Keypair,
+}
+
+impl TestNode {
+    pub fn add(test: &mut ProgramTest, payout_token: Pubkey, status: NodeStatus) -> Self {
+        let operator = Keypair::new();
+        let (pubkey, bump_seed) = find_node_address(&rndr::id(), &operator.pubkey());
+
+        let mut node = Node::new(InitNodeParams {
+            operator: operator.pubkey(),
+            payout_token,
+            tier: 0,
+            bump_seed,
+            rent_payer: operator.pubkey(),
+        });
+        node.status = status;
+        test.add_packable_account(pubkey, u32::MAX as u64, &node, &rndr::id());
+
+        Self { pubkey, operator }
+    }
+
+    pub async fn get(&self, banks_client: &mut BanksClient) -> Node {
+        get_node(banks_client, self.pubkey).await
+    }
+}
+
+pub struct TestStake {
+    pub pubkey: Pubkey,
+    pub operator: Keypair,
+    pub vault_associated_token: Pubkey,
+}
+
+impl TestStake {
+    /// Adds a stake and the stake vault of the escrow holding its tokens
+    pub fn add(
+        test: &mut ProgramTest,
+        token_mint: Pubkey,
+        escrow: Pubkey,
+        amount: u64,
+        unstaking_amount: u64,
+        unstake_available_at: i64,
+    ) -> Self {
+        let operator = Keypair::new();
+        let (pubkey, bump_seed) = find_stake_address(&rndr::id(), &escrow, &operator.pubkey());
+        let (vault, _bump_seed) = find_stake_vault_address(&rndr::id(), &escrow);
+        let vault_associated_token = get_associated_token_address(&vault, &token_mint);
+
+        test.add_packable_account(
+            vault_associated_token,
+            u32::MAX as u64,
+            &Token {
+                mint: token_mint,
+                owner: vault,
+                amount: amount + unstaking_amount,
+                state: AccountState::Initialized,
+                is_native: COption::None,
+                ..Token::default()
+            },
+            &spl_token::id(),
+        );
+
+        let mut stake = Stake::new(InitStakeParams {
+            escrow,
+            operator: operator.pubkey(),
+            bump_seed,
+            rent_payer: operator.pubkey(),
+        });
+        stake.amount = amount;
+        stake.unstaking_amount = unstaking_amount;
+        stake.unstake_available_at = unstake_available_at;
+        test.add_packable_account(pubkey, u32::MAX as u64, &stake, &rndr::id());
+
+        Self {
+            pubkey,
+            operator,
+            vault_associated_token,
+        }
+    }
+
+    pub async fn get(&self, banks_client: &mut BanksClient) -> Stake {
+        get_stake(banks_client, self.pubkey).await
+    }
+}
+
+pub struct TestNodeEarnings {
+    pub pubkey: Pubkey,
+}
+
+impl TestNodeEarnings {
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        owner: Pubkey,
+        earned: u64,
+        payout_count: u64,
+    ) -> Self {
+        let (pubkey, bump_seed) = find_node_earnings_address(&rndr::id(), &escrow, &owner);
+
+        let mut node_earnings = NodeEarnings::new(InitNodeEarningsParams {
+            escrow,
+            owner,
+            bump_seed,
+            rent_payer: Pubkey::new_unique(),
+        });
+        node_earnings.earned = earned;
+        node_earnings.payout_count = payout_count;
+        if payout_count > 0 {
+            node_earnings.first_payout_slot = 1;
+            node_earnings.last_payout_slot = 1;
+        }
+        test.add_packable_account(pubkey, u32::MAX as u64, &node_earnings, &rndr::id());
+
+        Self { pubkey }
+    }
+
+    pub async fn get(&self, banks_client: &mut BanksClient) -> NodeEarnings {
+        get_node_earnings(banks_client, self.pubkey).await
+    }
+}
+
+pub struct TestReceipt {
+    pub pubkey: Pubkey,
+}
+
+impl TestReceipt {
+    pub fn add(test: &mut ProgramTest, job: Pubkey, generation: u64, payout_index: u64) -> Self {
+        let (pubkey, bump_seed) = find_receipt_address(&rndr::id(), &job, generation, payout_index);
+
+        let receipt = Receipt::new(InitReceiptParams {
+            job,
+            payout_index,
+            destination: Pubkey::new_unique(),
+            amount: 1,
+            slot: 1,
+            disburser: Pubkey::new_unique(),
+            reference: [0; 32],
+            bump_seed,
+            rent_payer: Pubkey::new_unique(),
+        });
+        test.add_packable_account(pubkey, u32::MAX as u64, &receipt, &rndr::id());
+
+        Self { pubkey }
+    }
+}
+
+pub struct TestIdempotencyMarker {
+    pub pubkey: Pubkey,
+    pub rent_payer: Keypair,
+}
+
+impl TestIdempotencyMarker {
+    pub fn add(test: &mut ProgramTest, job: Pubkey, key: &[u8], created_at: i64) -> Self {
+        let (pubkey, bump_seed) = find_idempotency_marker_address(&rndr::id(), &job, 0, key);
+        let rent_payer = Keypair::new();
+
+        let idempotency_marker = IdempotencyMarker::new(InitIdempotencyMarkerParams {
+            job,
+            key: key.to_vec(),
+            created_at,
+            bump_seed,
+            rent_payer: rent_payer.pubkey(),
+        });
+        test.add_packable_account(pubkey, u32::MAX as u64, &idempotency_marker, &rndr::id());
+
+        Self { pubkey, rent_payer }
+    }
+}
@@ -2,12 +2,17 @@
 
 mod helpers;
 
-use rndr::state::AccountType;
 use {
     helpers::*,
-    rndr::{instruction::init_escrow, processor::process_instruction},
+    rndr::{
+        instruction::{find_escrow_address, init_escrow},
+        processor::process_instruction,
+        state::AccountType,
+    },
     solana_program_test::*,
     solana_sdk::{
+        account::Account,
+        bpf_loader_upgradeable::{self, UpgradeableLoaderState},
         pubkey::Pubkey,
         signature::{Keypair, Signer},
         transaction::Transaction,
@@ -23,6 +28,8 @@ async fn test_success() {
 
     let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
     let owner = Keypair::new();
+    let upgrade_authority = Keypair::new();
+    add_program_data(&mut test, Some(upgrade_authority.pubkey()));
 
     let (mut banks_client, payer, recent_blockhash) = test.start().await;
 
@@ -32,14 +39,17 @@ async fn test_success() {
             owner.pubkey(),
             test_mint.pubkey,
             payer.pubkey(),
+            upgrade_authority.pubkey(),
+            None,
+            None,
         )],
         Some(&payer.pubkey()),
     );
-    transaction.sign(&[&payer], recent_blockhash);
+    transaction.sign(&[&payer, &upgrade_authority], recent_blockhash);
 
     assert!(banks_client.process_transaction(transaction).await.is_ok());
 
-    let (escrow_pubkey, _bump_seed) = Pubkey::find_program_address(
+    let (escrow_pubkey, bump_seed) = Pubkey::find_program_address(
         &[
             b"escrow",
             test_mint.pubkey.as_ref(),
@@ -52,4 +62,238 @@ async fn test_success() {
     assert_eq!(escrow.account_type, AccountType::EscrowV1);
     assert_eq!(escrow.owner, owner.pubkey());
     assert_eq!(escrow.amount, ZERO);
+    assert_eq!(escrow.bump_seed, bump_seed);
+    assert_eq!(escrow.namespace, Pubkey::default());
+}
+
+#[tokio::test]
+async fn test_namespace() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_legacy_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let owner = Keypair::new();
+    let namespace = Pubkey::new_unique();
+    let upgrade_authority = Keypair::new();
+    add_program_data(&mut test, Some(upgrade_authority.pubkey()));
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[init_escrow(
+            rndr::id(),
+            owner.pubkey(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            upgrade_authority.pubkey(),
+            Some(namespace),
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &upgrade_authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (escrow_pubkey, bump_seed) =
+        find_escrow_address(&rndr::id(), &test_mint.pubkey, Some(&namespace));
+    assert_ne!(escrow_pubkey, test_legacy_escrow.pubkey);
+
+    let escrow = get_escrow(&mut banks_client, escrow_pubkey).await;
+    assert_eq!(escrow.owner, owner.pubkey());
+    assert_eq!(escrow.bump_seed, bump_seed);
+    assert_eq!(escrow.namespace, namespace);
+
+    // The legacy escrow of the mint is left as it was
+    let legacy_escrow = test_legacy_escrow.get(&mut banks_client).await;
+    assert_eq!(legacy_escrow.owner, test_legacy_escrow.owner.pubkey());
+}
+
+#[tokio::test]
+async fn test_front_run() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let upgrade_authority = Keypair::new();
+    add_program_data(&mut test, Some(upgrade_authority.pubkey()));
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Anyone else trying to claim the escrow for the mint first must fail
+    let attacker = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[init_escrow(
+            rndr::id(),
+            attacker.pubkey(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            attacker.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &attacker], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let (escrow_pubkey, _bump_seed) = find_escrow_address(&rndr::id(), &test_mint.pubkey, None);
+    assert!(banks_client
+        .get_account(escrow_pubkey)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_fake_program_data() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let upgrade_authority = Keypair::new();
+    add_program_data(&mut test, Some(upgrade_authority.pubkey()));
+
+    // Program data naming the attacker as upgrade authority, at an address of their choosing
+    let attacker = Keypair::new();
+    let fake_program_data = Pubkey::new_unique();
+    let data = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(attacker.pubkey()),
+    })
+    .unwrap();
+    let mut account = Account::new(u32::MAX as u64, data.len(), &bpf_loader_upgradeable::id());
+    account.data = data;
+    test.add_account(fake_program_data, account);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut instruction = init_escrow(
+        rndr::id(),
+        attacker.pubkey(),
+        test_mint.pubkey,
+        payer.pubkey(),
+        attacker.pubkey(),
+        None,
+        None,
+    );
+    instruction.accounts[3].pubkey = fake_program_data;
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, &attacker], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_immutable_program() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    add_program_data(&mut test, None);
+    let authority = Keypair::new();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[init_escrow(
+            rndr::id(),
+            authority.pubkey(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            authority.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_program_config_admin() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let upgrade_authority = Keypair::new();
+    add_program_data(&mut test, Some(upgrade_authority.pubkey()));
+    let default_escrow_owner = Pubkey::new_unique();
+    let test_program_config = TestProgramConfig::add(
+        &mut test,
+        vec![test_mint.pubkey],
+        vec![spl_token::id()],
+        default_escrow_owner,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Escrows initialized without an owner get the default owner of the program config
+    let mut transaction = Transaction::new_with_payer(
+        &[init_escrow(
+            rndr::id(),
+            Pubkey::default(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_program_config.admin.pubkey(),
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_program_config.admin], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (escrow_pubkey, _bump_seed) = find_escrow_address(&rndr::id(), &test_mint.pubkey, None);
+    let escrow = get_escrow(&mut banks_client, escrow_pubkey).await;
+    assert_eq!(escrow.owner, default_escrow_owner);
+}
+
+#[tokio::test]
+async fn test_mint_not_allowed() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let upgrade_authority = Keypair::new();
+    add_program_data(&mut test, Some(upgrade_authority.pubkey()));
+    let test_program_config = TestProgramConfig::add(
+        &mut test,
+        vec![Pubkey::new_unique()],
+        vec![spl_token::id()],
+        Pubkey::new_unique(),
+    );
+    let owner = Keypair::new();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Neither the admin nor the upgrade authority can create an escrow for another mint
+    for authority in [&test_program_config.admin, &upgrade_authority] {
+        let mut transaction = Transaction::new_with_payer(
+            &[init_escrow(
+                rndr::id(),
+                owner.pubkey(),
+                test_mint.pubkey,
+                payer.pubkey(),
+                authority.pubkey(),
+                None,
+                None,
+            )],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer, authority], recent_blockhash);
+
+        assert!(banks_client.process_transaction(transaction).await.is_err());
+    }
 }
@@ -0,0 +1,160 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::{find_funding_schedule_address, init_funding_schedule},
+        processor::process_instruction,
+        state::AccountType,
+    },
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+    const PERIOD: i64 = 7 * 24 * 60 * 60;
+    const FIRST_DUE: i64 = 1_700_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, 0, None, 1);
+    let test_source_token = TestToken::add_with_delegate(
+        &mut test,
+        test_mint.pubkey,
+        10 * AMOUNT,
+        Some(test_escrow.pubkey),
+        10 * AMOUNT,
+    );
+    let authority = test_source_token.owner.pubkey();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[init_funding_schedule(
+            rndr::id(),
+            AMOUNT,
+            PERIOD,
+            FIRST_DUE,
+            test_mint.pubkey,
+            payer.pubkey(),
+            authority,
+            test_source_token.pubkey,
+            test_job.generation,
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (funding_schedule_pubkey, bump_seed) =
+        find_funding_schedule_address(&rndr::id(), &test_job.pubkey, test_job.generation);
+    let funding_schedule = get_funding_schedule(&mut banks_client, funding_schedule_pubkey).await;
+    assert_eq!(
+        funding_schedule.account_type,
+        AccountType::FundingScheduleV1
+    );
+    assert_eq!(funding_schedule.escrow, test_escrow.pubkey);
+    assert_eq!(funding_schedule.job, test_job.pubkey);
+    assert_eq!(funding_schedule.authority, authority);
+    assert_eq!(funding_schedule.source_token, test_source_token.pubkey);
+    assert_eq!(funding_schedule.amount, AMOUNT);
+    assert_eq!(funding_schedule.period, PERIOD);
+    assert_eq!(funding_schedule.next_due, FIRST_DUE);
+    assert!(!funding_schedule.paused);
+    assert_eq!(funding_schedule.bump_seed, bump_seed);
+    assert_eq!(funding_schedule.rent_payer, payer.pubkey());
+}
+
+#[tokio::test]
+async fn test_escrow_not_delegate() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, 0, None, 1);
+    let test_source_token = TestToken::add_with_delegate(
+        &mut test,
+        test_mint.pubkey,
+        10 * AMOUNT,
+        Some(Pubkey::new_unique()),
+        10 * AMOUNT,
+    );
+    let authority = test_source_token.owner.pubkey();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[init_funding_schedule(
+            rndr::id(),
+            AMOUNT,
+            60,
+            0,
+            test_mint.pubkey,
+            payer.pubkey(),
+            authority,
+            test_source_token.pubkey,
+            test_job.generation,
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_invalid_period() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_escrow(&mut test, test_mint.pubkey, 0, None, 1);
+    let test_source_token = TestToken::add_with_delegate(
+        &mut test,
+        test_mint.pubkey,
+        10 * AMOUNT,
+        Some(test_escrow.pubkey),
+        10 * AMOUNT,
+    );
+    let authority = test_source_token.owner.pubkey();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[init_funding_schedule(
+            rndr::id(),
+            AMOUNT,
+            0,
+            0,
+            test_mint.pubkey,
+            payer.pubkey(),
+            authority,
+            test_source_token.pubkey,
+            test_job.generation,
+            None,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::{find_program_config_address, init_program_config},
+        processor::process_instruction,
+        state::AccountType,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let upgrade_authority = Keypair::new();
+    add_program_data(&mut test, Some(upgrade_authority.pubkey()));
+    let admin = Pubkey::new_unique();
+    let token_mint = Pubkey::new_unique();
+    let default_escrow_owner = Pubkey::new_unique();
+    let treasury = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[init_program_config(
+            rndr::id(),
+            admin,
+            vec![token_mint],
+            vec![spl_token::id()],
+            default_escrow_owner,
+            treasury,
+            payer.pubkey(),
+            upgrade_authority.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &upgrade_authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (program_config_pubkey, bump_seed) = find_program_config_address(&rndr::id());
+    let program_config = get_program_config(&mut banks_client, program_config_pubkey).await;
+
+    assert_eq!(program_config.account_type, AccountType::ProgramConfigV1);
+    assert_eq!(program_config.admin, admin);
+    assert_eq!(program_config.allowed_mints, vec![token_mint]);
+    assert_eq!(program_config.allowed_token_programs, vec![spl_token::id()]);
+    assert_eq!(program_config.default_escrow_owner, default_escrow_owner);
+    assert_eq!(program_config.treasury, treasury);
+    assert_eq!(program_config.bump_seed, bump_seed);
+}
+
+#[tokio::test]
+async fn test_not_upgrade_authority() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let upgrade_authority = Keypair::new();
+    add_program_data(&mut test, Some(upgrade_authority.pubkey()));
+    let attacker = Keypair::new();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[init_program_config(
+            rndr::id(),
+            attacker.pubkey(),
+            vec![Pubkey::new_unique()],
+            vec![spl_token::id()],
+            attacker.pubkey(),
+            attacker.pubkey(),
+            payer.pubkey(),
+            attacker.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &attacker], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
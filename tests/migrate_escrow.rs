@@ -0,0 +1,146 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::{find_escrow_address, migrate_escrow},
+        processor::process_instruction,
+    },
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let old_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let new_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, old_mint.pubkey, DECIMALS);
+    let (new_escrow, bump_seed) = find_escrow_address(&rndr::id(), &new_mint.pubkey, None);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_escrow(
+            rndr::id(),
+            old_mint.pubkey,
+            new_mint.pubkey,
+            test_escrow.owner.pubkey(),
+            payer.pubkey(),
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.successor, new_escrow);
+    assert_eq!(escrow.amount, DECIMALS);
+
+    let escrow = get_escrow(&mut banks_client, new_escrow).await;
+    assert_eq!(escrow.owner, test_escrow.owner.pubkey());
+    assert_eq!(escrow.bump_seed, bump_seed);
+    assert_eq!(escrow.namespace, Pubkey::default());
+    assert_eq!(escrow.amount, 0);
+}
+
+#[tokio::test]
+async fn test_already_migrating() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let old_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let new_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_with_successor(
+        &mut test,
+        old_mint.pubkey,
+        0,
+        None,
+        0,
+        Some(Pubkey::new_unique()),
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_escrow(
+            rndr::id(),
+            old_mint.pubkey,
+            new_mint.pubkey,
+            test_escrow.owner.pubkey(),
+            payer.pubkey(),
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_decimals_mismatch() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let old_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let new_mint = TestMint::add(&mut test, 6, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, old_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_escrow(
+            rndr::id(),
+            old_mint.pubkey,
+            new_mint.pubkey,
+            test_escrow.owner.pubkey(),
+            payer.pubkey(),
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_not_owner() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let old_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let new_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, old_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_escrow(
+            rndr::id(),
+            old_mint.pubkey,
+            new_mint.pubkey,
+            payer.pubkey(),
+            payer.pubkey(),
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.successor, Pubkey::default());
+}
@@ -0,0 +1,409 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::{find_escrow_address, find_job_address, migrate_job},
+        processor::process_instruction,
+    },
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let old_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let new_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let (new_escrow, _bump_seed) = find_escrow_address(&rndr::id(), &new_mint.pubkey, None);
+    let old_escrow = TestEscrow::add_with_successor(
+        &mut test,
+        old_mint.pubkey,
+        AMOUNT,
+        None,
+        1,
+        Some(new_escrow),
+    );
+    let new_escrow = TestEscrow::add(&mut test, new_mint.pubkey, 0);
+    let authority = Pubkey::new_unique();
+    let old_job = TestJob::add(&mut test, old_escrow.pubkey, authority, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, old_mint.pubkey, 0);
+    let test_source_token = TestToken::add(&mut test, new_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_job(
+            rndr::id(),
+            old_mint.pubkey,
+            new_mint.pubkey,
+            old_escrow.owner.pubkey(),
+            authority,
+            test_destination_token.pubkey,
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+            payer.pubkey(),
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(
+        &[&payer, &old_escrow.owner, &test_source_token.owner],
+        recent_blockhash,
+    );
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, old_escrow.associated_token).await,
+        0
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, new_escrow.associated_token).await,
+        AMOUNT
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await,
+        0
+    );
+
+    let escrow = old_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, 0);
+    assert_eq!(escrow.job_count, 1);
+    let job = old_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, 0);
+
+    let escrow = new_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, AMOUNT);
+    assert_eq!(escrow.job_count, 1);
+    let (new_job, _bump_seed) = find_job_address(&rndr::id(), &new_escrow.pubkey, &authority);
+    let job = get_job(&mut banks_client, new_job).await;
+    assert_eq!(job.authority, authority);
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.rent_payer, payer.pubkey());
+}
+
+#[tokio::test]
+async fn test_existing_job() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let old_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let new_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let (new_escrow, _bump_seed) = find_escrow_address(&rndr::id(), &new_mint.pubkey, None);
+    let old_escrow = TestEscrow::add_with_successor(
+        &mut test,
+        old_mint.pubkey,
+        AMOUNT,
+        None,
+        1,
+        Some(new_escrow),
+    );
+    let new_escrow = TestEscrow::add_escrow(&mut test, new_mint.pubkey, AMOUNT, None, 1);
+    let authority = Pubkey::new_unique();
+    let old_job = TestJob::add(&mut test, old_escrow.pubkey, authority, AMOUNT);
+    let new_job = TestJob::add(&mut test, new_escrow.pubkey, authority, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, old_mint.pubkey, 0);
+    let test_source_token = TestToken::add(&mut test, new_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_job(
+            rndr::id(),
+            old_mint.pubkey,
+            new_mint.pubkey,
+            old_escrow.owner.pubkey(),
+            authority,
+            test_destination_token.pubkey,
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+            payer.pubkey(),
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(
+        &[&payer, &old_escrow.owner, &test_source_token.owner],
+        recent_blockhash,
+    );
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow = old_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, 0);
+    let job = old_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, 0);
+
+    let escrow = new_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, 2 * AMOUNT);
+    assert_eq!(escrow.job_count, 1);
+    let job = new_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, 2 * AMOUNT);
+}
+
+#[tokio::test]
+async fn test_not_migrating() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let old_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let new_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let old_escrow = TestEscrow::add_escrow(&mut test, old_mint.pubkey, AMOUNT, None, 1);
+    let new_escrow = TestEscrow::add_escrow(&mut test, new_mint.pubkey, 0, None, 1);
+    let authority = Pubkey::new_unique();
+    TestJob::add(&mut test, old_escrow.pubkey, authority, AMOUNT);
+    TestJob::add(&mut test, new_escrow.pubkey, authority, 0);
+    let test_destination_token = TestToken::add(&mut test, old_mint.pubkey, 0);
+    let test_source_token = TestToken::add(&mut test, new_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_job(
+            rndr::id(),
+            old_mint.pubkey,
+            new_mint.pubkey,
+            old_escrow.owner.pubkey(),
+            authority,
+            test_destination_token.pubkey,
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+            payer.pubkey(),
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(
+        &[&payer, &old_escrow.owner, &test_source_token.owner],
+        recent_blockhash,
+    );
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_reserved_funds() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let old_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let new_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let (new_escrow, _bump_seed) = find_escrow_address(&rndr::id(), &new_mint.pubkey, None);
+    let old_escrow = TestEscrow::add_with_successor(
+        &mut test,
+        old_mint.pubkey,
+        AMOUNT,
+        None,
+        1,
+        Some(new_escrow),
+    );
+    let new_escrow = TestEscrow::add_escrow(&mut test, new_mint.pubkey, 0, None, 1);
+    let authority = Pubkey::new_unique();
+    let mut job = TestJob::new_job(old_escrow.pubkey, authority);
+    job.amount = AMOUNT;
+    job.reserved = AMOUNT;
+    TestJob::add_job(&mut test, old_escrow.pubkey, job);
+    TestJob::add(&mut test, new_escrow.pubkey, authority, 0);
+    let test_destination_token = TestToken::add(&mut test, old_mint.pubkey, 0);
+    let test_source_token = TestToken::add(&mut test, new_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_job(
+            rndr::id(),
+            old_mint.pubkey,
+            new_mint.pubkey,
+            old_escrow.owner.pubkey(),
+            authority,
+            test_destination_token.pubkey,
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+            payer.pubkey(),
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(
+        &[&payer, &old_escrow.owner, &test_source_token.owner],
+        recent_blockhash,
+    );
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_milestones() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let old_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let new_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let (new_escrow, _bump_seed) = find_escrow_address(&rndr::id(), &new_mint.pubkey, None);
+    let old_escrow = TestEscrow::add_with_successor(
+        &mut test,
+        old_mint.pubkey,
+        AMOUNT,
+        None,
+        1,
+        Some(new_escrow),
+    );
+    let new_escrow = TestEscrow::add_escrow(&mut test, new_mint.pubkey, 0, None, 1);
+    let authority = Pubkey::new_unique();
+    let mut job = TestJob::new_job(old_escrow.pubkey, authority);
+    job.amount = AMOUNT;
+    job.milestone_count = 1;
+    TestJob::add_job(&mut test, old_escrow.pubkey, job);
+    TestJob::add(&mut test, new_escrow.pubkey, authority, 0);
+    let test_destination_token = TestToken::add(&mut test, old_mint.pubkey, 0);
+    let test_source_token = TestToken::add(&mut test, new_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_job(
+            rndr::id(),
+            old_mint.pubkey,
+            new_mint.pubkey,
+            old_escrow.owner.pubkey(),
+            authority,
+            test_destination_token.pubkey,
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+            payer.pubkey(),
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(
+        &[&payer, &old_escrow.owner, &test_source_token.owner],
+        recent_blockhash,
+    );
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_submissions_required() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let old_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let new_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let (new_escrow, _bump_seed) = find_escrow_address(&rndr::id(), &new_mint.pubkey, None);
+    let old_escrow = TestEscrow::add_with_successor(
+        &mut test,
+        old_mint.pubkey,
+        AMOUNT,
+        None,
+        1,
+        Some(new_escrow),
+    );
+    let new_escrow = TestEscrow::add_escrow(&mut test, new_mint.pubkey, 0, None, 1);
+    let authority = Pubkey::new_unique();
+    let mut job = TestJob::new_job(old_escrow.pubkey, authority);
+    job.amount = AMOUNT;
+    job.submissions_required = true;
+    TestJob::add_job(&mut test, old_escrow.pubkey, job);
+    TestJob::add(&mut test, new_escrow.pubkey, authority, 0);
+    let test_destination_token = TestToken::add(&mut test, old_mint.pubkey, 0);
+    let test_source_token = TestToken::add(&mut test, new_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_job(
+            rndr::id(),
+            old_mint.pubkey,
+            new_mint.pubkey,
+            old_escrow.owner.pubkey(),
+            authority,
+            test_destination_token.pubkey,
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+            payer.pubkey(),
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(
+        &[&payer, &old_escrow.owner, &test_source_token.owner],
+        recent_blockhash,
+    );
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_priced_per_frame() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let old_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let new_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let (new_escrow, _bump_seed) = find_escrow_address(&rndr::id(), &new_mint.pubkey, None);
+    let old_escrow = TestEscrow::add_with_successor(
+        &mut test,
+        old_mint.pubkey,
+        AMOUNT,
+        None,
+        1,
+        Some(new_escrow),
+    );
+    let new_escrow = TestEscrow::add_escrow(&mut test, new_mint.pubkey, 0, None, 1);
+    let authority = Pubkey::new_unique();
+    let mut job = TestJob::new_job(old_escrow.pubkey, authority);
+    job.amount = AMOUNT;
+    job.priced_per_frame = true;
+    TestJob::add_job(&mut test, old_escrow.pubkey, job);
+    TestJob::add(&mut test, new_escrow.pubkey, authority, 0);
+    let test_destination_token = TestToken::add(&mut test, old_mint.pubkey, 0);
+    let test_source_token = TestToken::add(&mut test, new_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_job(
+            rndr::id(),
+            old_mint.pubkey,
+            new_mint.pubkey,
+            old_escrow.owner.pubkey(),
+            authority,
+            test_destination_token.pubkey,
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+            payer.pubkey(),
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(
+        &[&payer, &old_escrow.owner, &test_source_token.owner],
+        recent_blockhash,
+    );
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
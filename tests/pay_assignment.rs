@@ -0,0 +1,210 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::pay_assignment, processor::process_instruction},
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_assignment = TestAssignment::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        test_node_token.owner.pubkey(),
+        AMOUNT,
+        i64::MAX,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[pay_assignment(
+            rndr::id(),
+            AMOUNT / 4,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_assignment.pubkey,
+            test_node_token.pubkey,
+            test_assignment.rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_node_token.pubkey).await,
+        AMOUNT / 4
+    );
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, AMOUNT - AMOUNT / 4);
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.reserved, AMOUNT - AMOUNT / 4);
+    assert_eq!(job.disbursed, AMOUNT / 4);
+    let assignment = test_assignment.get(&mut banks_client).await;
+    assert_eq!(assignment.amount, AMOUNT - AMOUNT / 4);
+}
+
+#[tokio::test]
+async fn test_fully_paid() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_assignment = TestAssignment::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        test_node_token.owner.pubkey(),
+        AMOUNT,
+        i64::MAX,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[pay_assignment(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_assignment.pubkey,
+            test_node_token.pubkey,
+            test_assignment.rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_node_token.pubkey).await,
+        AMOUNT
+    );
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.reserved, 0);
+    assert_eq!(job.disbursed, AMOUNT);
+
+    assert!(banks_client
+        .get_account(test_assignment.pubkey)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_exceeds_reservation() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_assignment = TestAssignment::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        test_node_token.owner.pubkey(),
+        AMOUNT,
+        i64::MAX,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[pay_assignment(
+            rndr::id(),
+            AMOUNT + 1,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_assignment.pubkey,
+            test_node_token.pubkey,
+            test_assignment.rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_wrong_node_token() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_assignment = TestAssignment::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        Pubkey::new_unique(),
+        AMOUNT,
+        i64::MAX,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[pay_assignment(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_assignment.pubkey,
+            test_node_token.pubkey,
+            test_assignment.rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
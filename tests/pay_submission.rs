@@ -0,0 +1,292 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::pay_submission, processor::process_instruction, state::SubmissionStatus},
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_render_submission = TestRenderSubmission::add(
+        &mut test,
+        test_job.pubkey,
+        test_node_token.owner.pubkey(),
+        AMOUNT,
+        SubmissionStatus::Accepted,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[pay_submission(
+            rndr::id(),
+            AMOUNT / 2,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_render_submission.pubkey,
+            test_node_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_node_token.pubkey).await,
+        AMOUNT / 2
+    );
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, AMOUNT / 2);
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT / 2);
+    assert_eq!(job.disbursed, AMOUNT / 2);
+    let render_submission = test_render_submission.get(&mut banks_client).await;
+    assert_eq!(render_submission.paid, AMOUNT / 2);
+}
+
+#[tokio::test]
+async fn test_tier_multiplier() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 2 * AMOUNT);
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.amount = 2 * AMOUNT;
+    job.tier = 2;
+    job.tier_multiplier = 15_000;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_render_submission = TestRenderSubmission::add(
+        &mut test,
+        test_job.pubkey,
+        test_node_token.owner.pubkey(),
+        AMOUNT,
+        SubmissionStatus::Accepted,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[pay_submission(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_render_submission.pubkey,
+            test_node_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The submission is priced before the 1.5x multiplier of tier 2
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_node_token.pubkey).await,
+        AMOUNT * 3 / 2
+    );
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT / 2);
+    assert_eq!(job.disbursed, AMOUNT * 3 / 2);
+    let render_submission = test_render_submission.get(&mut banks_client).await;
+    assert_eq!(render_submission.paid, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_exceeds_price() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_render_submission = TestRenderSubmission::add(
+        &mut test,
+        test_job.pubkey,
+        test_node_token.owner.pubkey(),
+        AMOUNT / 2,
+        SubmissionStatus::Accepted,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[pay_submission(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_render_submission.pubkey,
+            test_node_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_not_accepted() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_render_submission = TestRenderSubmission::add(
+        &mut test,
+        test_job.pubkey,
+        test_node_token.owner.pubkey(),
+        AMOUNT,
+        SubmissionStatus::Pending,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[pay_submission(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_render_submission.pubkey,
+            test_node_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_wrong_node_token() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_render_submission = TestRenderSubmission::add(
+        &mut test,
+        test_job.pubkey,
+        Pubkey::new_unique(),
+        AMOUNT,
+        SubmissionStatus::Accepted,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[pay_submission(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_render_submission.pubkey,
+            test_node_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_closed_job_submission() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    // The job was created again after a job of the same address was closed
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.amount = AMOUNT;
+    job.generation = 1;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_render_submission = TestRenderSubmission::add(
+        &mut test,
+        test_job.pubkey,
+        test_node_token.owner.pubkey(),
+        AMOUNT,
+        SubmissionStatus::Accepted,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[pay_submission(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_render_submission.pubkey,
+            test_node_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert_eq!(
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidSeeds)
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_node_token.pubkey).await,
+        0
+    );
+}
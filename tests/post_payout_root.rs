@@ -0,0 +1,74 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::post_payout_root, processor::process_instruction, state::AccountType},
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 10 * DECIMALS;
+    const PAYOUT_AMOUNT: u64 = 6 * DECIMALS;
+    const ROOT: [u8; 32] = [9; 32];
+    const LEAF_COUNT: u32 = 1_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[post_payout_root(
+            rndr::id(),
+            ROOT,
+            PAYOUT_AMOUNT,
+            LEAF_COUNT,
+            i64::MAX,
+            payer.pubkey(),
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (payout_root_pubkey, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"payout_root",
+            test_job.pubkey.as_ref(),
+            &test_job.generation.to_le_bytes(),
+            &ROOT,
+        ],
+        &rndr::id(),
+    );
+    let payout_root = get_payout_root(&mut banks_client, payout_root_pubkey).await;
+
+    assert_eq!(payout_root.account_type, AccountType::PayoutRootV1);
+    assert_eq!(payout_root.escrow, test_escrow.pubkey);
+    assert_eq!(payout_root.job, test_job.pubkey);
+    assert_eq!(payout_root.root, ROOT);
+    assert_eq!(payout_root.amount, PAYOUT_AMOUNT);
+    assert_eq!(payout_root.claimed_amount, ZERO);
+    assert_eq!(payout_root.leaf_count, LEAF_COUNT);
+    assert_eq!(payout_root.rent_payer, payer.pubkey());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT - PAYOUT_AMOUNT);
+    assert_eq!(job.reserved, PAYOUT_AMOUNT);
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, AMOUNT);
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::{find_pending_payout_address, post_pending_payout},
+        processor::process_instruction,
+        state::{AccountType, PAYOUT_CHALLENGE_PERIOD},
+    },
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+    const NONCE: u64 = 7;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 2 * AMOUNT);
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        Pubkey::new_unique(),
+        2 * AMOUNT,
+    );
+    let destination = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[post_pending_payout(
+            rndr::id(),
+            NONCE,
+            AMOUNT,
+            payer.pubkey(),
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+            destination,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.reserved, AMOUNT);
+
+    let (pending_payout_pubkey, bump_seed) =
+        find_pending_payout_address(&rndr::id(), &test_job.pubkey, test_job.generation, NONCE);
+    let pending_payout = get_pending_payout(&mut banks_client, pending_payout_pubkey).await;
+    assert_eq!(pending_payout.account_type, AccountType::PendingPayoutV1);
+    assert_eq!(pending_payout.escrow, test_escrow.pubkey);
+    assert_eq!(pending_payout.job, test_job.pubkey);
+    assert_eq!(pending_payout.destination, destination);
+    assert_eq!(pending_payout.amount, AMOUNT);
+    assert!(pending_payout.finalize_after >= PAYOUT_CHALLENGE_PERIOD);
+    assert_eq!(pending_payout.nonce, NONCE);
+    assert_eq!(pending_payout.bump_seed, bump_seed);
+    assert_eq!(pending_payout.rent_payer, payer.pubkey());
+}
+
+#[tokio::test]
+async fn test_not_escrow_owner() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[post_pending_payout(
+            rndr::id(),
+            0,
+            AMOUNT,
+            payer.pubkey(),
+            test_escrow.pubkey,
+            payer.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+            Pubkey::new_unique(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
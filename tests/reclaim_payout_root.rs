@@ -0,0 +1,63 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::reclaim_payout_root, processor::process_instruction},
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 10 * DECIMALS;
+    const PAYOUT_AMOUNT: u64 = 6 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.amount = AMOUNT - PAYOUT_AMOUNT;
+    job.reserved = PAYOUT_AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+
+    let test_payout_root = TestPayoutRoot::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        [9; 32],
+        PAYOUT_AMOUNT,
+        1_000,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[reclaim_payout_root(
+            rndr::id(),
+            test_job.pubkey,
+            test_payout_root.pubkey,
+            test_payout_root.rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.reserved, ZERO);
+
+    let payout_root_account = banks_client
+        .get_account(test_payout_root.pubkey)
+        .await
+        .unwrap();
+    assert!(payout_root_account.is_none());
+}
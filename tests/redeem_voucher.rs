@@ -0,0 +1,116 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::redeem_voucher,
+        processor::process_instruction,
+        state::AccountType,
+        voucher::{ed25519_program, new_ed25519_instruction, Voucher},
+    },
+    solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, program_pack::Pack},
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+    std::convert::TryInto,
+};
+
+/// Stand-in for the ed25519 signature verification program, which isn't loaded by the test
+/// runtime. The program only reads the instruction through the instructions sysvar.
+fn process_ed25519_instruction(
+    _program_id: &Pubkey,
+    _accounts: &[AccountInfo],
+    _input: &[u8],
+) -> ProgramResult {
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    test.add_program(
+        "ed25519",
+        ed25519_program::id(),
+        processor!(process_ed25519_instruction),
+    );
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 10 * DECIMALS;
+    const VOUCHER_AMOUNT: u64 = 3 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let voucher = Voucher {
+        escrow: test_escrow.pubkey,
+        job: test_job.pubkey,
+        destination: test_destination_token.pubkey,
+        amount: VOUCHER_AMOUNT,
+        nonce: 1,
+        expiry: i64::MAX,
+    };
+    let mut message = [0; Voucher::LEN];
+    voucher.pack_into_slice(&mut message);
+    let signature = test_escrow.owner.sign_message(&message);
+
+    let instructions = [
+        new_ed25519_instruction(
+            &test_escrow.owner.pubkey(),
+            signature.as_ref().try_into().unwrap(),
+            &message,
+        ),
+        redeem_voucher(
+            rndr::id(),
+            voucher.clone(),
+            test_mint.pubkey,
+            payer.pubkey(),
+        ),
+    ];
+
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let destination_token_balance =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+    assert_eq!(destination_token_balance, VOUCHER_AMOUNT);
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT - VOUCHER_AMOUNT);
+    assert_eq!(job.disbursed, VOUCHER_AMOUNT);
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, AMOUNT - VOUCHER_AMOUNT);
+
+    let (redeemed_voucher_pubkey, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"voucher",
+            test_escrow.pubkey.as_ref(),
+            &voucher.nonce.to_le_bytes(),
+        ],
+        &rndr::id(),
+    );
+    let redeemed_voucher = get_redeemed_voucher(&mut banks_client, redeemed_voucher_pubkey).await;
+    assert_eq!(
+        redeemed_voucher.account_type,
+        AccountType::RedeemedVoucherV1
+    );
+    assert_eq!(redeemed_voucher.nonce, voucher.nonce);
+
+    // The same voucher can't be redeemed twice
+    let (recent_blockhash, _fee_calculator) = banks_client
+        .get_new_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
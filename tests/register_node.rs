@@ -0,0 +1,107 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::{find_node_address, register_node},
+        processor::process_instruction,
+        state::NodeStatus,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_payout_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[register_node(
+            rndr::id(),
+            2,
+            payer.pubkey(),
+            test_payout_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (node_pubkey, _bump_seed) = find_node_address(&rndr::id(), &payer.pubkey());
+    let node = get_node(&mut banks_client, node_pubkey).await;
+    assert_eq!(node.operator, payer.pubkey());
+    assert_eq!(node.payout_token, test_payout_token.pubkey);
+    assert_eq!(node.tier, 2);
+    assert_eq!(node.status, NodeStatus::Active);
+    assert_eq!(node.payout_count, 0);
+    assert_eq!(node.earned, 0);
+}
+
+#[tokio::test]
+async fn test_already_registered() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_payout_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_node = TestNode::add(&mut test, test_payout_token.pubkey, NodeStatus::Suspended);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[register_node(
+            rndr::id(),
+            0,
+            test_node.operator.pubkey(),
+            test_payout_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_node.operator], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let node = test_node.get(&mut banks_client).await;
+    assert_eq!(node.status, NodeStatus::Suspended);
+}
+
+#[tokio::test]
+async fn test_invalid_tier() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_payout_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let operator = Keypair::new();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[register_node(
+            rndr::id(),
+            4,
+            operator.pubkey(),
+            test_payout_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &operator], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
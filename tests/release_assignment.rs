@@ -0,0 +1,98 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::release_assignment, processor::process_instruction},
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_assignment = TestAssignment::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        Pubkey::new_unique(),
+        AMOUNT,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[release_assignment(
+            rndr::id(),
+            test_job.pubkey,
+            test_assignment.pubkey,
+            test_assignment.rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.reserved, 0);
+
+    assert!(banks_client
+        .get_account(test_assignment.pubkey)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_not_expired() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let mut job = TestJob::new_job(test_escrow.pubkey, Pubkey::new_unique());
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    let test_assignment = TestAssignment::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        Pubkey::new_unique(),
+        AMOUNT,
+        i64::MAX,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[release_assignment(
+            rndr::id(),
+            test_job.pubkey,
+            test_assignment.pubkey,
+            test_assignment.rent_payer,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, 0);
+    assert_eq!(job.reserved, AMOUNT);
+}
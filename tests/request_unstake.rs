@@ -0,0 +1,86 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::request_unstake, processor::process_instruction, state::UNSTAKE_COOLDOWN},
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let test_stake = TestStake::add(
+        &mut test,
+        test_mint.pubkey,
+        test_escrow.pubkey,
+        AMOUNT,
+        0,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[request_unstake(
+            rndr::id(),
+            AMOUNT / 4,
+            test_stake.operator.pubkey(),
+            test_escrow.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_stake.operator], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let stake = test_stake.get(&mut banks_client).await;
+    assert_eq!(stake.amount, AMOUNT - AMOUNT / 4);
+    assert_eq!(stake.unstaking_amount, AMOUNT / 4);
+    assert!(stake.unstake_available_at >= UNSTAKE_COOLDOWN);
+}
+
+#[tokio::test]
+async fn test_exceeds_stake() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let test_stake = TestStake::add(
+        &mut test,
+        test_mint.pubkey,
+        test_escrow.pubkey,
+        AMOUNT,
+        0,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[request_unstake(
+            rndr::id(),
+            AMOUNT + 1,
+            test_stake.operator.pubkey(),
+            test_escrow.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_stake.operator], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let stake = test_stake.get(&mut banks_client).await;
+    assert_eq!(stake.amount, AMOUNT);
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::resume_funding_schedule, processor::process_instruction},
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let authority = Keypair::new();
+    let job = Pubkey::new_unique();
+    let test_funding_schedule = TestFundingSchedule::add(
+        &mut test,
+        Pubkey::new_unique(),
+        job,
+        authority.pubkey(),
+        Pubkey::new_unique(),
+        1,
+        0,
+        true,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[resume_funding_schedule(
+            rndr::id(),
+            authority.pubkey(),
+            job,
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let funding_schedule = test_funding_schedule.get(&mut banks_client).await;
+    assert!(!funding_schedule.paused);
+    // Periods missed while paused are skipped
+    assert!(funding_schedule.next_due > 0);
+}
+
+#[tokio::test]
+async fn test_not_paused() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let authority = Keypair::new();
+    let job = Pubkey::new_unique();
+    TestFundingSchedule::add(
+        &mut test,
+        Pubkey::new_unique(),
+        job,
+        authority.pubkey(),
+        Pubkey::new_unique(),
+        1,
+        0,
+        false,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[resume_funding_schedule(
+            rndr::id(),
+            authority.pubkey(),
+            job,
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_not_authority() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let job = Pubkey::new_unique();
+    TestFundingSchedule::add(
+        &mut test,
+        Pubkey::new_unique(),
+        job,
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        1,
+        0,
+        true,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[resume_funding_schedule(rndr::id(), payer.pubkey(), job, 0)],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::review_submission, processor::process_instruction, state::SubmissionStatus,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_accept() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+    let test_render_submission = TestRenderSubmission::add(
+        &mut test,
+        test_job.pubkey,
+        Pubkey::new_unique(),
+        AMOUNT,
+        SubmissionStatus::Pending,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[review_submission(
+            rndr::id(),
+            true,
+            authority.pubkey(),
+            test_job.pubkey,
+            test_render_submission.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let render_submission = test_render_submission.get(&mut banks_client).await;
+    assert_eq!(render_submission.status, SubmissionStatus::Accepted);
+}
+
+#[tokio::test]
+async fn test_reject() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+    let test_render_submission = TestRenderSubmission::add(
+        &mut test,
+        test_job.pubkey,
+        Pubkey::new_unique(),
+        AMOUNT,
+        SubmissionStatus::Pending,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[review_submission(
+            rndr::id(),
+            false,
+            authority.pubkey(),
+            test_job.pubkey,
+            test_render_submission.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let render_submission = test_render_submission.get(&mut banks_client).await;
+    assert_eq!(render_submission.status, SubmissionStatus::Rejected);
+}
+
+#[tokio::test]
+async fn test_already_reviewed() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+    let test_render_submission = TestRenderSubmission::add(
+        &mut test,
+        test_job.pubkey,
+        Pubkey::new_unique(),
+        AMOUNT,
+        SubmissionStatus::Rejected,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[review_submission(
+            rndr::id(),
+            true,
+            authority.pubkey(),
+            test_job.pubkey,
+            test_render_submission.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_wrong_authority() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let test_render_submission = TestRenderSubmission::add(
+        &mut test,
+        test_job.pubkey,
+        Pubkey::new_unique(),
+        AMOUNT,
+        SubmissionStatus::Pending,
+    );
+    let authority = Keypair::new();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[review_submission(
+            rndr::id(),
+            true,
+            authority.pubkey(),
+            test_job.pubkey,
+            test_render_submission.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,146 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::set_job_metadata, processor::process_instruction, state::AccountType},
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+    const SCENE_HASH: [u8; 32] = [7; 32];
+    const CONTENT_ID: &[u8] = b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_job_metadata(
+            rndr::id(),
+            SCENE_HASH,
+            CONTENT_ID.to_vec(),
+            1,
+            240,
+            payer.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+            authority.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (job_metadata_pubkey, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"job_metadata",
+            test_job.pubkey.as_ref(),
+            &test_job.generation.to_le_bytes(),
+        ],
+        &rndr::id(),
+    );
+    let job_metadata = get_job_metadata(&mut banks_client, job_metadata_pubkey).await;
+
+    assert_eq!(job_metadata.account_type, AccountType::JobMetadataV1);
+    assert_eq!(job_metadata.job, test_job.pubkey);
+    assert_eq!(job_metadata.scene_hash, SCENE_HASH);
+    assert_eq!(job_metadata.content_id, CONTENT_ID);
+    assert_eq!(job_metadata.frame_start, 1);
+    assert_eq!(job_metadata.frame_end, 240);
+}
+
+#[tokio::test]
+async fn test_immutable_after_disbursal() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let mut job = TestJob::new_job(test_escrow.pubkey, authority.pubkey());
+    job.amount = AMOUNT;
+    job.disbursed = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_job_metadata(
+            rndr::id(),
+            [7; 32],
+            vec![],
+            1,
+            240,
+            payer.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+            authority.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_pending_payout() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let mut job = TestJob::new_job(test_escrow.pubkey, authority.pubkey());
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+    TestPendingPayout::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job.pubkey,
+        Pubkey::new_unique(),
+        AMOUNT,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_job_metadata(
+            rndr::id(),
+            [7; 32],
+            vec![],
+            1,
+            240,
+            payer.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+            authority.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    // Funds reserved for the pending payout are committed to the current metadata
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
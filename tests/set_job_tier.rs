@@ -0,0 +1,175 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::set_job_tier, processor::process_instruction},
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_with_tier_multipliers(
+        &mut test,
+        test_mint.pubkey,
+        AMOUNT,
+        None,
+        1,
+        None,
+        [10_000, 15_000, 20_000],
+    );
+    let authority = Keypair::new();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_job_tier(
+            rndr::id(),
+            2,
+            test_escrow.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.tier, 2);
+    assert_eq!(job.tier_multiplier, 15_000);
+}
+
+#[tokio::test]
+async fn test_invalid_tier() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_job_tier(
+            rndr::id(),
+            4,
+            test_escrow.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_in_progress() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let mut job = TestJob::new_job(test_escrow.pubkey, authority.pubkey());
+    job.amount = AMOUNT;
+    job.disbursed = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The escrow owner must consent once funds have been disbursed
+    let mut transaction = Transaction::new_with_payer(
+        &[set_job_tier(
+            rndr::id(),
+            2,
+            test_escrow.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_job_tier(
+            rndr::id(),
+            2,
+            test_escrow.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            Some(test_escrow.owner.pubkey()),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.tier, 2);
+}
+
+#[tokio::test]
+async fn test_pending_payouts() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let mut job = TestJob::new_job(test_escrow.pubkey, authority.pubkey());
+    job.amount = AMOUNT;
+    job.reserved = AMOUNT;
+    let test_job = TestJob::add_job(&mut test, test_escrow.pubkey, job);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Reserved funds are paid out with the multiplier they were reserved with
+    let mut transaction = Transaction::new_with_payer(
+        &[set_job_tier(
+            rndr::id(),
+            2,
+            test_escrow.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            Some(test_escrow.owner.pubkey()),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.tier, 0);
+}
@@ -0,0 +1,256 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::slash_stake, processor::process_instruction},
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let test_stake = TestStake::add(
+        &mut test,
+        test_mint.pubkey,
+        test_escrow.pubkey,
+        AMOUNT / 2,
+        AMOUNT / 2,
+        i64::MAX,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[slash_stake(
+            rndr::id(),
+            AMOUNT * 3 / 4,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_stake.operator.pubkey(),
+            test_job.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // Staked tokens are slashed before unstaking ones
+    let stake = test_stake.get(&mut banks_client).await;
+    assert_eq!(stake.amount, 0);
+    assert_eq!(stake.unstaking_amount, AMOUNT / 4);
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_stake.vault_associated_token).await,
+        AMOUNT / 4
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        AMOUNT + AMOUNT * 3 / 4
+    );
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, AMOUNT + AMOUNT * 3 / 4);
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT + AMOUNT * 3 / 4);
+}
+
+#[tokio::test]
+async fn test_treasury() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let test_treasury_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_program_config = TestProgramConfig::add_with_treasury(
+        &mut test,
+        vec![],
+        vec![],
+        Pubkey::default(),
+        test_treasury_token.owner.pubkey(),
+    );
+    let test_stake = TestStake::add(
+        &mut test,
+        test_mint.pubkey,
+        test_escrow.pubkey,
+        AMOUNT,
+        0,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The program config admin arbitrates as well
+    let mut transaction = Transaction::new_with_payer(
+        &[slash_stake(
+            rndr::id(),
+            AMOUNT / 4,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_program_config.admin.pubkey(),
+            test_stake.operator.pubkey(),
+            test_treasury_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_program_config.admin], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_treasury_token.pubkey).await,
+        AMOUNT / 4
+    );
+    let stake = test_stake.get(&mut banks_client).await;
+    assert_eq!(stake.amount, AMOUNT - AMOUNT / 4);
+}
+
+#[tokio::test]
+async fn test_wrong_authority() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    TestProgramConfig::add(&mut test, vec![], vec![], Pubkey::default());
+    let test_stake = TestStake::add(
+        &mut test,
+        test_mint.pubkey,
+        test_escrow.pubkey,
+        AMOUNT,
+        0,
+        0,
+    );
+    let test_treasury_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let authority = Keypair::new();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[slash_stake(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            authority.pubkey(),
+            test_stake.operator.pubkey(),
+            test_treasury_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let stake = test_stake.get(&mut banks_client).await;
+    assert_eq!(stake.amount, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_exceeds_stake() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let test_stake = TestStake::add(
+        &mut test,
+        test_mint.pubkey,
+        test_escrow.pubkey,
+        AMOUNT,
+        AMOUNT,
+        i64::MAX,
+    );
+    let test_treasury_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[slash_stake(
+            rndr::id(),
+            2 * AMOUNT + 1,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_stake.operator.pubkey(),
+            test_treasury_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_not_treasury() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    TestProgramConfig::add_with_treasury(
+        &mut test,
+        vec![],
+        vec![],
+        Pubkey::default(),
+        Pubkey::new_unique(),
+    );
+    let test_stake = TestStake::add(
+        &mut test,
+        test_mint.pubkey,
+        test_escrow.pubkey,
+        AMOUNT,
+        0,
+        0,
+    );
+    let test_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[slash_stake(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            test_stake.operator.pubkey(),
+            test_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_token.pubkey).await,
+        0
+    );
+    let stake = test_stake.get(&mut banks_client).await;
+    assert_eq!(stake.amount, AMOUNT);
+}
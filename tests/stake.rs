@@ -0,0 +1,155 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::{find_stake_address, find_stake_vault_address, stake},
+        processor::process_instruction,
+    },
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+    spl_associated_token_account::get_associated_token_address,
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[stake(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+            test_escrow.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (stake_vault, _bump_seed) = find_stake_vault_address(&rndr::id(), &test_escrow.pubkey);
+    assert_eq!(
+        get_token_balance(
+            &mut banks_client,
+            get_associated_token_address(&stake_vault, &test_mint.pubkey)
+        )
+        .await,
+        AMOUNT
+    );
+
+    let (stake_address, _bump_seed) =
+        find_stake_address(&rndr::id(), &test_escrow.pubkey, &payer.pubkey());
+    let stake = get_stake(&mut banks_client, stake_address).await;
+    assert_eq!(stake.escrow, test_escrow.pubkey);
+    assert_eq!(stake.operator, payer.pubkey());
+    assert_eq!(stake.amount, AMOUNT);
+    assert_eq!(stake.unstaking_amount, 0);
+}
+
+#[tokio::test]
+async fn test_top_up() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let test_stake = TestStake::add(
+        &mut test,
+        test_mint.pubkey,
+        test_escrow.pubkey,
+        AMOUNT,
+        0,
+        0,
+    );
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[stake(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_stake.operator.pubkey(),
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+            test_escrow.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(
+        &[&payer, &test_stake.operator, &test_source_token.owner],
+        recent_blockhash,
+    );
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_stake.vault_associated_token).await,
+        2 * AMOUNT
+    );
+    let stake = test_stake.get(&mut banks_client).await;
+    assert_eq!(stake.amount, 2 * AMOUNT);
+}
+
+#[tokio::test]
+async fn test_wrong_mint() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_other_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let test_stake = TestStake::add(
+        &mut test,
+        test_mint.pubkey,
+        test_escrow.pubkey,
+        AMOUNT,
+        0,
+        0,
+    );
+    let test_source_token = TestToken::add(&mut test, test_other_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[stake(
+            rndr::id(),
+            AMOUNT,
+            test_other_mint.pubkey,
+            test_stake.operator.pubkey(),
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+            test_escrow.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(
+        &[&payer, &test_stake.operator, &test_source_token.owner],
+        recent_blockhash,
+    );
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let stake = test_stake.get(&mut banks_client).await;
+    assert_eq!(stake.amount, AMOUNT);
+}
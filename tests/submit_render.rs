@@ -0,0 +1,183 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{
+        instruction::{find_render_submission_address, submit_render},
+        processor::process_instruction,
+        state::SubmissionStatus,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[submit_render(
+            rndr::id(),
+            [7; 32],
+            1,
+            100,
+            AMOUNT,
+            payer.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (render_submission_address, _bump_seed) = find_render_submission_address(
+        &rndr::id(),
+        &test_job.pubkey,
+        test_job.generation,
+        &payer.pubkey(),
+    );
+    let render_submission =
+        get_render_submission(&mut banks_client, render_submission_address).await;
+    assert_eq!(render_submission.job, test_job.pubkey);
+    assert_eq!(render_submission.node, payer.pubkey());
+    assert_eq!(render_submission.frames_hash, [7; 32]);
+    assert_eq!(render_submission.frame_start, 1);
+    assert_eq!(render_submission.frame_end, 100);
+    assert_eq!(render_submission.price, AMOUNT);
+    assert_eq!(render_submission.status, SubmissionStatus::Pending);
+    assert_eq!(render_submission.paid, 0);
+    assert_eq!(render_submission.rent_payer, payer.pubkey());
+}
+
+#[tokio::test]
+async fn test_resubmit_rejected() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let node = Keypair::new();
+    let test_render_submission = TestRenderSubmission::add(
+        &mut test,
+        test_job.pubkey,
+        node.pubkey(),
+        AMOUNT,
+        SubmissionStatus::Rejected,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[submit_render(
+            rndr::id(),
+            [7; 32],
+            1,
+            50,
+            AMOUNT / 2,
+            node.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let render_submission = test_render_submission.get(&mut banks_client).await;
+    assert_eq!(render_submission.frames_hash, [7; 32]);
+    assert_eq!(render_submission.frame_start, 1);
+    assert_eq!(render_submission.frame_end, 50);
+    assert_eq!(render_submission.price, AMOUNT / 2);
+    assert_eq!(render_submission.status, SubmissionStatus::Pending);
+}
+
+#[tokio::test]
+async fn test_resubmit_accepted() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let node = Keypair::new();
+    TestRenderSubmission::add(
+        &mut test,
+        test_job.pubkey,
+        node.pubkey(),
+        AMOUNT,
+        SubmissionStatus::Accepted,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[submit_render(
+            rndr::id(),
+            [7; 32],
+            1,
+            50,
+            AMOUNT,
+            node.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_invalid_frame_range() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[submit_render(
+            rndr::id(),
+            [7; 32],
+            100,
+            1,
+            AMOUNT,
+            payer.pubkey(),
+            test_job.pubkey,
+            test_job.generation,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
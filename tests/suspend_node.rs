@@ -0,0 +1,91 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::suspend_node, processor::process_instruction, state::NodeStatus},
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_program_config = TestProgramConfig::add(&mut test, vec![], vec![], Pubkey::default());
+    let test_payout_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_node = TestNode::add(&mut test, test_payout_token.pubkey, NodeStatus::Active);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[suspend_node(
+            rndr::id(),
+            true,
+            test_program_config.admin.pubkey(),
+            test_node.operator.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_program_config.admin], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let node = test_node.get(&mut banks_client).await;
+    assert_eq!(node.status, NodeStatus::Suspended);
+
+    let mut transaction = Transaction::new_with_payer(
+        &[suspend_node(
+            rndr::id(),
+            false,
+            test_program_config.admin.pubkey(),
+            test_node.operator.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_program_config.admin], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let node = test_node.get(&mut banks_client).await;
+    assert_eq!(node.status, NodeStatus::Active);
+}
+
+#[tokio::test]
+async fn test_wrong_admin() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    TestProgramConfig::add(&mut test, vec![], vec![], Pubkey::default());
+    let test_payout_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_node = TestNode::add(&mut test, test_payout_token.pubkey, NodeStatus::Active);
+    let admin = Keypair::new();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[suspend_node(
+            rndr::id(),
+            true,
+            admin.pubkey(),
+            test_node.operator.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &admin], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let node = test_node.get(&mut banks_client).await;
+    assert_eq!(node.status, NodeStatus::Active);
+}
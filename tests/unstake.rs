@@ -0,0 +1,97 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::unstake, processor::process_instruction},
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let test_stake = TestStake::add(
+        &mut test,
+        test_mint.pubkey,
+        test_escrow.pubkey,
+        AMOUNT,
+        AMOUNT / 4,
+        0,
+    );
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[unstake(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_stake.operator.pubkey(),
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_stake.operator], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT / 4
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_stake.vault_associated_token).await,
+        AMOUNT
+    );
+    let stake = test_stake.get(&mut banks_client).await;
+    assert_eq!(stake.amount, AMOUNT);
+    assert_eq!(stake.unstaking_amount, 0);
+}
+
+#[tokio::test]
+async fn test_cooldown() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let test_stake = TestStake::add(
+        &mut test,
+        test_mint.pubkey,
+        test_escrow.pubkey,
+        0,
+        AMOUNT,
+        i64::MAX,
+    );
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[unstake(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.pubkey,
+            test_stake.operator.pubkey(),
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_stake.operator], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let stake = test_stake.get(&mut banks_client).await;
+    assert_eq!(stake.unstaking_amount, AMOUNT);
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::update_node, processor::process_instruction, state::NodeStatus},
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_payout_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_new_payout_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_node = TestNode::add(&mut test, test_payout_token.pubkey, NodeStatus::Active);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[update_node(
+            rndr::id(),
+            3,
+            test_node.operator.pubkey(),
+            test_new_payout_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_node.operator], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let node = test_node.get(&mut banks_client).await;
+    assert_eq!(node.payout_token, test_new_payout_token.pubkey);
+    assert_eq!(node.tier, 3);
+    assert_eq!(node.status, NodeStatus::Active);
+}
+
+#[tokio::test]
+async fn test_wrong_operator() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_payout_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_new_payout_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_node = TestNode::add(&mut test, test_payout_token.pubkey, NodeStatus::Active);
+    let operator = Keypair::new();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut instruction = update_node(
+        rndr::id(),
+        0,
+        operator.pubkey(),
+        test_new_payout_token.pubkey,
+    );
+    instruction.accounts[2].pubkey = test_node.pubkey;
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, &operator], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let node = test_node.get(&mut banks_client).await;
+    assert_eq!(node.payout_token, test_payout_token.pubkey);
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "test-bpf")]
+
+mod helpers;
+
+use {
+    helpers::*,
+    rndr::{instruction::update_program_config, processor::process_instruction},
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_program_config = TestProgramConfig::add(
+        &mut test,
+        vec![Pubkey::new_unique()],
+        vec![spl_token::id()],
+        Pubkey::new_unique(),
+    );
+    let new_admin = Pubkey::new_unique();
+    let token_mints = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+    let default_escrow_owner = Pubkey::new_unique();
+    let treasury = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[update_program_config(
+            rndr::id(),
+            new_admin,
+            token_mints.clone(),
+            vec![spl_token::id()],
+            default_escrow_owner,
+            treasury,
+            test_program_config.admin.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_program_config.admin], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let program_config = test_program_config.get(&mut banks_client).await;
+
+    assert_eq!(program_config.admin, new_admin);
+    assert_eq!(program_config.allowed_mints, token_mints);
+    assert_eq!(program_config.allowed_token_programs, vec![spl_token::id()]);
+    assert_eq!(program_config.default_escrow_owner, default_escrow_owner);
+    assert_eq!(program_config.treasury, treasury);
+}
+
+#[tokio::test]
+async fn test_not_admin() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_program_config = TestProgramConfig::add(
+        &mut test,
+        vec![Pubkey::new_unique()],
+        vec![spl_token::id()],
+        Pubkey::new_unique(),
+    );
+    let attacker = Keypair::new();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[update_program_config(
+            rndr::id(),
+            attacker.pubkey(),
+            vec![Pubkey::new_unique()],
+            vec![spl_token::id()],
+            attacker.pubkey(),
+            attacker.pubkey(),
+            attacker.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &attacker], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let program_config = test_program_config.get(&mut banks_client).await;
+    assert_eq!(program_config.admin, test_program_config.admin.pubkey());
+}